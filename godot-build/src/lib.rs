@@ -0,0 +1,341 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Build-script helpers for gdext extensions.
+//!
+//! Add this crate as a build dependency and call [`generate_extension_file()`] from `build.rs`, to have the `.gdextension` file
+//! written alongside the compiled library instead of maintaining it by hand:
+//!
+//! ```no_run
+//! // In build.rs, inside main():
+//! godot_build::generate_extension_file();
+//! ```
+//!
+//! The file lists the library for all platforms Godot supports (Linux, Windows, macOS and Web), for both debug and release builds.
+//! Library paths are relative to the `.gdextension` file, which Godot resolves accordingly.
+//!
+//! The entry of the platform currently being built points to the directory Cargo actually uses, which accounts for `--target`
+//! (cross-compiling) and custom profiles. Other entries assume Cargo's default layout, i.e. `debug` and `release` directories, nested
+//! in a directory named after the target triple where the platform is usually cross-compiled to.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+/// Writes `<target-dir>/<crate_name>.gdextension` with default settings, and returns its path.
+///
+/// The entry symbol is `gdext_rust_init`, the default of `#[gdextension]`. For anything else, use [`ExtensionFile`].
+///
+/// # Panics
+/// If not called from a build script, or if the file cannot be written.
+pub fn generate_extension_file() -> PathBuf {
+    ExtensionFile::from_build_env()
+        .write()
+        .expect("failed to write .gdextension file")
+}
+
+/// Contents and location of a `.gdextension` file.
+#[derive(Clone, Debug)]
+pub struct ExtensionFile {
+    library_name: String,
+    entry_symbol: String,
+    compatibility_minimum: String,
+    target_dir: PathBuf,
+    output_path: Option<PathBuf>,
+    current_build: Option<CurrentBuild>,
+}
+
+/// The build that runs the build script, whose library location is known exactly.
+#[derive(Clone, Debug)]
+struct CurrentBuild {
+    /// Godot platform and architecture, as in the `.gdextension` keys.
+    platform: &'static str,
+    arch: &'static str,
+    /// Whether Godot's `release` entry (rather than `debug`) refers to this build.
+    is_release: bool,
+    /// Directory of the built library, relative to the target directory, e.g. `x86_64-pc-windows-gnu/dist`.
+    profile_dir: String,
+}
+
+impl ExtensionFile {
+    /// Settings derived from the environment Cargo provides to build scripts.
+    ///
+    /// The library name is the package name with `-` replaced by `_`, matching Cargo's default `[lib]` name.
+    ///
+    /// # Panics
+    /// If not called from a build script.
+    pub fn from_build_env() -> Self {
+        let package_name = build_env("CARGO_PKG_NAME");
+        let out_dir = PathBuf::from(build_env("OUT_DIR"));
+        let target = build_env("TARGET");
+        // Custom profiles report the profile they inherit from, i.e. `debug` or `release`.
+        let profile = build_env("PROFILE");
+
+        let target_dir = target_dir(&out_dir, &target);
+        let current_build = current_build(&out_dir, &target_dir, &target, &profile);
+
+        let mut file = Self::new(package_name.replace('-', "_"), target_dir);
+        file.current_build = current_build;
+        file
+    }
+
+    /// Settings for the library `library_name` built into `target_dir` (usually Cargo's `target` directory).
+    pub fn new(library_name: impl Into<String>, target_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            library_name: library_name.into(),
+            entry_symbol: "gdext_rust_init".to_string(),
+            compatibility_minimum: "4.1".to_string(),
+            target_dir: target_dir.into(),
+            output_path: None,
+            current_build: None,
+        }
+    }
+
+    /// Entry symbol, must match `#[gdextension(entry_point = ...)]`.
+    pub fn entry_symbol(mut self, entry_symbol: impl Into<String>) -> Self {
+        self.entry_symbol = entry_symbol.into();
+        self
+    }
+
+    /// Minimum Godot version that loads the extension, e.g. `"4.2"`.
+    pub fn compatibility_minimum(mut self, version: impl Into<String>) -> Self {
+        self.compatibility_minimum = version.into();
+        self
+    }
+
+    /// Location of the file; defaults to `<target_dir>/<library_name>.gdextension`.
+    ///
+    /// Library paths are written relative to the target directory, so this should only be changed if the paths are still valid
+    /// from there, e.g. when using a symlink.
+    pub fn output_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.output_path = Some(path.into());
+        self
+    }
+
+    /// Writes the file (unless its content is unchanged) and returns its path.
+    pub fn write(&self) -> io::Result<PathBuf> {
+        let path = self.output_path.clone().unwrap_or_else(|| {
+            self.target_dir
+                .join(format!("{}.gdextension", self.library_name))
+        });
+
+        let content = self.to_file_content();
+
+        // Avoid touching the file, since Godot reloads extensions whose .gdextension file changes.
+        if fs::read_to_string(&path).ok().as_deref() != Some(content.as_str()) {
+            fs::write(&path, content)?;
+        }
+
+        Ok(path)
+    }
+
+    /// Returns the file content, in Godot's config file format.
+    pub fn to_file_content(&self) -> String {
+        let name = &self.library_name;
+        let mut content = format!(
+            "[configuration]\n\
+            entry_symbol = \"{}\"\n\
+            compatibility_minimum = {}\n\
+            \n\
+            [libraries]\n",
+            self.entry_symbol, self.compatibility_minimum
+        );
+
+        for profile in ["debug", "release"] {
+            let entries = [
+                ("linux", "x86_64", format!("{profile}/lib{name}.so")),
+                ("linux", "arm64", format!("{profile}/lib{name}.so")),
+                ("windows", "x86_64", format!("{profile}/{name}.dll")),
+                ("macos", "", format!("{profile}/lib{name}.dylib")),
+                ("macos", "arm64", format!("{profile}/lib{name}.dylib")),
+                (
+                    "web",
+                    "wasm32",
+                    format!("wasm32-unknown-emscripten/{profile}/{name}.wasm"),
+                ),
+            ];
+
+            for (platform, arch, default_path) in entries {
+                let key = if arch.is_empty() {
+                    format!("{platform}.{profile}")
+                } else {
+                    format!("{platform}.{profile}.{arch}")
+                };
+
+                let path = match &self.current_build {
+                    Some(build)
+                        if build.platform == platform
+                            && build.arch == arch
+                            && build.is_release == (profile == "release") =>
+                    {
+                        let file_name = default_path.rsplit('/').next().unwrap_or_default();
+                        format!("{}/{file_name}", build.profile_dir)
+                    }
+                    _ => default_path,
+                };
+
+                let _ = writeln!(content, "{key} = \"{path}\"");
+            }
+        }
+
+        content
+    }
+}
+
+fn build_env(key: &str) -> String {
+    env::var(key).unwrap_or_else(|_| {
+        panic!("environment variable `{key}` not set; godot-build must be used from a build script")
+    })
+}
+
+/// Derives Cargo's target directory from `OUT_DIR`, which is `<target-dir>/[<triple>/]<profile>/build/<package>-<hash>/out`.
+fn target_dir(out_dir: &Path, target: &str) -> PathBuf {
+    let profile_dir = out_dir
+        .ancestors()
+        .nth(3)
+        .expect("unexpected OUT_DIR layout");
+
+    let parent = profile_dir.parent().expect("unexpected OUT_DIR layout");
+
+    // With an explicit --target, profile directories are nested in a directory named after the target triple.
+    if parent.file_name().is_some_and(|name| name == target) {
+        parent
+            .parent()
+            .expect("unexpected OUT_DIR layout")
+            .to_path_buf()
+    } else {
+        parent.to_path_buf()
+    }
+}
+
+/// Describes the build from `OUT_DIR`, `TARGET` and `PROFILE`. `None` if the target is not a platform listed in the file.
+fn current_build(
+    out_dir: &Path,
+    target_dir: &Path,
+    target: &str,
+    profile: &str,
+) -> Option<CurrentBuild> {
+    let (platform, arch) = godot_platform(target)?;
+
+    // The profile directory is named after the profile, e.g. `debug` for `dev` or `dist` for a custom profile.
+    let profile_dir = out_dir.ancestors().nth(3)?.strip_prefix(target_dir).ok()?;
+    let profile_dir = profile_dir
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+
+    Some(CurrentBuild {
+        platform,
+        arch,
+        is_release: profile == "release",
+        profile_dir,
+    })
+}
+
+/// Maps a target triple to the platform and architecture of the corresponding `.gdextension` entry.
+fn godot_platform(target: &str) -> Option<(&'static str, &'static str)> {
+    let arch = target.split('-').next()?;
+
+    if target.contains("-linux-") {
+        match arch {
+            "x86_64" => Some(("linux", "x86_64")),
+            "aarch64" => Some(("linux", "arm64")),
+            _ => None,
+        }
+    } else if target.contains("-windows-") {
+        (arch == "x86_64").then_some(("windows", "x86_64"))
+    } else if target.contains("-apple-darwin") {
+        match arch {
+            "x86_64" => Some(("macos", "")),
+            "aarch64" => Some(("macos", "arm64")),
+            _ => None,
+        }
+    } else if target.contains("-emscripten") {
+        (arch == "wasm32").then_some(("web", "wasm32"))
+    } else {
+        None
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_dir_from_out_dir() {
+        let host = Path::new("/work/target/debug/build/my-ext-0123abcd/out");
+        assert_eq!(
+            target_dir(host, "x86_64-unknown-linux-gnu"),
+            Path::new("/work/target")
+        );
+
+        let cross =
+            Path::new("/work/target/wasm32-unknown-emscripten/release/build/my-ext-0123abcd/out");
+        assert_eq!(
+            target_dir(cross, "wasm32-unknown-emscripten"),
+            Path::new("/work/target")
+        );
+    }
+
+    #[test]
+    fn current_build_paths() {
+        let target_dir = Path::new("/work/target");
+        let file_content = |out_dir: &str, target: &str, profile: &str| {
+            let mut file = ExtensionFile::new("my_ext", target_dir);
+            file.current_build = current_build(Path::new(out_dir), target_dir, target, profile);
+            file.to_file_content()
+        };
+
+        // Cross-compiling: the current platform's entry includes the target triple.
+        let content = file_content(
+            "/work/target/x86_64-pc-windows-gnu/release/build/my-ext-0123abcd/out",
+            "x86_64-pc-windows-gnu",
+            "release",
+        );
+        assert!(content
+            .contains("windows.release.x86_64 = \"x86_64-pc-windows-gnu/release/my_ext.dll\"\n"));
+        assert!(content.contains("windows.debug.x86_64 = \"debug/my_ext.dll\"\n"));
+        assert!(content.contains("linux.release.x86_64 = \"release/libmy_ext.so\"\n"));
+
+        // Custom profile inheriting from release.
+        let content = file_content(
+            "/work/target/dist/build/my-ext-0123abcd/out",
+            "aarch64-unknown-linux-gnu",
+            "release",
+        );
+        assert!(content.contains("linux.release.arm64 = \"dist/libmy_ext.so\"\n"));
+        assert!(content.contains("linux.release.x86_64 = \"release/libmy_ext.so\"\n"));
+
+        // Unlisted platforms use the defaults.
+        let content = file_content(
+            "/work/target/debug/build/my-ext-0123abcd/out",
+            "riscv64gc-unknown-linux-gnu",
+            "debug",
+        );
+        assert!(content.contains("linux.debug.x86_64 = \"debug/libmy_ext.so\"\n"));
+    }
+
+    #[test]
+    fn file_content() {
+        let content = ExtensionFile::new("my_ext", "/work/target")
+            .entry_symbol("my_init")
+            .compatibility_minimum("4.2")
+            .to_file_content();
+
+        assert!(content.starts_with(
+            "[configuration]\nentry_symbol = \"my_init\"\ncompatibility_minimum = 4.2\n\n[libraries]\n"
+        ));
+        assert!(content.contains("linux.debug.x86_64 = \"debug/libmy_ext.so\"\n"));
+        assert!(content.contains("windows.release.x86_64 = \"release/my_ext.dll\"\n"));
+        assert!(content.contains("macos.debug = \"debug/libmy_ext.dylib\"\n"));
+        assert!(content
+            .contains("web.release.wasm32 = \"wasm32-unknown-emscripten/release/my_ext.wasm\"\n"));
+    }
+}
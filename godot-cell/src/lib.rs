@@ -114,6 +114,14 @@ impl<T> GdCell<T> {
         InaccessibleGuard::new(&self.get_ref().state, current_ref)
     }
 
+    /// Returns a raw pointer to the contents of the cell, without registering a borrow.
+    ///
+    /// Dereferencing the pointer is only sound while no reference handed out by this cell conflicts with the access, i.e.
+    /// no accessible mutable reference exists for a read, and no reference at all exists for a write.
+    pub fn as_ptr(self: Pin<&Self>) -> *mut T {
+        self.value.get()
+    }
+
     /// Returns `true` if there are any mutable or shared references, regardless of whether the mutable
     /// references are accessible or not.
     ///
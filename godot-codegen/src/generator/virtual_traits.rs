@@ -26,6 +26,14 @@ pub fn make_virtual_methods_trait(
     let virtual_method_fns = make_all_virtual_methods(class, all_base_names, view);
     let special_virtual_methods = special_virtual_methods(notification_enum_name);
 
+    let is_node = class.name().godot_ty == "Node"
+        || all_base_names.iter().any(|base| base.godot_ty == "Node");
+    let node_virtual_methods = if is_node {
+        special_node_virtual_methods()
+    } else {
+        TokenStream::new()
+    };
+
     let trait_doc = docs::make_virtual_trait_doc(trait_name_str, class.name());
 
     quote! {
@@ -34,6 +42,7 @@ pub fn make_virtual_methods_trait(
         #[allow(clippy::unimplemented)]
         pub trait #trait_name: crate::obj::GodotClass + crate::private::You_forgot_the_attribute__godot_api {
             #special_virtual_methods
+            #node_virtual_methods
             #( #virtual_method_fns )*
         }
     }
@@ -104,6 +113,49 @@ fn special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
             unimplemented!()
         }
 
+        /// Called whenever Godot retrieves a property's info, e.g. to show it in the editor's inspector.
+        ///
+        /// `property` can be modified in place, for example to change its hint or usage flags depending on the values of other properties.
+        /// Hints from `#[export(range_max_from = ...)]` are already applied at this point. Call
+        /// [`notify_property_list_changed()`](crate::engine::Object::notify_property_list_changed) when the result would change,
+        /// to have the editor query all properties again.
+        ///
+        /// This is named `_validate_property` in Godot. It takes `&self` since Godot declares it `const`; the method is invoked
+        /// whenever the property list is requested, which may happen while `self` is already bound.
+        ///
+        /// See also in Godot docs:
+        /// * [`Object::_validate_property`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-validate-property).
+        #[cfg(since_api = "4.2")]
+        fn validate_property(&self, property: &mut crate::builtin::meta::PropertyInfo) {
+            unimplemented!()
+        }
+
+    }
+}
+
+/// Methods that Godot doesn't declare as virtual, but which `#[godot_api]` wires up to signals of `Node`.
+fn special_node_virtual_methods() -> TokenStream {
+    quote! {
+        /// Called when a direct child node enters the scene tree, i.e. on the node's own `child_entered_tree` signal.
+        ///
+        /// Unlike `NOTIFICATION_CHILD_ORDER_CHANGED`, this
+        /// identifies the child. The signal is connected right before [`ready()`][Self::ready], so children present at that
+        /// point have already entered the tree and are not reported.
+        ///
+        /// This method is not a Godot virtual function, but implemented through a signal connection.
+        #[cfg(since_api = "4.2")]
+        fn on_child_entered_tree(&mut self, child: Gd<crate::engine::Node>) {
+            unimplemented!()
+        }
+
+        /// Called when a direct child node is about to exit the scene tree, i.e. on the node's own `child_exiting_tree` signal.
+        ///
+        /// The signal is connected right before [`ready()`][Self::ready], like for
+        /// [`on_child_entered_tree()`][Self::on_child_entered_tree].
+        #[cfg(since_api = "4.2")]
+        fn on_child_exiting_tree(&mut self, child: Gd<crate::engine::Node>) {
+            unimplemented!()
+        }
     }
 }
 
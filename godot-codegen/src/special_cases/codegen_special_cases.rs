@@ -122,33 +122,47 @@ pub(crate) fn is_utility_function_excluded(
 const SELECTED_CLASSES: &[&str] = &[
     "AnimatedSprite2D",
     "Area2D",
+    "Area3D",
     "ArrayMesh",
     "AudioStreamPlayer",
     "BaseButton",
+    "BoxContainer",
     "BoxMesh",
     "Button",
     "Camera2D",
     "Camera3D",
     "CanvasItem",
     "CanvasLayer",
+    "CharacterBody3D",
     "ClassDB",
     "CollisionObject2D",
+    "CollisionObject3D",
     "CollisionShape2D",
+    "Container",
     "Control",
     "EditorPlugin",
     "Engine",
     "FileAccess",
     "GDScript",
+    "GeometryInstance3D",
+    "GridMap",
     "HTTPRequest",
     "Image",
     "ImageTextureLayered",
     "Input",
     "InputEvent",
     "InputEventAction",
+    "InputEventJoypadButton",
+    "InputMap",
+    "InstancePlaceholder",
+    "JSON",
     "Label",
     "MainLoop",
     "Marker2D",
+    "Material",
     "Mesh",
+    "MeshInstance3D",
+    "MultiplayerSynchronizer",
     "Node",
     "Node2D",
     "Node3D",
@@ -157,7 +171,13 @@ const SELECTED_CLASSES: &[&str] = &[
     "OS",
     "PackedScene",
     "PathFollow2D",
+    "Performance",
     "PhysicsBody2D",
+    "PhysicsBody3D",
+    "PhysicsDirectSpaceState3D",
+    "PhysicsRayQueryParameters3D",
+    "PhysicsServer3D",
+    "PlaceholderTexture2D",
     "PrimitiveMesh",
     "RefCounted",
     "RenderingServer",
@@ -166,6 +186,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "ResourceLoader",
     "ResourceSaver",
     "RigidBody2D",
+    "SceneReplicationConfig",
     "SceneTree",
     "SceneTreeTimer",
     "Script",
@@ -176,10 +197,14 @@ const SELECTED_CLASSES: &[&str] = &[
     "TextServer",
     "TextServerExtension",
     "Texture",
+    "Texture2D",
     "Texture2DArray",
     "TextureLayered",
+    "TileMap",
     "Time",
     "Timer",
+    "VBoxContainer",
     "Viewport",
+    "VisualInstance3D",
     "Window",
 ];
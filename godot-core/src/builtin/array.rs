@@ -33,6 +33,9 @@ use super::meta::{
 /// runtime checks that no values of the wrong type are put into the array. We represent this as
 /// `Array<T>`, where the type `T` implements `GodotType`.
 ///
+/// `Array<T>` parameters and return types of `#[func]` methods and signals are registered with their element type, so GDScript
+/// sees them as `Array[T]`.
+///
 /// # Reference semantics
 ///
 /// Like in GDScript, `Array` acts as a reference type: multiple `Array` instances may
@@ -861,14 +864,20 @@ impl<T: GodotType> Var for Array<T> {
 
     #[cfg(since_api = "4.2")]
     fn property_hint() -> PropertyHintInfo {
-        if T::Ffi::variant_type() == VariantType::Nil {
-            return PropertyHintInfo::with_hint_none("");
-        }
+        element_type_hint::<T>()
+    }
+}
 
-        PropertyHintInfo {
-            hint: crate::engine::global::PropertyHint::ARRAY_TYPE,
-            hint_string: T::godot_type_name().into(),
-        }
+/// Hint describing the element type of `Array<T>`, or no hint for untyped arrays.
+#[cfg(since_api = "4.2")]
+fn element_type_hint<T: GodotType>() -> PropertyHintInfo {
+    if T::Ffi::variant_type() == VariantType::Nil {
+        return PropertyHintInfo::with_hint_none("");
+    }
+
+    PropertyHintInfo {
+        hint: crate::engine::global::PropertyHint::ARRAY_TYPE,
+        hint_string: T::godot_type_name().into(),
     }
 }
 
@@ -929,6 +938,21 @@ impl<T: GodotType> GodotType for Array<T> {
         Ok(ffi)
     }
 
+    // Typed arrays carry their element type in the hint, so that #[func] parameters and return values show up as `Array[T]`.
+    #[cfg(since_api = "4.2")]
+    fn property_info(property_name: &str) -> crate::builtin::meta::PropertyInfo {
+        let PropertyHintInfo { hint, hint_string } = element_type_hint::<T>();
+
+        crate::builtin::meta::PropertyInfo {
+            variant_type: Self::Ffi::variant_type(),
+            class_name: Self::class_name(),
+            property_name: StringName::from(property_name),
+            hint,
+            hint_string,
+            usage: crate::engine::global::PropertyUsageFlags::DEFAULT,
+        }
+    }
+
     fn godot_type_name() -> String {
         "Array".into()
     }
@@ -945,6 +969,19 @@ impl<T: GodotType> GodotFfiVariant for Array<T> {
     }
 
     fn ffi_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+        // SAFETY: The element type is checked before the array is handed out.
+        let array = unsafe { Self::from_variant_any_type(variant)? };
+
+        array.with_checked_type()
+    }
+}
+
+impl<T: GodotType> Array<T> {
+    /// Converts a variant holding an array, without checking the element type.
+    ///
+    /// # Safety
+    /// The result may violate the safety invariant of `Array`; see [`assume_type()`][Self::assume_type] for how it may be used.
+    unsafe fn from_variant_any_type(variant: &Variant) -> Result<Self, ConvertError> {
         if variant.get_type() != Self::variant_type() {
             return Err(FromVariantError::BadType {
                 expected: Self::variant_type(),
@@ -960,7 +997,26 @@ impl<T: GodotType> GodotFfiVariant for Array<T> {
             })
         };
 
-        array.with_checked_type()
+        Ok(array)
+    }
+}
+
+impl VariantArray {
+    /// Converts a variant holding an array of any element type. Typed arrays are copied into a new untyped array.
+    pub(crate) fn from_variant_untyped(variant: &Variant) -> Result<Self, ConvertError> {
+        // SAFETY: A typed array is only read from, as it is copied.
+        let array = unsafe { Self::from_variant_any_type(variant)? };
+        if !array.type_info().is_typed() {
+            return Ok(array);
+        }
+
+        let untyped = Self::new();
+
+        // SAFETY: An untyped array accepts values of all types.
+        let mut inner = unsafe { untyped.as_inner_mut() };
+        inner.append_array(array);
+
+        Ok(untyped)
     }
 }
 
@@ -850,6 +850,35 @@ mod test {
         );
     }
 
+    #[test]
+    fn basis_constructors_roundtrip() {
+        let scale = Vector3::new(2.0, -0.5, 4.0);
+        let scaled = Basis::from_scale(scale);
+        assert_eq_approx!(scaled * Vector3::ONE, scale);
+        assert_eq_approx!(scaled.inverse(), Basis::from_scale(Vector3::ONE / scale));
+
+        let axis = Vector3::new(1.0, -2.0, 3.0).normalized();
+        let rotated = Basis::from_axis_angle(axis, 0.7);
+        assert_eq_approx!(rotated * axis, axis);
+        assert_eq_approx!(
+            Basis::from_axis_angle(axis, -0.7) * rotated,
+            Basis::IDENTITY
+        );
+        assert_eq_approx!(rotated.inverse(), rotated.transposed());
+
+        let quat = Quaternion::from_axis_angle(axis, 0.7);
+        assert_eq_approx!(Basis::from_quat(quat), rotated);
+        assert_eq_approx!(Basis::from_quat(quat).to_quat(), quat);
+        assert_eq_approx!(Basis::from_quat(quat.inverse()) * rotated, Basis::IDENTITY);
+
+        let euler = Vector3::new(0.3, -0.4, 1.2);
+        for order in [EulerOrder::XYZ, EulerOrder::YXZ, EulerOrder::ZYX] {
+            let basis = Basis::from_euler(order, euler);
+            assert_eq_approx!(basis.to_euler(order), euler, "order: {order:?}\n");
+            assert_eq_approx!(basis.inverse() * basis, Basis::IDENTITY);
+        }
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -8,10 +8,13 @@
 use godot_ffi as sys;
 
 use crate::builtin::meta::{FromGodot, ToGodot};
-use crate::builtin::{inner, Variant, VariantArray};
+use crate::builtin::{inner, GString, Variant, VariantArray};
+use crate::engine::global::Error;
+use crate::engine::Json;
+use crate::obj::NewGd;
 use crate::property::{Export, PropertyHintInfo, TypeStringHint, Var};
 use std::marker::PhantomData;
-use std::{fmt, ptr};
+use std::{error, fmt, ptr};
 use sys::types::OpaqueDictionary;
 use sys::{ffi_methods, interface_fn, AsUninit, GodotFfi};
 
@@ -40,6 +43,45 @@ impl Dictionary {
         Self::default()
     }
 
+    /// Creates a dictionary from key-value pairs, converting each key and value to a `Variant`.
+    ///
+    /// Later pairs overwrite earlier ones with the same key.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let dict = Dictionary::from_pairs([("health", 100), ("armor", 25)]);
+    /// assert_eq!(dict.get("armor"), Some(25.to_variant()));
+    /// ```
+    pub fn from_pairs<K: ToGodot, V: ToGodot>(pairs: impl IntoIterator<Item = (K, V)>) -> Self {
+        pairs.into_iter().collect()
+    }
+
+    /// Parses a JSON object into a dictionary, using Godot's `JSON` parser.
+    ///
+    /// Fails if `json` is not valid JSON, or if its top-level value is not an object.
+    ///
+    /// Note that Godot parses all JSON numbers as `float`.
+    ///
+    /// _Godot equivalent: `JSON.new().parse(json)` followed by `get_data()`_
+    pub fn from_json(json: &str) -> Result<Self, JsonParseError> {
+        let mut parser = Json::new_gd();
+
+        if parser.parse(GString::from(json)) != Error::OK {
+            return Err(JsonParseError {
+                line: Some(parser.get_error_line()),
+                message: parser.get_error_message().to_string(),
+            });
+        }
+
+        parser
+            .get_data()
+            .try_to::<Self>()
+            .map_err(|_| JsonParseError {
+                line: None,
+                message: "top-level JSON value is not an object".to_string(),
+            })
+    }
+
     /// Removes all key-value pairs from the dictionary.
     pub fn clear(&mut self) {
         self.as_inner().clear()
@@ -404,6 +446,38 @@ impl<K: ToGodot, V: ToGodot> FromIterator<(K, V)> for Dictionary {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Error returned by [`Dictionary::from_json()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonParseError {
+    line: Option<i32>,
+    message: String,
+}
+
+impl JsonParseError {
+    /// Line at which parsing failed, or `None` if the error doesn't refer to a line.
+    pub fn line(&self) -> Option<i32> {
+        self.line
+    }
+
+    /// Description of the error, as reported by Godot.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for JsonParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "invalid JSON at line {line}: {}", self.message),
+            None => write!(f, "invalid JSON: {}", self.message),
+        }
+    }
+}
+
+impl error::Error for JsonParseError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// Internal helper for different iterator impls -- not an iterator itself
 struct DictionaryIter<'a> {
     last_key: Option<Variant>,
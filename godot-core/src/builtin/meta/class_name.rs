@@ -18,6 +18,9 @@ use sys::Global;
 // but we don't know how many classes).
 static CACHED_STRING_NAMES: Global<HashMap<ClassName, Box<StringName>>> = Global::default();
 
+// Names only known at runtime (received from Godot), allocated once per distinct name.
+static DYNAMIC_CLASS_NAMES: Global<HashMap<String, &'static CStr>> = Global::default();
+
 /// Name of a class registered with Godot.
 ///
 /// Holds the Godot name, not the Rust name (they sometimes differ, e.g. Godot `CSGMesh3D` vs Rust `CsgMesh3D`).
@@ -49,6 +52,25 @@ impl ClassName {
         Self { c_str }
     }
 
+    /// Looks up a class name received from Godot at runtime.
+    ///
+    /// Each distinct name is allocated once and never freed, just like the `&'static CStr` of statically known classes.
+    pub(crate) fn from_godot_name(name: &StringName) -> Self {
+        let name = name.to_string();
+        if name.is_empty() {
+            return Self::none();
+        }
+
+        let mut map = DYNAMIC_CLASS_NAMES.lock();
+        let c_str = *map.entry(name).or_insert_with_key(|name| {
+            let c_string =
+                std::ffi::CString::new(name.as_str()).expect("class name contains null byte");
+            Box::leak(c_string.into_boxed_c_str())
+        });
+
+        Self { c_str }
+    }
+
     #[doc(hidden)]
     pub fn none() -> Self {
         // In Godot, an empty class name means "no class".
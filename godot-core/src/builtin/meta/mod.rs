@@ -183,6 +183,27 @@ pub trait GodotType:
     }
 }
 
+/// Integer and floating-point types that Godot represents natively, as `int` or `float`.
+///
+/// This trait is sealed. It is mostly used as a bound for generic `#[func]` methods, which are registered once per type with
+/// `#[func(instantiate = [...])]`.
+pub trait GodotNumeric: numeric_sealed::Sealed + GodotType + Copy + PartialOrd {}
+
+mod numeric_sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_godot_numeric {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl numeric_sealed::Sealed for $ty {}
+            impl GodotNumeric for $ty {}
+        )*
+    };
+}
+
+impl_godot_numeric!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
 impl<T> GodotType for Option<T>
 where
     T: GodotType,
@@ -271,6 +292,87 @@ impl PropertyInfo {
         }
     }
 
+    /// Copies the values of a property info owned by Godot.
+    ///
+    /// Returns `None` if the hint is not known to this API version, e.g. one added in a newer Godot version.
+    ///
+    /// # Safety
+    /// `info` must point to valid strings and string names, as in a property info passed by Godot.
+    pub(crate) unsafe fn try_from_sys_ref(info: &sys::GDExtensionPropertyInfo) -> Option<Self> {
+        use crate::obj::EngineBitfield as _;
+        use crate::obj::EngineEnum as _;
+
+        let hint = global::PropertyHint::try_from_ord(info.hint as i32)?;
+
+        // Godot keeps ownership of the strings, so the copies need their own ref-count.
+        let property_name = StringName::from_string_sys(info.name);
+        std::mem::forget(property_name.clone());
+
+        let class_name = StringName::from_string_sys(info.class_name);
+        std::mem::forget(class_name.clone());
+
+        let hint_string = GString::from_string_sys(info.hint_string);
+        std::mem::forget(hint_string.clone());
+
+        Some(Self {
+            variant_type: VariantType::from_sys(info.type_),
+            class_name: ClassName::from_godot_name(&class_name),
+            property_name,
+            hint,
+            hint_string,
+            usage: global::PropertyUsageFlags::from_ord(info.usage as u64),
+        })
+    }
+
+    /// Overwrites the values of a property info owned by Godot, destroying the previous strings.
+    ///
+    /// # Safety
+    /// `info` must point to valid strings and string names, as in a property info passed by Godot.
+    pub(crate) unsafe fn write_to_sys(self, info: &mut sys::GDExtensionPropertyInfo) {
+        use crate::obj::EngineBitfield as _;
+        use crate::obj::EngineEnum as _;
+
+        info.type_ = self.variant_type.sys();
+        info.hint = u32::try_from(self.hint.ord()).expect("hint.ord()");
+        info.usage = u32::try_from(self.usage.ord()).expect("usage.ord()");
+
+        self.property_name.move_string_ptr(info.name);
+        self.class_name
+            .to_string_name()
+            .move_string_ptr(info.class_name);
+        self.hint_string.move_string_ptr(info.hint_string);
+    }
+
+    /// Converts to the FFI type, moving the strings to the heap. Must be released with [`Self::free_owned_property_sys()`].
+    pub(crate) fn into_owned_property_sys(self) -> sys::GDExtensionPropertyInfo {
+        use crate::obj::EngineBitfield as _;
+        use crate::obj::EngineEnum as _;
+
+        // StringName and GString are #[repr(C)]/#[repr(transparent)] over their opaque data, so the boxes have the sys addresses.
+        let name = Box::leak(Box::new(self.property_name));
+        let class_name = Box::leak(Box::new(self.class_name.to_string_name()));
+        let hint_string = Box::leak(Box::new(self.hint_string));
+
+        sys::GDExtensionPropertyInfo {
+            type_: self.variant_type.sys(),
+            name: name.string_sys(),
+            class_name: class_name.string_sys(),
+            hint: u32::try_from(self.hint.ord()).expect("hint.ord()"),
+            hint_string: hint_string.string_sys(),
+            usage: u32::try_from(self.usage.ord()).expect("usage.ord()"),
+        }
+    }
+
+    /// Releases the strings of a property info created by [`Self::into_owned_property_sys()`].
+    ///
+    /// # Safety
+    /// `info` must come from `into_owned_property_sys()` and not have been freed before.
+    pub(crate) unsafe fn free_owned_property_sys(info: &sys::GDExtensionPropertyInfo) {
+        drop(Box::from_raw(info.name as *mut StringName));
+        drop(Box::from_raw(info.class_name as *mut StringName));
+        drop(Box::from_raw(info.hint_string as *mut GString));
+    }
+
     pub fn empty_sys() -> sys::GDExtensionPropertyInfo {
         use crate::obj::EngineBitfield as _;
         use crate::obj::EngineEnum as _;
@@ -593,6 +593,77 @@ impl<T: FromGodot> FromVariantIndirect for T {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Fast path for zero-argument getters.
+
+/// Inbound ptrcall of a `&self` method without parameters; used by `#[func]` for getters returning a scalar.
+///
+/// Equivalent to [`PtrcallSignatureTuple::in_ptrcall()`] inside [`handle_panic()`][crate::private::handle_panic], but cheaper,
+/// see [`call_getter()`].
+///
+/// # Safety
+/// `instance_ptr` must point to an instance of `C`, and `ret` must follow the requirements of [`ptrcall_return()`].
+#[doc(hidden)]
+pub unsafe fn in_getter_ptrcall<C: GodotClass, R: ToGodot>(
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    call_ctx: &CallContext,
+    ret: sys::GDExtensionTypePtr,
+    getter: fn(&C) -> R,
+) {
+    if let Some(value) = call_getter(instance_ptr, call_ctx, None, getter) {
+        ptrcall_return::<R>(value, ret, call_ctx, sys::PtrcallType::Standard);
+    }
+}
+
+/// Inbound varcall of a `&self` method without parameters; used by `#[func]` for getters returning a scalar.
+///
+/// Equivalent to [`VarcallSignatureTuple::in_varcall()`] inside [`handle_panic()`][crate::private::handle_panic], but cheaper,
+/// see [`call_getter()`].
+///
+/// # Safety
+/// `instance_ptr` must point to an instance of `C`, and `ret` and `err` must follow the requirements of [`varcall_return()`].
+#[doc(hidden)]
+pub unsafe fn in_getter_varcall<C: GodotClass, R: ToGodot>(
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    call_ctx: &CallContext,
+    arg_count: i64,
+    ret: sys::GDExtensionVariantPtr,
+    err: *mut sys::GDExtensionCallError,
+    getter: fn(&C) -> R,
+) {
+    match call_getter(instance_ptr, call_ctx, Some(arg_count), getter) {
+        Some(value) => varcall_return::<R>(value, ret, err),
+        None => {
+            // Same as after a panic in the general path.
+            (*err).error = sys::GODOT_RUST_CALL_ERROR;
+            sys::interface_fn!(variant_new_nil)(sys::AsUninit::as_uninit(ret));
+        }
+    }
+}
+
+/// Binds the instance and calls `getter` inside [`handle_panic()`][crate::private::handle_panic]. Returns `None` after a panic.
+///
+/// Compared to the general path, this skips the parameter tuple and `Variant` conversions, which dominate the cost of trivial getters.
+unsafe fn call_getter<C: GodotClass, R>(
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    call_ctx: &CallContext,
+    varcall_arg_count: Option<i64>,
+    getter: fn(&C) -> R,
+) -> Option<R> {
+    crate::private::handle_panic(
+        || call_ctx,
+        std::panic::AssertUnwindSafe(|| {
+            if let Some(arg_count) = varcall_arg_count {
+                check_arg_count(arg_count, 0, call_ctx);
+            }
+
+            let storage = crate::storage::as_storage::<C>(instance_ptr);
+            let instance = crate::storage::Storage::get(storage);
+            getter(&*instance)
+        }),
+    )
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Poor man's variadic templates.
 // For example, RenderingServer::environment_set_volumetric_fog() has 14 parameters. We may need to extend this if the API adds more such methods.
@@ -56,6 +56,8 @@ pub mod __prelude_reexport {
     pub use rect2i::*;
     pub use rid::*;
     pub use signal::*;
+    #[allow(deprecated)]
+    pub use string::GodotString;
     pub use string::{GString, NodePath, StringName};
     pub use transform2d::*;
     pub use transform3d::*;
@@ -80,7 +82,7 @@ pub mod array {
 
 /// Specialized types related to dictionaries.
 pub mod dictionary {
-    pub use super::dictionary_inner::{Iter, Keys, TypedIter, TypedKeys};
+    pub use super::dictionary_inner::{Iter, JsonParseError, Keys, TypedIter, TypedKeys};
 }
 
 /// Specialized types related to Godot's various string implementations.
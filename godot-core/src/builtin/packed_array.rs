@@ -64,6 +64,12 @@ macro_rules! impl_packed_array {
         ///
         /// See also [#godot/76150](https://github.com/godotengine/godot/issues/76150) for details.
         ///
+        /// # Conversion from `Vec`
+        ///
+        #[doc = concat!("`Vec<", stringify!($Element), ">` converts to and from `", stringify!($PackedArray), "`, so it can be used")]
+        /// directly as a `#[func]` parameter or return type. Unlike a packed array, which is shared by copy-on-write, every such conversion
+        /// copies all elements. For large data that crosses the boundary often, prefer the packed array type in the signature.
+        ///
         /// # Thread safety
         ///
         #[doc = concat!("Usage is safe if the `", stringify!($PackedArray), "`")]
@@ -503,6 +509,22 @@ macro_rules! impl_packed_array {
         }
 
         $crate::builtin::meta::impl_godot_as_self!($PackedArray);
+
+        impl $crate::builtin::meta::GodotConvert for Vec<$Element> {
+            type Via = $PackedArray;
+        }
+
+        impl ToGodot for Vec<$Element> {
+            fn to_godot(&self) -> Self::Via {
+                $PackedArray::from(self.as_slice())
+            }
+        }
+
+        impl $crate::builtin::meta::FromGodot for Vec<$Element> {
+            fn try_from_godot(via: Self::Via) -> Result<Self, $crate::builtin::meta::ConvertError> {
+                Ok(via.to_vec())
+            }
+        }
     }
 }
 
@@ -679,6 +701,56 @@ impl_packed_array!(
     },
 );
 
+impl PackedVector2Array {
+    /// Returns the signed area of the polygon described by the points in this array, using the shoelace formula.
+    ///
+    /// The area is positive if the points are in counter-clockwise order (in a Y-up coordinate system), and negative if they are clockwise.
+    /// Note that Godot's 2D coordinate system is Y-down, where this is reversed. Returns 0 if the array has fewer than 3 points.
+    pub fn to_polygon_area(&self) -> real {
+        let points = self.as_slice();
+        if points.len() < 3 {
+            return 0.0;
+        }
+
+        Self::polygon_edges(points)
+            .map(|(a, b)| a.cross(b))
+            .sum::<real>()
+            / 2.0
+    }
+
+    /// Returns the centroid (center of mass) of the polygon described by the points in this array.
+    ///
+    /// Returns [`Vector2::ZERO`] if the array has fewer than 3 points, or if the polygon has no area.
+    pub fn to_polygon_centroid(&self) -> Vector2 {
+        let points = self.as_slice();
+        if points.len() < 3 {
+            return Vector2::ZERO;
+        }
+
+        let mut twice_area = 0.0;
+        let mut weighted_sum = Vector2::ZERO;
+        for (a, b) in Self::polygon_edges(points) {
+            let cross = a.cross(b);
+            twice_area += cross;
+            weighted_sum += (a + b) * cross;
+        }
+
+        if twice_area == 0.0 {
+            return Vector2::ZERO;
+        }
+
+        weighted_sum / (3.0 * twice_area)
+    }
+
+    /// Iterates over all edges `(points[i], points[i + 1])` of the closed polygon, including the one from the last to the first point.
+    fn polygon_edges(points: &[Vector2]) -> impl Iterator<Item = (Vector2, Vector2)> + '_ {
+        points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+    }
+}
+
 impl_packed_array!(
     type_name: PackedVector3Array,
     element_type: Vector3,
@@ -54,8 +54,11 @@ impl Quaternion {
     // pub fn from_vector_vector(arc_to: Vector3, arc_from: Vector3) -> Self {}
     // pub fn from_basis(basis: Basis) -> Self {}
 
+    /// Returns the angle (in radians) of the rotation from this quaternion to `to`.
     pub fn angle_to(self, to: Self) -> real {
-        self.glam2(&to, RQuat::angle_between)
+        // Same formula as Godot; equivalent to 2 * acos(|d|), but well-defined for slight rounding errors.
+        let d = self.dot(to);
+        (d * d * 2.0 - 1.0).clamp(-1.0, 1.0).acos()
     }
 
     pub fn dot(self, with: Self) -> real {
@@ -109,6 +112,13 @@ impl Quaternion {
         }
     }
 
+    /// Returns the rotation axis and angle (in radians) of this quaternion.
+    ///
+    /// Inverse of [`from_axis_angle()`][Self::from_axis_angle] for normalized quaternions.
+    pub fn to_axis_angle(self) -> (Vector3, real) {
+        (self.get_axis(), self.get_angle())
+    }
+
     pub fn to_euler(self, order: EulerOrder) -> Vector3 {
         Basis::from_quat(self).to_euler(order)
     }
@@ -155,6 +165,21 @@ impl Quaternion {
         self.as_inner().slerp(to, weight.as_f64())
     }
 
+    /// Rotates from this quaternion toward `to` by at most `delta` radians, without overshooting.
+    ///
+    /// Like [`slerp()`][Self::slerp], but with a fixed angular step instead of a weight.
+    ///
+    /// # Panics
+    /// If either quaternion is not normalized.
+    pub fn rotate_toward(self, to: Self, delta: real) -> Self {
+        let angle = self.angle_to(to);
+        if angle.is_zero_approx() {
+            return to;
+        }
+
+        self.slerp(to, (delta / angle).min(1.0))
+    }
+
     /// # Panics
     /// If either quaternion is not normalized.
     pub fn slerpni(self, to: Self, weight: real) -> Self {
@@ -17,6 +17,10 @@ pub use gstring::*;
 pub use node_path::NodePath;
 pub use string_name::{StringName, TransientStringNameOrd};
 
+/// Former name of [`GString`], kept for compatibility.
+#[deprecated = "Renamed to `GString`."]
+pub type GodotString = GString;
+
 use super::meta::{ConvertError, FromGodot, GodotConvert, ToGodot};
 
 impl GodotConvert for &str {
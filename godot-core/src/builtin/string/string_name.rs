@@ -120,6 +120,14 @@ impl StringName {
         fn string_sys = sys;
     }
 
+    /// Move `self` into a system pointer. This transfers ownership and thus does not call the destructor.
+    ///
+    /// # Safety
+    /// `dst` must be a pointer to a `StringName` which is suitable for ffi with Godot.
+    pub(crate) unsafe fn move_string_ptr(self, dst: sys::GDExtensionStringNamePtr) {
+        self.move_return_ptr(dst as *mut _, sys::PtrcallType::Standard);
+    }
+
     #[doc(hidden)]
     pub fn string_sys_const(&self) -> sys::GDExtensionConstStringNamePtr {
         sys::to_const_ptr(self.string_sys())
@@ -141,6 +141,35 @@ impl Transform3D {
         }
     }
 
+    /// Returns a transform interpolated between this transform and another by
+    /// a given weight (on the range of 0.0 to 1.0).
+    ///
+    /// The basis is interpolated with [`Basis::slerp()`], the origin linearly. Unlike
+    /// [`interpolate_with()`](Self::interpolate_with), scale is interpolated per row of the basis.
+    #[must_use]
+    pub fn lerp(self, other: Self, weight: real) -> Self {
+        Self {
+            basis: self.basis.slerp(other.basis, weight),
+            origin: self.origin.lerp(other.origin, weight),
+        }
+    }
+
+    /// Returns a transform spherically interpolated between this transform and another by
+    /// a given weight (on the range of 0.0 to 1.0).
+    ///
+    /// Only the rotation of the basis is interpolated; the result has an orthonormal basis, i.e. scale is discarded.
+    /// The origin is interpolated linearly. Use [`interpolate_with()`](Self::interpolate_with) to preserve scale.
+    #[must_use]
+    pub fn slerp(self, other: Self, weight: real) -> Self {
+        let src_rot = self.basis.to_quat().normalized();
+        let dst_rot = other.basis.to_quat().normalized();
+
+        Self {
+            basis: Basis::from_quat(src_rot.slerp(dst_rot, weight)),
+            origin: self.origin.lerp(other.origin, weight),
+        }
+    }
+
     /// Returns true if this transform is finite by calling `is_finite` on the
     /// basis and origin.
     pub fn is_finite(&self) -> bool {
@@ -95,6 +95,9 @@ impl Vector2 {
         self.to_glam().angle_between(to.to_glam())
     }
 
+    /// Returns the angle (in radians) of the line from `self` to `to`, measured from the positive X axis.
+    ///
+    /// Useful for aiming: `from_angle(a.angle_to_point(b))` is the direction from `a` to `b`.
     pub fn angle_to_point(self, to: Self) -> real {
         (to - self).angle()
     }
@@ -139,6 +142,7 @@ impl Vector2 {
         Self::from_glam(self.to_glam().floor())
     }
 
+    /// Creates a unit vector rotated by `angle` (in radians) from the positive X axis.
     pub fn from_angle(angle: real) -> Self {
         Self::from_glam(RVec2::from_angle(angle))
     }
@@ -185,10 +189,18 @@ impl Vector2 {
         }
     }
 
+    /// Returns a perpendicular vector of the same length, rotated 90 degrees counter-clockwise.
     pub fn orthogonal(self) -> Self {
         Self::new(self.y, -self.x)
     }
 
+    /// Returns a perpendicular vector of the same length, rotated 90 degrees counter-clockwise.
+    ///
+    /// Same as [`orthogonal()`][Self::orthogonal].
+    pub fn perpendicular(self) -> Self {
+        self.orthogonal()
+    }
+
     pub fn project(self, b: Self) -> Self {
         Self::from_glam(self.to_glam().project_onto(b.to_glam()))
     }
@@ -290,6 +302,25 @@ mod test {
         assert_eq_approx!(a.coord_max(b), Vector2::new(1.2, 5.6));
     }
 
+    #[test]
+    fn angles() {
+        use crate::builtin::real_consts::{FRAC_PI_2, FRAC_PI_4};
+
+        let a = Vector2::new(1.0, 1.0);
+        assert_eq_approx!(a.angle_to_point(Vector2::new(2.0, 2.0)), FRAC_PI_4);
+        assert_eq_approx!(a.angle_to_point(Vector2::new(1.0, 3.0)), FRAC_PI_2);
+
+        assert_eq_approx!(Vector2::from_angle(0.0), Vector2::RIGHT);
+        assert_eq_approx!(Vector2::from_angle(FRAC_PI_2), Vector2::DOWN);
+
+        assert_eq_approx!(Vector2::RIGHT.rotated(FRAC_PI_2), Vector2::DOWN);
+        assert_eq_approx!(Vector2::new(3.0, 4.0).orthogonal(), Vector2::new(4.0, -3.0));
+        assert_eq_approx!(
+            Vector2::new(3.0, 4.0).perpendicular(),
+            Vector2::new(4.0, -3.0)
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
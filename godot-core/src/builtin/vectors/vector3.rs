@@ -149,6 +149,7 @@ impl Vector3 {
         self.to_glam().length_squared()
     }
 
+    /// Returns the vector with a maximum length by limiting its length to `length` (1.0 if `None`), keeping its direction.
     pub fn limit_length(self, length: Option<real>) -> Self {
         Self::from_glam(self.to_glam().clamp_length_max(length.unwrap_or(1.0)))
     }
@@ -181,6 +182,7 @@ impl Vector3 {
         }
     }
 
+    /// Returns a new vector moved toward `to` by the fixed `delta` amount. Will not go past the final value.
     pub fn move_toward(self, to: Self, delta: real) -> Self {
         let vd = to - self;
         let len = vd.length();
@@ -203,9 +205,13 @@ impl Vector3 {
         Self::from_glam(self.to_glam().round())
     }
 
+    /// Returns the signed angle to the given vector, in radians.
+    ///
+    /// The sign of the angle is positive in a counter-clockwise direction and negative in a clockwise direction, when viewed from
+    /// the side specified by `axis`.
     pub fn signed_angle_to(self, to: Self, axis: Self) -> real {
         let cross_to = self.cross(to);
-        let unsigned_angle = self.dot(to).atan2(cross_to.length());
+        let unsigned_angle = cross_to.length().atan2(self.dot(to));
         let sign = cross_to.dot(axis);
         if sign < 0.0 {
             -unsigned_angle
@@ -414,6 +420,51 @@ mod test {
         assert_eq_approx!(vector1.slerp(vector2, 0.5).length(), real!(6.258_311));
     }
 
+    // Translated from Godot
+    #[test]
+    fn signed_angle_to() {
+        let vector_x = Vector3::new(1.0, 0.0, 0.0);
+        let vector_y = Vector3::new(0.0, 1.0, 0.0);
+        let vector_yz = Vector3::new(0.0, 1.0, 1.0);
+
+        assert_eq_approx!(vector_x.signed_angle_to(vector_y, vector_y), TAU / 4.0);
+        assert_eq_approx!(vector_x.signed_angle_to(vector_yz, vector_y), TAU / -4.0);
+        assert_eq_approx!(vector_yz.signed_angle_to(vector_x, vector_y), TAU / 4.0);
+    }
+
+    #[test]
+    fn limit_length() {
+        let vector = Vector3::new(10.0, 10.0, 10.0);
+        assert_eq_approx!(
+            vector.limit_length(None),
+            Vector3::splat(1.0 / real!(3.0).sqrt())
+        );
+        assert_eq_approx!(
+            vector.limit_length(Some(5.0)),
+            Vector3::splat(5.0 / real!(3.0).sqrt())
+        );
+
+        // Shorter vectors are unchanged.
+        let vector = Vector3::new(0.1, 0.2, 0.3);
+        assert_eq_approx!(vector.limit_length(None), vector);
+        assert_eq!(Vector3::ZERO.limit_length(None), Vector3::ZERO);
+    }
+
+    // Translated from Godot
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn move_toward() {
+        let vector1 = Vector3::new(1.0, 2.0, 3.0);
+        let vector2 = Vector3::new(4.0, 5.0, 6.0);
+
+        assert_eq_approx!(
+            vector1.move_toward(vector2, 1.0),
+            Vector3::new(1.5773502691896, 2.5773502691896, 3.5773502691896)
+        );
+        assert_eq_approx!(vector1.move_toward(vector2, 100.0), vector2);
+        assert_eq_approx!(vector1.move_toward(vector1, 1.0), vector1);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
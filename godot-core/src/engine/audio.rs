@@ -0,0 +1,72 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{Callable, Signal, StringName};
+use crate::engine::object::ConnectFlags;
+use crate::engine::{AudioStreamPlayer, SceneTreeTimer};
+use crate::obj::{EngineBitfield, Gd};
+
+/// Metadata key under which the pending timer of [`AudioStreamPlayerExt::play_after()`] is stored.
+const DELAYED_PLAY_META: &str = "_gdext_delayed_play_timer";
+
+/// Extension trait for delayed playback of audio.
+pub trait AudioStreamPlayerExt {
+    /// Starts playback after `delay_seconds`, using a [`SceneTreeTimer`].
+    ///
+    /// A previously scheduled delayed playback is cancelled. The timer runs in the player's scene tree, so it is affected by pausing
+    /// according to the default `SceneTree::create_timer()` arguments.
+    ///
+    /// # Panics
+    /// If the player is not inside the scene tree.
+    fn play_after(&mut self, delay_seconds: f64);
+
+    /// Cancels the playback scheduled by [`play_after()`][Self::play_after], if any.
+    ///
+    /// The timer is disconnected and released; it cannot be freed explicitly, as `SceneTreeTimer` is reference-counted.
+    fn cancel_delayed_play(&mut self);
+}
+
+impl AudioStreamPlayerExt for Gd<AudioStreamPlayer> {
+    fn play_after(&mut self, delay_seconds: f64) {
+        self.cancel_delayed_play();
+
+        let mut tree = self
+            .get_tree()
+            .expect("play_after() requires the AudioStreamPlayer to be inside the scene tree");
+        let timer = tree
+            .create_timer(delay_seconds)
+            .expect("SceneTree::create_timer() returned null");
+
+        // One-shot, so that the connection does not outlive the timeout.
+        Signal::from_object_signal(&timer, "timeout").connect(
+            Callable::from_object_method(self, "play"),
+            ConnectFlags::ONE_SHOT.ord() as i64,
+        );
+
+        self.set_meta(DELAYED_PLAY_META.into(), timer.to_variant());
+    }
+
+    fn cancel_delayed_play(&mut self) {
+        let meta = StringName::from(DELAYED_PLAY_META);
+        if !self.has_meta(meta.clone()) {
+            return;
+        }
+
+        let timer = self.get_meta(meta.clone()).try_to::<Gd<SceneTreeTimer>>();
+        self.remove_meta(meta);
+
+        if let Ok(timer) = timer {
+            let timeout = Signal::from_object_signal(&timer, "timeout");
+            let play = Callable::from_object_method(self, "play");
+
+            if timeout.is_connected(play.clone()) {
+                timeout.disconnect(play);
+            }
+        }
+    }
+}
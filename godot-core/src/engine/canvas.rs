@@ -0,0 +1,183 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{
+    Color, PackedColorArray, PackedInt32Array, PackedVector2Array, Rid, Transform2D, Vector2,
+};
+use crate::engine::{RenderingServer, Texture2D};
+use crate::obj::Gd;
+
+/// Batches many sprites with the same texture into a single 2D draw call.
+///
+/// Drawing each sprite on its own, e.g. with a `Sprite2D` node or `CanvasItem::draw_texture()`, issues one draw command per sprite.
+/// For particle-like effects with thousands of sprites, this overhead dominates. `SpriteBatch2D` instead accumulates the quads of
+/// all sprites and submits them with one [`RenderingServer::canvas_item_add_triangle_array()`] call.
+///
+/// Sprites are drawn centered on their position, in the size of the texture. Batches are typically rebuilt every frame: call
+/// [`clear()`][Self::clear], add the sprites, then [`draw()`][Self::draw] from the canvas item's `draw()` callback.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{INode2D, Node2D, Texture2D};
+/// use godot::engine::SpriteBatch2D;
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Node2D)]
+/// struct Sparks {
+///     batch: SpriteBatch2D,
+///     positions: PackedVector2Array,
+///     tints: PackedColorArray,
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode2D for Sparks {
+///     fn init(base: Base<Node2D>) -> Self {
+///         Self {
+///             batch: SpriteBatch2D::new(load::<Texture2D>("res://spark.png")),
+///             positions: PackedVector2Array::new(),
+///             tints: PackedColorArray::new(),
+///             base,
+///         }
+///     }
+///
+///     fn process(&mut self, _delta: f64) {
+///         // Update positions and tints...
+///         self.base_mut().queue_redraw();
+///     }
+///
+///     fn draw(&mut self) {
+///         self.batch.clear();
+///         self.batch.add_sprites(&self.positions, &self.tints);
+///
+///         let canvas_item = self.base().get_canvas_item();
+///         self.batch.draw(canvas_item);
+///     }
+/// }
+/// ```
+pub struct SpriteBatch2D {
+    texture: Gd<Texture2D>,
+    half_size: Vector2,
+    // Kept in Rust and converted once in draw(), as each element pushed to a packed array is an FFI call.
+    indices: Vec<i32>,
+    points: Vec<Vector2>,
+    colors: Vec<Color>,
+    uvs: Vec<Vector2>,
+}
+
+impl SpriteBatch2D {
+    /// Creates an empty batch, drawing all sprites with `texture`.
+    pub fn new(texture: Gd<Texture2D>) -> Self {
+        let half_size = texture.get_size() / 2.0;
+
+        Self {
+            texture,
+            half_size,
+            indices: Vec::new(),
+            points: Vec::new(),
+            colors: Vec::new(),
+            uvs: Vec::new(),
+        }
+    }
+
+    /// Adds a sprite, transformed by `transform` and modulated by `tint`.
+    pub fn add_sprite(&mut self, transform: Transform2D, tint: Color) {
+        self.push_quad(|corner| transform * corner, tint);
+    }
+
+    /// Adds one untransformed sprite per position, modulated by the tint at the same index.
+    ///
+    /// # Panics
+    /// If `positions` and `tints` have different lengths.
+    pub fn add_sprites(&mut self, positions: &PackedVector2Array, tints: &PackedColorArray) {
+        assert_eq!(
+            positions.len(),
+            tints.len(),
+            "SpriteBatch2D::add_sprites(): need one tint per position"
+        );
+
+        let count = positions.len();
+        self.indices.reserve(count * 6);
+        self.points.reserve(count * 4);
+        self.colors.reserve(count * 4);
+        self.uvs.reserve(count * 4);
+
+        for (&position, &tint) in positions.as_slice().iter().zip(tints.as_slice()) {
+            self.push_quad(|corner| position + corner, tint);
+        }
+    }
+
+    /// Number of sprites in the batch.
+    pub fn len(&self) -> usize {
+        self.points.len() / 4
+    }
+
+    /// Whether the batch contains no sprites.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Removes all sprites, keeping the texture.
+    pub fn clear(&mut self) {
+        self.indices.clear();
+        self.points.clear();
+        self.colors.clear();
+        self.uvs.clear();
+    }
+
+    /// The texture all sprites are drawn with.
+    pub fn texture(&self) -> &Gd<Texture2D> {
+        &self.texture
+    }
+
+    /// Submits all sprites to `canvas_item` in a single draw call.
+    ///
+    /// Like other canvas item commands, this must happen while the item is redrawn, typically in `CanvasItem::draw()`. Nothing is
+    /// submitted for an empty batch.
+    pub fn draw(&self, canvas_item: Rid) {
+        if self.is_empty() {
+            return;
+        }
+
+        RenderingServer::singleton()
+            .canvas_item_add_triangle_array_ex(
+                canvas_item,
+                PackedInt32Array::from(self.indices.as_slice()),
+                PackedVector2Array::from(self.points.as_slice()),
+                PackedColorArray::from(self.colors.as_slice()),
+            )
+            .uvs(PackedVector2Array::from(self.uvs.as_slice()))
+            .texture(self.texture.get_rid())
+            .done();
+    }
+
+    /// Appends the two triangles of a sprite, with its centered corners mapped by `place`.
+    fn push_quad(&mut self, place: impl Fn(Vector2) -> Vector2, tint: Color) {
+        let Vector2 { x, y } = self.half_size;
+        let corners = [
+            Vector2::new(-x, -y),
+            Vector2::new(x, -y),
+            Vector2::new(x, y),
+            Vector2::new(-x, y),
+        ];
+        let uvs = [
+            Vector2::new(0.0, 0.0),
+            Vector2::new(1.0, 0.0),
+            Vector2::new(1.0, 1.0),
+            Vector2::new(0.0, 1.0),
+        ];
+
+        let first = i32::try_from(self.points.len()).expect("too many sprites in batch");
+        self.indices
+            .extend([0, 1, 2, 0, 2, 3].map(|index| first + index));
+
+        self.points.extend(corners.map(place));
+        self.colors.extend([tint; 4]);
+        self.uvs.extend(uvs);
+    }
+}
@@ -0,0 +1,254 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{real, Dictionary, PackedInt32Array, PackedVector3Array, Vector3};
+use crate::engine::mesh::{ArrayType, PrimitiveType};
+use crate::engine::{ArrayMesh, Mesh, MeshInstance3D};
+use crate::obj::{EngineEnum, Gd, NewAlloc, NewGd};
+
+/// Creates a [`MeshInstance3D`] whose mesh carries automatically generated levels of detail (LODs).
+///
+/// Each LOD is a lower-poly index buffer of the base mesh's surfaces, which Godot selects depending on the camera distance. The
+/// simplification is a basic vertex clustering: vertices are snapped to a grid, and triangles collapsing to a line or point are removed.
+/// This is much cruder than the quadric-based simplification Godot applies when importing meshes, but needs no import step, e.g. for
+/// procedurally generated meshes.
+///
+/// Only triangle surfaces receive LODs; other surfaces, blend shapes and existing LODs of the base mesh are not simplified.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{LodMeshBuilder, Mesh};
+///
+/// let rock = load::<Mesh>("res://rock.obj");
+/// let instance = LodMeshBuilder::new(rock)
+///     .add_lod(10.0, 0.5)
+///     .add_lod(40.0, 0.9)
+///     .lod_bias(1.5)
+///     .build();
+/// ```
+#[must_use]
+pub struct LodMeshBuilder {
+    base_mesh: Gd<Mesh>,
+    lods: Vec<(f32, f32)>,
+    lod_bias: f32,
+}
+
+impl LodMeshBuilder {
+    /// Starts a builder for `base_mesh`, without any LODs and with a LOD bias of 1.0.
+    pub fn new(base_mesh: Gd<Mesh>) -> Self {
+        Self {
+            base_mesh,
+            lods: Vec::new(),
+            lod_bias: 1.0,
+        }
+    }
+
+    /// Adds a LOD used from `distance` onwards, which removes roughly the fraction `reduction` of the base mesh's vertices.
+    ///
+    /// `distance` is the threshold that Godot's LOD selection compares against (see `ArrayMesh.add_surface_from_arrays()`).
+    /// LODs that would leave no triangle are skipped.
+    ///
+    /// # Panics
+    /// If `distance` is not positive, or `reduction` is not in `[0, 1)`.
+    pub fn add_lod(mut self, distance: f32, reduction: f32) -> Self {
+        assert!(
+            distance > 0.0,
+            "LodMeshBuilder::add_lod(): distance must be positive, got {distance}"
+        );
+        assert!(
+            (0.0..1.0).contains(&reduction),
+            "LodMeshBuilder::add_lod(): reduction must be in [0, 1), got {reduction}"
+        );
+
+        self.lods.push((distance, reduction));
+        self
+    }
+
+    /// Sets `GeometryInstance3D.lod_bias` of the created instance; values above 1.0 keep higher LODs for longer.
+    pub fn lod_bias(mut self, lod_bias: f32) -> Self {
+        self.lod_bias = lod_bias;
+        self
+    }
+
+    /// Creates the simplified mesh and a `MeshInstance3D` showing it, which is not yet part of the scene tree.
+    pub fn build(self) -> Gd<MeshInstance3D> {
+        let mut instance = MeshInstance3D::new_alloc();
+        instance.set_mesh(self.build_mesh().upcast());
+        instance.set_lod_bias(self.lod_bias);
+        instance
+    }
+
+    /// Copies all surfaces of the base mesh into a new `ArrayMesh`, with the LOD index buffers attached.
+    fn build_mesh(&self) -> Gd<ArrayMesh> {
+        let vertex_index = ArrayType::ARRAY_VERTEX.ord() as usize;
+        let index_index = ArrayType::ARRAY_INDEX.ord() as usize;
+
+        // Only `ArrayMesh` surfaces can have non-triangle primitives; e.g. `PrimitiveMesh` surfaces are always triangles.
+        let base_array_mesh = self.base_mesh.clone().try_cast::<ArrayMesh>().ok();
+
+        let mut mesh = ArrayMesh::new_gd();
+        for surface in 0..self.base_mesh.get_surface_count() {
+            let mut arrays = self.base_mesh.surface_get_arrays(surface);
+            let primitive = base_array_mesh
+                .as_ref()
+                .map_or(PrimitiveType::PRIMITIVE_TRIANGLES, |base| {
+                    base.surface_get_primitive_type(surface)
+                });
+
+            let mut lods = Dictionary::new();
+            if primitive == PrimitiveType::PRIMITIVE_TRIANGLES {
+                let vertices = arrays.get(vertex_index).to::<PackedVector3Array>();
+
+                // LODs are index buffers, so non-indexed surfaces get the implicit one made explicit.
+                let indices = match arrays.get(index_index).try_to::<PackedInt32Array>() {
+                    Ok(indices) => indices,
+                    Err(_) => {
+                        let len =
+                            i32::try_from(vertices.len()).expect("too many vertices in surface");
+                        let indices: PackedInt32Array = (0..len).collect();
+                        arrays.set(index_index, indices.to_variant());
+                        indices
+                    }
+                };
+
+                for &(distance, reduction) in &self.lods {
+                    if let Some(lod) =
+                        cluster_vertices(vertices.as_slice(), indices.as_slice(), reduction)
+                    {
+                        lods.set(distance, PackedInt32Array::from(lod.as_slice()));
+                    }
+                }
+            }
+
+            mesh.add_surface_from_arrays_ex(primitive, arrays)
+                .lods(lods)
+                .done();
+
+            if let Some(material) = self.base_mesh.surface_get_material(surface) {
+                mesh.surface_set_material(surface, material);
+            }
+        }
+
+        mesh
+    }
+}
+
+/// Returns the triangle indices after merging vertices on a grid, which keeps about `1 - reduction` of them.
+///
+/// Each grid cell is represented by the first vertex inside it. `None` if no triangle remains.
+fn cluster_vertices(vertices: &[Vector3], indices: &[i32], reduction: f32) -> Option<Vec<i32>> {
+    let first = *vertices.first()?;
+    let (min, max) = vertices.iter().fold((first, first), |(min, max), &v| {
+        (min.coord_min(v), max.coord_max(v))
+    });
+
+    let extent = max - min;
+    let longest = extent.x.max(extent.y).max(extent.z);
+    if longest <= 0.0 {
+        return None;
+    }
+
+    // Meshes are surfaces, so their vertex count grows with the square of the grid resolution.
+    let target_vertices = (vertices.len() as real * (1.0 - reduction as real)).max(1.0);
+    let cell_size = longest / target_vertices.sqrt().ceil();
+
+    let mut representatives = HashMap::new();
+    let remap: Vec<i32> = vertices
+        .iter()
+        .enumerate()
+        .map(|(index, &v)| {
+            let cell = ((v - min) / cell_size).floor();
+            let key = (cell.x as i64, cell.y as i64, cell.z as i64);
+            *representatives.entry(key).or_insert(index as i32)
+        })
+        .collect();
+
+    let mut lod = Vec::new();
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| remap[triangle[i] as usize]);
+        if a != b && b != c && a != c {
+            lod.extend([a, b, c]);
+        }
+    }
+
+    (!lod.is_empty()).then_some(lod)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Flat `n * n` grid with unit spacing, split into two triangles per cell.
+    fn make_grid(n: i32) -> (Vec<Vector3>, Vec<i32>) {
+        let vertices = (0..n * n)
+            .map(|i| Vector3::new((i % n) as real, (i / n) as real, 0.0))
+            .collect();
+
+        let mut indices = Vec::new();
+        for y in 0..n - 1 {
+            for x in 0..n - 1 {
+                let i = y * n + x;
+                indices.extend([i, i + 1, i + n, i + 1, i + n + 1, i + n]);
+            }
+        }
+
+        (vertices, indices)
+    }
+
+    #[test]
+    fn cluster_vertices_reduces() {
+        let (vertices, indices) = make_grid(10);
+        let lod = cluster_vertices(&vertices, &indices, 0.75).expect("triangles remain");
+
+        assert_eq!(lod.len() % 3, 0);
+        assert!(lod.len() < indices.len());
+
+        let mut used = lod.clone();
+        used.sort_unstable();
+        used.dedup();
+        assert!(
+            used.len() < vertices.len() / 2,
+            "{} vertices kept",
+            used.len()
+        );
+    }
+
+    #[test]
+    fn cluster_vertices_removes_degenerate_triangles() {
+        let vertices = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(10.0, 0.0, 0.0),
+            Vector3::new(0.0, 10.0, 0.0),
+            Vector3::new(0.01, 0.0, 0.0),
+            Vector3::new(0.0, 0.01, 0.0),
+        ];
+        let indices = [0, 1, 2, 0, 3, 4, 1, 3, 2];
+
+        // 3 and 4 merge into 0: the second triangle collapses to a point and is removed, the third one is remapped.
+        let lod = cluster_vertices(&vertices, &indices, 0.5);
+        assert_eq!(lod, Some(vec![0, 1, 2, 1, 0, 2]));
+    }
+
+    #[test]
+    fn cluster_vertices_empty_result() {
+        // All vertices in one point.
+        let vertices = [Vector3::ONE; 3];
+        assert_eq!(cluster_vertices(&vertices, &[0, 1, 2], 0.5), None);
+
+        // Triangle collapsing to a line.
+        let vertices = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(10.0, 0.0, 0.0),
+            Vector3::new(10.0, 0.01, 0.0),
+        ];
+        assert_eq!(cluster_vertices(&vertices, &[0, 1, 2], 0.5), None);
+    }
+}
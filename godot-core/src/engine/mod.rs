@@ -7,21 +7,36 @@
 
 //! Godot engine classes and methods.
 
+use crate::builtin::meta::ToGodot;
 use crate::builtin::{GString, NodePath};
-use crate::obj::{bounds, Bounds, Gd, GodotClass, Inherits, InstanceId};
+use crate::obj::{bounds, Bounds, Gd, GodotClass, Inherits, InstanceId, NewAlloc};
 
 // Re-exports of generated symbols
 pub use crate::gen::central::global;
 pub use crate::gen::classes::*;
 pub use crate::gen::utilities;
+pub use audio::AudioStreamPlayerExt;
+pub use canvas::SpriteBatch2D;
 pub use io::*;
+pub use lod::LodMeshBuilder;
+pub use physics::{
+    CharacterBody3DExt, MoveAndSlideConfig, PhysicsDirectSpaceState3DExt, RaycastHit,
+};
 pub use script_instance::{create_script_instance, ScriptInstance};
+pub use shortcut::ShortcutHandler;
+pub use tile::{GridMapExt, TileMapExt, UsedCells};
 
 use crate::builtin::meta::CallContext;
 use crate::sys;
 
+mod audio;
+mod canvas;
 mod io;
+mod lod;
+mod physics;
 mod script_instance;
+mod shortcut;
+mod tile;
 pub mod translate;
 
 #[cfg(debug_assertions)]
@@ -73,6 +88,10 @@ impl PackedSceneExt for PackedScene {
 pub trait NodeExt {
     /// Retrieves the node at path `path`, panicking if not found or bad type.
     ///
+    /// If the node at `path` is an `InstancePlaceholder` of a scene that has not been loaded yet, the panic message says so,
+    /// instead of reporting a type mismatch. Use [`try_get_node_as()`][Self::try_get_node_as] for deferred-loaded nodes,
+    /// which returns `None` for placeholders (unless `T` is `InstancePlaceholder` itself).
+    ///
     /// # Panics
     /// If the node is not found, or if it does not have type `T` or inherited.
     fn get_node_as<T>(&self, path: impl Into<NodePath>) -> Gd<T>
@@ -80,14 +99,28 @@ pub trait NodeExt {
         T: GodotClass + Inherits<Node>,
     {
         let path = path.into();
-        let copy = path.clone(); // TODO avoid copy
 
-        self.try_get_node_as(path).unwrap_or_else(|| {
-            panic!(
-                "There is no node of type {ty} path `{copy}`",
+        match self.try_get_node_as::<Node>(path.clone()) {
+            Some(node) => node.try_cast::<T>().unwrap_or_else(|node| {
+                if node.is_class("InstancePlaceholder".into()) {
+                    panic!(
+                        "Node at path `{path}` is an InstancePlaceholder, not yet instantiated as {ty}; \
+                        call create_instance() on the placeholder before accessing it",
+                        ty = T::class_name()
+                    )
+                }
+
+                panic!(
+                    "Node at path `{path}` has type {actual}, expected {ty}",
+                    actual = node.get_class(),
+                    ty = T::class_name()
+                )
+            }),
+            None => panic!(
+                "There is no node of type {ty} path `{path}`",
                 ty = T::class_name()
-            )
-        })
+            ),
+        }
     }
 
     /// Retrieves the node at path `path` (fallible).
@@ -97,6 +130,44 @@ pub trait NodeExt {
     fn try_get_node_as<T>(&self, path: impl Into<NodePath>) -> Option<Gd<T>>
     where
         T: GodotClass + Inherits<Node>;
+
+    /// Returns the direct child `name` of type `T`, creating and adding it first if there is no such child.
+    ///
+    /// Calling this repeatedly with the same name yields the same node, which is useful in tool scripts and procedural scene
+    /// construction. A newly created child is allocated with [`NewAlloc::new_alloc()`], named `name` and added via `add_child()`.
+    ///
+    /// # Panics
+    /// If a child `name` exists, but does not have type `T` or inherited. Adding another node would make Godot rename it.
+    fn get_or_add_child<T>(&mut self, name: &str) -> Gd<T>
+    where
+        T: GodotClass + Inherits<Node> + NewAlloc;
+
+    /// Adds `child` at the end of the current frame, via `call_deferred("add_child", ...)`.
+    ///
+    /// Use this instead of `add_child()` where the scene tree may not be modified, e.g. in physics callbacks or some signal handlers.
+    fn add_child_deferred(&mut self, child: Gd<Node>);
+
+    /// Removes `child` at the end of the current frame, via `call_deferred("remove_child", ...)`.
+    ///
+    /// The child is not freed; it is up to the caller to keep or free it.
+    fn remove_child_deferred(&mut self, child: Gd<Node>);
+
+    /// Moves this node under `new_parent` at the end of the current frame, via `call_deferred("reparent", ...)`.
+    fn queue_reparent(&mut self, new_parent: Gd<Node>);
+
+    /// Returns all direct children that have type `T` or inherited, in tree order.
+    ///
+    /// Internal children are not included. For the whole subtree, use [`descendants_of_type()`][Self::descendants_of_type].
+    fn children_of_type<T>(&self) -> Vec<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>;
+
+    /// Returns all nodes in the subtree below this node that have type `T` or inherited, in depth-first pre-order.
+    ///
+    /// Children of nodes that do not match `T` are still searched. This node itself is not included, nor are internal children.
+    fn descendants_of_type<T>(&self) -> Vec<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>;
 }
 
 impl NodeExt for Node {
@@ -110,6 +181,59 @@ impl NodeExt for Node {
         self.get_node_or_null(path)
             .and_then(|node| node.try_cast::<T>().ok())
     }
+
+    fn get_or_add_child<T>(&mut self, name: &str) -> Gd<T>
+    where
+        T: GodotClass + Inherits<Node> + NewAlloc,
+    {
+        if let Some(existing) = self.get_node_or_null(name.into()) {
+            return existing.try_cast::<T>().unwrap_or_else(|node| {
+                panic!(
+                    "Child `{name}` has type {actual}, expected {ty}",
+                    actual = node.get_class(),
+                    ty = T::class_name()
+                )
+            });
+        }
+
+        let child = T::new_alloc();
+        let mut node = child.clone().upcast::<Node>();
+        node.set_name(name.into());
+        self.add_child(node);
+
+        child
+    }
+
+    fn add_child_deferred(&mut self, child: Gd<Node>) {
+        self.call_deferred("add_child".into(), &[child.to_variant()]);
+    }
+
+    fn remove_child_deferred(&mut self, child: Gd<Node>) {
+        self.call_deferred("remove_child".into(), &[child.to_variant()]);
+    }
+
+    fn queue_reparent(&mut self, new_parent: Gd<Node>) {
+        self.call_deferred("reparent".into(), &[new_parent.to_variant()]);
+    }
+
+    fn children_of_type<T>(&self) -> Vec<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        self.get_children()
+            .iter_shared()
+            .filter_map(|child| child.try_cast::<T>().ok())
+            .collect()
+    }
+
+    fn descendants_of_type<T>(&self) -> Vec<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        let mut descendants = Vec::new();
+        collect_descendants(self, &mut descendants);
+        descendants
+    }
 }
 
 impl<U> NodeExt for Gd<U>
@@ -129,6 +253,58 @@ where
 
         <Node as NodeExt>::try_get_node_as(&*node, path)
     }
+
+    fn get_or_add_child<T>(&mut self, name: &str) -> Gd<T>
+    where
+        T: GodotClass + Inherits<Node> + NewAlloc,
+    {
+        let mut node = self.clone().upcast::<Node>();
+        <Node as NodeExt>::get_or_add_child(&mut *node, name)
+    }
+
+    fn add_child_deferred(&mut self, child: Gd<Node>) {
+        let mut node = self.clone().upcast::<Node>();
+        <Node as NodeExt>::add_child_deferred(&mut *node, child)
+    }
+
+    fn remove_child_deferred(&mut self, child: Gd<Node>) {
+        let mut node = self.clone().upcast::<Node>();
+        <Node as NodeExt>::remove_child_deferred(&mut *node, child)
+    }
+
+    fn queue_reparent(&mut self, new_parent: Gd<Node>) {
+        let mut node = self.clone().upcast::<Node>();
+        <Node as NodeExt>::queue_reparent(&mut *node, new_parent)
+    }
+
+    fn children_of_type<T>(&self) -> Vec<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        let node = self.clone().upcast::<Node>();
+        <Node as NodeExt>::children_of_type(&*node)
+    }
+
+    fn descendants_of_type<T>(&self) -> Vec<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        let node = self.clone().upcast::<Node>();
+        <Node as NodeExt>::descendants_of_type(&*node)
+    }
+}
+
+fn collect_descendants<T>(node: &Node, descendants: &mut Vec<Gd<T>>)
+where
+    T: GodotClass + Inherits<Node>,
+{
+    for child in node.get_children().iter_shared() {
+        if let Ok(matching) = child.clone().try_cast::<T>() {
+            descendants.push(matching);
+        }
+
+        collect_descendants(&child, descendants);
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
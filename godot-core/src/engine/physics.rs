@@ -0,0 +1,185 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{real, Dictionary, Rid, Variant, Vector3};
+use crate::engine::{
+    CharacterBody3D, Node, PhysicsDirectSpaceState3D, PhysicsRayQueryParameters3D,
+};
+use crate::obj::Gd;
+
+/// Result of a ray query that hit something, see [`PhysicsDirectSpaceState3DExt::intersect_ray_typed()`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct RaycastHit {
+    /// Intersection point, in global coordinates.
+    pub position: Vector3,
+
+    /// Surface normal at the intersection point.
+    pub normal: Vector3,
+
+    /// Node owning the shape that was hit.
+    ///
+    /// `None` if the collision object was created directly through `PhysicsServer3D` and has no node attached.
+    pub collider: Option<Gd<Node>>,
+
+    /// RID of the collision object that was hit.
+    pub rid: Rid,
+
+    /// Index of the hit shape within the collision object.
+    pub shape_idx: i32,
+}
+
+impl RaycastHit {
+    /// Converts the dictionary returned by `intersect_ray()`; `None` for an empty dictionary (nothing hit).
+    fn from_dictionary(result: &Dictionary) -> Option<Self> {
+        if result.is_empty() {
+            return None;
+        }
+
+        let get = |key: &str| -> Variant {
+            result
+                .get(key)
+                .unwrap_or_else(|| panic!("intersect_ray() result lacks key `{key}`"))
+        };
+
+        Some(Self {
+            position: get("position").to(),
+            normal: get("normal").to(),
+            collider: get("collider").try_to().ok(),
+            rid: get("rid").to(),
+            shape_idx: get("shape").to(),
+        })
+    }
+}
+
+/// Extension trait with typed physics queries.
+pub trait PhysicsDirectSpaceState3DExt {
+    /// Casts a ray into the space and returns the first hit, if any.
+    ///
+    /// Typed version of [`PhysicsDirectSpaceState3D::intersect_ray()`], which returns an untyped dictionary.
+    /// The query runs immediately, unlike `RayCast3D` nodes which update once per physics step.
+    fn intersect_ray_typed(
+        &mut self,
+        parameters: Gd<PhysicsRayQueryParameters3D>,
+    ) -> Option<RaycastHit>;
+}
+
+impl PhysicsDirectSpaceState3DExt for PhysicsDirectSpaceState3D {
+    fn intersect_ray_typed(
+        &mut self,
+        parameters: Gd<PhysicsRayQueryParameters3D>,
+    ) -> Option<RaycastHit> {
+        let result = self.intersect_ray(parameters);
+
+        RaycastHit::from_dictionary(&result)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for configuring and running `move_and_slide()` in one call.
+pub trait CharacterBody3DExt {
+    /// Starts a [`MoveAndSlideConfig`], which sets motion properties of the body before calling `move_and_slide()`.
+    ///
+    /// In Godot 4, the parameters of the former `move_and_slide_with_snap()` and similar methods are properties of the body. The
+    /// builder sets those which are specified, and leaves the others unchanged:
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::engine::{CharacterBody3D, CharacterBody3DExt};
+    /// # fn physics_process(body: &mut Gd<CharacterBody3D>, direction: Vector3) {
+    /// let collided = body
+    ///     .move_and_slide_ex()
+    ///     .velocity(direction * 5.0)
+    ///     .up_direction(Vector3::UP)
+    ///     .max_slides(4)
+    ///     .apply();
+    /// # }
+    /// ```
+    fn move_and_slide_ex(&mut self) -> MoveAndSlideConfig<'_>;
+}
+
+impl CharacterBody3DExt for CharacterBody3D {
+    fn move_and_slide_ex(&mut self) -> MoveAndSlideConfig<'_> {
+        MoveAndSlideConfig {
+            body: self,
+            velocity: None,
+            up_direction: None,
+            floor_stop_on_slope: None,
+            max_slides: None,
+            floor_max_angle: None,
+        }
+    }
+}
+
+/// Builder for [`CharacterBody3DExt::move_and_slide_ex()`].
+///
+/// The values are stored in the body's properties when [`apply()`][Self::apply] is called, so they also apply to later
+/// `move_and_slide()` calls.
+#[must_use = "call apply() to move the body"]
+pub struct MoveAndSlideConfig<'a> {
+    body: &'a mut CharacterBody3D,
+    velocity: Option<Vector3>,
+    up_direction: Option<Vector3>,
+    floor_stop_on_slope: Option<bool>,
+    max_slides: Option<i32>,
+    floor_max_angle: Option<real>,
+}
+
+impl<'a> MoveAndSlideConfig<'a> {
+    /// Velocity of the body, in units per second. Sets the `velocity` property.
+    pub fn velocity(mut self, velocity: Vector3) -> Self {
+        self.velocity = Some(velocity);
+        self
+    }
+
+    /// Direction considered up, for detecting floors and ceilings. Sets the `up_direction` property.
+    pub fn up_direction(mut self, up_direction: Vector3) -> Self {
+        self.up_direction = Some(up_direction);
+        self
+    }
+
+    /// Whether the body stands still on slopes when not moving. Sets the `floor_stop_on_slope` property.
+    pub fn floor_stop_on_slope(mut self, enabled: bool) -> Self {
+        self.floor_stop_on_slope = Some(enabled);
+        self
+    }
+
+    /// Maximum number of direction changes in one call. Sets the `max_slides` property.
+    pub fn max_slides(mut self, max_slides: i32) -> Self {
+        self.max_slides = Some(max_slides);
+        self
+    }
+
+    /// Steepest slope still considered a floor, in radians. Sets the `floor_max_angle` property.
+    pub fn floor_max_angle(mut self, radians: real) -> Self {
+        self.floor_max_angle = Some(radians);
+        self
+    }
+
+    /// Sets the specified properties and calls `move_and_slide()`. Returns `true` if the body collided.
+    pub fn apply(self) -> bool {
+        let body = self.body;
+
+        if let Some(velocity) = self.velocity {
+            body.set_velocity(velocity);
+        }
+        if let Some(up_direction) = self.up_direction {
+            body.set_up_direction(up_direction);
+        }
+        if let Some(enabled) = self.floor_stop_on_slope {
+            body.set_floor_stop_on_slope_enabled(enabled);
+        }
+        if let Some(max_slides) = self.max_slides {
+            body.set_max_slides(max_slides);
+        }
+        if let Some(radians) = self.floor_max_angle {
+            body.set_floor_max_angle(radians);
+        }
+
+        body.move_and_slide()
+    }
+}
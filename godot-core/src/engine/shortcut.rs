@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::StringName;
+use crate::engine::{InputEvent, InputMap, Node};
+use crate::obj::{Gd, Inherits, WithBaseField};
+
+/// Routes shortcut events to input map actions.
+///
+/// Godot calls `shortcut_input()` (`_shortcut_input`) for key, joypad button and shortcut events, after GUI input and before
+/// `unhandled_input()`. Forward the event to [`route_shortcut_input()`][Self::route_shortcut_input], which calls
+/// [`on_shortcut_activated()`][Self::on_shortcut_activated] for every action of the [`InputMap`] that the event presses.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Control, IControl, InputEvent, ShortcutHandler};
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Control)]
+/// struct Editor {
+///     base: Base<Control>,
+/// }
+///
+/// #[godot_api]
+/// impl IControl for Editor {
+///     fn shortcut_input(&mut self, event: Gd<InputEvent>) {
+///         self.route_shortcut_input(event);
+///     }
+/// }
+///
+/// impl ShortcutHandler for Editor {
+///     fn on_shortcut_activated(&mut self, action: &StringName) -> bool {
+///         if *action == StringName::from("ui_undo") {
+///             godot_print!("Undo");
+///             return true;
+///         }
+///         false
+///     }
+/// }
+/// ```
+pub trait ShortcutHandler: WithBaseField
+where
+    Self::Base: Inherits<Node>,
+{
+    /// Called for each input map action pressed by a shortcut event.
+    ///
+    /// Return `true` if the shortcut was handled; the event is then marked as handled and no further actions are checked.
+    fn on_shortcut_activated(&mut self, action: &StringName) -> bool;
+
+    /// Dispatches `event` to [`on_shortcut_activated()`][Self::on_shortcut_activated], for the actions it presses in declaration
+    /// order of the input map. Echo events of held keys are ignored.
+    fn route_shortcut_input(&mut self, event: Gd<InputEvent>) {
+        for action in InputMap::singleton().get_actions().iter_shared() {
+            if !event.is_action_pressed(action.clone()) || !self.on_shortcut_activated(&action) {
+                continue;
+            }
+
+            let node = self.base_field().to_gd().upcast::<Node>();
+            if let Some(mut viewport) = node.get_viewport() {
+                viewport.set_input_as_handled();
+            }
+            return;
+        }
+    }
+}
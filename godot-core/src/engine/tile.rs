@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::{FromGodot, GodotType};
+use crate::builtin::{Array, Vector2i, Vector3i};
+use crate::engine::{GridMap, TileMap};
+use crate::obj::Gd;
+
+/// Iterator over the used cells of a [`TileMap`] or [`GridMap`].
+///
+/// Created by [`TileMapExt::iter_used_cells()`] and [`GridMapExt::iter_used_cells()`]. The cells are queried once on creation; later
+/// changes to the map are not reflected.
+pub struct UsedCells<T: GodotType> {
+    cells: Array<T>,
+    next_index: usize,
+}
+
+impl<T: GodotType> UsedCells<T> {
+    fn new(cells: Array<T>) -> Self {
+        Self {
+            cells,
+            next_index: 0,
+        }
+    }
+}
+
+impl<T: GodotType + FromGodot> Iterator for UsedCells<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cell = self.cells.try_get(self.next_index)?;
+        self.next_index += 1;
+        Some(cell)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.cells.len().saturating_sub(self.next_index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: GodotType + FromGodot> ExactSizeIterator for UsedCells<T> {}
+
+/// Extension trait with typed cell iteration for 2D tile maps.
+pub trait TileMapExt {
+    /// Iterates over the coordinates of all non-empty cells in `layer`.
+    ///
+    /// Typed version of [`TileMap::get_used_cells()`].
+    fn iter_used_cells(&self, layer: i32) -> UsedCells<Vector2i>;
+}
+
+impl TileMapExt for Gd<TileMap> {
+    fn iter_used_cells(&self, layer: i32) -> UsedCells<Vector2i> {
+        UsedCells::new(self.get_used_cells(layer))
+    }
+}
+
+/// Extension trait with typed cell iteration for 3D grid maps.
+pub trait GridMapExt {
+    /// Iterates over the coordinates of all non-empty cells.
+    ///
+    /// Typed version of [`GridMap::get_used_cells()`].
+    fn iter_used_cells(&self) -> UsedCells<Vector3i>;
+}
+
+impl GridMapExt for Gd<GridMap> {
+    fn iter_used_cells(&self) -> UsedCells<Vector3i> {
+        UsedCells::new(self.get_used_cells())
+    }
+}
@@ -19,6 +19,7 @@ use crate::builtin::{Callable, NodePath, StringName, Variant};
 use crate::obj::raw::RawGd;
 use crate::obj::{
     bounds, cap, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
+    WeakGd,
 };
 use crate::property::{Export, PropertyHintInfo, TypeStringHint, Var};
 use crate::{callbacks, engine, out};
@@ -173,6 +174,46 @@ where
     pub fn bind_mut(&mut self) -> GdMut<T> {
         self.raw.bind_mut()
     }
+
+    /// Returns a shared reference to the user instance, without the runtime borrow check of [`bind()`][Self::bind].
+    ///
+    /// No guard is involved, which avoids its bookkeeping in performance-critical code. Prefer `bind()` unless profiling shows
+    /// that the guard matters.
+    ///
+    /// # Safety
+    /// While the returned reference is alive, no exclusive borrow of the same instance may be active -- neither a `GdMut` guard,
+    /// nor a `&mut T` from [`as_mut_unchecked()`][Self::as_mut_unchecked], nor a `&mut self` method called from GDScript
+    /// (e.g. through re-entrancy). Shared borrows may coexist.
+    ///
+    /// The object must also not be destroyed while the reference is alive: no call to `free()` or `queue_free()` may take effect,
+    /// and for `RefCounted` classes, other references must not drop the last strong reference. The reference is not tied to the
+    /// object's lifetime, so the compiler cannot catch this.
+    ///
+    /// # Panics
+    /// If the object is null or has been freed.
+    pub unsafe fn as_ref_unchecked(&self) -> &T {
+        self.raw.as_ref_unchecked()
+    }
+
+    /// Returns an exclusive reference to the user instance, without the runtime borrow check of [`bind_mut()`][Self::bind_mut].
+    ///
+    /// No guard is involved, which avoids its bookkeeping in performance-critical code. Prefer `bind_mut()` unless profiling shows
+    /// that the guard matters.
+    ///
+    /// # Safety
+    /// While the returned reference is alive, no other borrow of the same instance may be active -- neither a `GdRef`/`GdMut`
+    /// guard, nor a reference from `as_ref_unchecked()`/`as_mut_unchecked()` through another `Gd` pointer, nor a `&self`/`&mut self`
+    /// method called from GDScript (e.g. through re-entrancy). This typically holds in single-threaded code that does not call
+    /// into the engine while the reference is used.
+    ///
+    /// The object must also not be destroyed while the reference is alive: no call to `free()` or `queue_free()` may take effect,
+    /// and for `RefCounted` classes, other references must not drop the last strong reference.
+    ///
+    /// # Panics
+    /// If the object is null or has been freed.
+    pub unsafe fn as_mut_unchecked(&mut self) -> &mut T {
+        self.raw.as_mut_unchecked()
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -245,6 +286,16 @@ impl<T: GodotClass> Gd<T> {
         unsafe { self.raw.instance_id_unchecked().unwrap_unchecked() }
     }
 
+    /// Creates a non-owning reference to this object.
+    ///
+    /// The returned [`WeakGd`] does not keep the object alive; use [`WeakGd::upgrade()`] to access it again.
+    ///
+    /// # Panics
+    /// If this object is no longer alive.
+    pub fn downgrade(&self) -> WeakGd<T> {
+        WeakGd::from_instance_id(self.instance_id())
+    }
+
     /// Checks if this smart pointer points to a live object (read description!).
     ///
     /// Using this method is often indicative of bad design -- you should dispose of your pointers once an object is
@@ -413,6 +464,66 @@ impl<T: GodotClass> Gd<T> {
     pub fn callable<S: Into<StringName>>(&self, method_name: S) -> Callable {
         Callable::from_object_method(self, method_name)
     }
+
+    /// Connects `signal` of this object to a Rust closure, returning a handle which disconnects it again when dropped.
+    ///
+    /// The closure receives the signal arguments. Like for [`Callable::from_fn()`], it must be `Send + Sync`, as Godot may invoke
+    /// callables from other threads. It may mutate its captured state.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::obj::SignalHandle;
+    /// let mut timer = Timer::new_alloc();
+    /// let handle: SignalHandle = timer.subscribe("timeout", |_args| {
+    ///     godot_print!("timeout!");
+    /// });
+    ///
+    /// // Disconnects the closure.
+    /// drop(handle);
+    /// ```
+    ///
+    /// # Panics
+    /// If the object has no signal named `signal`.
+    #[cfg(since_api = "4.2")]
+    pub fn subscribe<F>(&mut self, signal: &str, mut function: F) -> crate::obj::SignalHandle
+    where
+        T: Inherits<engine::Object>,
+        F: FnMut(&[&Variant]) + Send + Sync + 'static,
+    {
+        let signal_name = StringName::from(signal);
+        let callable = Callable::from_fn(format!("subscribe::{signal}"), move |args| {
+            function(args);
+            Ok(Variant::nil())
+        });
+
+        let object = self.upcast_mut::<engine::Object>();
+        assert!(
+            object.has_signal(signal_name.clone()),
+            "cannot subscribe to signal '{signal}': no such signal on class {class}",
+            class = object.get_class()
+        );
+        object.connect(signal_name.clone(), callable.clone());
+
+        crate::obj::SignalHandle::new(self.instance_id(), signal_name, callable)
+    }
+
+    /// Returns the script attached to this object, as a typed handle.
+    ///
+    /// Returns `None` if no script is attached, or if the attached script is not of type `S` (or one of its subclasses).
+    /// `S` can be an engine class such as `GDScript`, or a user-defined `ScriptExtension` class.
+    ///
+    /// This is a typed alternative to [`Object::get_script()`][crate::engine::Object::get_script], which returns a `Variant`.
+    pub fn get_script_typed<S>(&self) -> Option<Gd<S>>
+    where
+        T: Inherits<engine::Object>,
+        S: GodotClass + Inherits<engine::Script>,
+    {
+        self.upcast_ref::<engine::Object>()
+            .get_script()
+            .try_to::<Gd<S>>()
+            .ok()
+    }
 }
 
 impl<T: GodotClass> Deref for Gd<T> {
@@ -708,6 +819,15 @@ impl<T: GodotClass> Debug for Gd<T> {
     }
 }
 
+impl<T: GodotClass> std::fmt::Pointer for Gd<T> {
+    /// Formats the address of the underlying Godot object, e.g. with `{:p}`.
+    ///
+    /// The address is only meaningful for debugging and is not checked for liveness; a freed object may still print its old address.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        std::fmt::Pointer::fmt(&self.obj_sys(), f)
+    }
+}
+
 impl<T: GodotClass> std::hash::Hash for Gd<T> {
     /// ⚠️ Hashes this object based on its instance ID.
     ///
@@ -15,9 +15,13 @@ mod base;
 mod gd;
 mod guards;
 mod instance_id;
+mod oneditor;
 mod onready;
 mod raw;
+#[cfg(since_api = "4.2")]
+mod signal_handle;
 mod traits;
+mod weak_gd;
 
 pub(crate) mod rtti;
 
@@ -25,9 +29,13 @@ pub use base::*;
 pub use gd::*;
 pub use guards::*;
 pub use instance_id::*;
+pub use oneditor::*;
 pub use onready::*;
 pub use raw::*;
+#[cfg(since_api = "4.2")]
+pub use signal_handle::*;
 pub use traits::*;
+pub use weak_gd::*;
 
 pub mod bounds;
 pub use bounds::private::Bounds;
@@ -0,0 +1,153 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::GodotConvert;
+use crate::property::{Export, PropertyHintInfo, Var};
+
+/// Exported field that must be assigned in the editor before the node runs.
+///
+/// A common pattern is an `#[export]` reference to another node or resource, which is set up in the inspector and is never null at
+/// runtime. Declaring it as [`Option<T>`][option] requires an `unwrap()` on every access, while `OnEditor<T>` can be used like `T`.
+///
+/// `OnEditor<T>` fields must be `#[export]`ed, and `T` must be nullable in Godot, e.g. `Gd<Node>` or `Gd<Texture2D>`. Until the
+/// inspector assigns a value, the field is unset; the editor shows it as empty, just like an `Option<T>` property.
+///
+/// # Timing
+///
+/// The field is validated right before `ready()` runs (or in its place, if it is not overridden) -- the same point at which
+/// [`OnReady`][crate::obj::OnReady] fields are initialized. An unset field is reported as a Godot error naming the property. In the
+/// editor, e.g. for `#[class(tool)]` classes, no validation happens, since the property may not have been assigned yet.
+///
+/// Accessing an unset field panics with a message that the property must be set in the inspector. This applies both before and after
+/// `ready()`; fields assigned in the scene are already set when the node is instantiated. With the generated `init`, the message names
+/// the property from the start; a field initialized with `OnEditor::default()` in a custom `init()` learns its name only before `ready()`.
+///
+/// [option]: std::option::Option
+///
+/// # Example
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base = Node)]
+/// struct Turret {
+///     #[export]
+///     target: OnEditor<Gd<Node3D>>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Turret {
+///     fn process(&mut self, _delta: f64) {
+///         // No unwrap() needed.
+///         let _position = self.target.get_global_position();
+///         // ...
+///     }
+/// }
+/// ```
+pub struct OnEditor<T> {
+    value: Option<T>,
+    property_name: Option<&'static str>,
+}
+
+impl<T> OnEditor<T> {
+    /// Creates an unset value, which names `property_name` in the panic message on access.
+    pub(crate) fn with_property_name(property_name: &'static str) -> Self {
+        Self {
+            value: None,
+            property_name: Some(property_name),
+        }
+    }
+
+    /// Whether the value has been set, e.g. by the inspector.
+    ///
+    /// Values created with [`Default`] in a user-defined `init()` don't know their property name yet; they receive `property_name` here.
+    pub(crate) fn validate(&mut self, property_name: &'static str) -> bool {
+        self.property_name.get_or_insert(property_name);
+        self.value.is_some()
+    }
+
+    #[track_caller]
+    fn panic_unset(&self) -> ! {
+        match self.property_name {
+            Some(name) => panic!("property `{name}` must be set in the inspector"),
+            None => panic!("OnEditor property must be set in the inspector"),
+        }
+    }
+}
+
+impl<T> Default for OnEditor<T> {
+    /// Creates an unset value, to be assigned in the editor.
+    fn default() -> Self {
+        Self {
+            value: None,
+            property_name: None,
+        }
+    }
+}
+
+impl<T> std::ops::Deref for OnEditor<T> {
+    type Target = T;
+
+    /// Returns a shared reference to the value.
+    ///
+    /// # Panics
+    /// If the value was not set in the inspector.
+    fn deref(&self) -> &Self::Target {
+        match &self.value {
+            Some(value) => value,
+            None => self.panic_unset(),
+        }
+    }
+}
+
+impl<T> std::ops::DerefMut for OnEditor<T> {
+    /// Returns an exclusive reference to the value.
+    ///
+    /// # Panics
+    /// If the value was not set in the inspector.
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        if self.value.is_none() {
+            self.panic_unset();
+        }
+
+        self.value.as_mut().unwrap()
+    }
+}
+
+// Godot transports the property like `Option<T>`, so that the editor can read and write an unset value as null.
+impl<T> GodotConvert for OnEditor<T>
+where
+    Option<T>: GodotConvert,
+{
+    type Via = <Option<T> as GodotConvert>::Via;
+}
+
+impl<T> Var for OnEditor<T>
+where
+    Option<T>: Var,
+{
+    fn get_property(&self) -> Self::Via {
+        self.value.get_property()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        self.value.set_property(value);
+    }
+
+    fn property_hint() -> PropertyHintInfo {
+        <Option<T> as Var>::property_hint()
+    }
+}
+
+impl<T> Export for OnEditor<T>
+where
+    Option<T>: Export,
+{
+    fn default_export_info() -> PropertyHintInfo {
+        <Option<T> as Export>::default_export_info()
+    }
+}
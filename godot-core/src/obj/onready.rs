@@ -31,6 +31,20 @@ use std::mem;
 /// `OnReady<T>` cannot be used with `#[export]` fields, because `ready()` is typically not called in the editor (unless `#[class(tool)]`
 /// is specified). You can however use it with `#[var]` -- just make sure to access the fields in GDScript after `ready()`.
 ///
+/// When initializing from the scene tree with [`NodeExt::get_node_as()`][crate::engine::NodeExt::get_node_as], keep in mind that nodes loaded
+/// through an `InstancePlaceholder` are not yet present in `ready()`. `get_node_as()` panics with a message pointing this out, rather than
+/// reporting a plain type mismatch; for such nodes, prefer `OnReady::manual()` and initialize once the placeholder has been instantiated.
+///
+/// # Timing
+///
+/// Automatic initialization is tied to `ready()`, never to `enter_tree()`. Godot calls `enter_tree()` on a node before its children
+/// have entered the tree, and `ready()` only once the node and all its children are in the tree. `OnReady` fields are initialized
+/// right before the user's `ready()` runs (or in its place, if it is not overridden), so they must not be accessed in `enter_tree()`
+/// or earlier; this panics.
+///
+/// If `ready()` runs again later, e.g. because `Node::request_ready()` was called before re-adding the node to the tree, fields keep
+/// their current values. The initializer closure is run at most once.
+///
 /// This type is not thread-safe. `ready()` runs on the main thread and you are expected to access its value on the main thread, as well.
 ///
 /// [option]: std::option::Option
@@ -122,6 +136,13 @@ impl<T> OnReady<T> {
         };
     }
 
+    /// Runs initialization before `ready()`, unless the value is already initialized from an earlier `ready()` call.
+    pub(crate) fn init_auto_before_ready(&mut self) {
+        if !matches!(self.state, InitState::Initialized { .. }) {
+            self.init_auto();
+        }
+    }
+
     /// Runs initialization.
     ///
     /// # Panics
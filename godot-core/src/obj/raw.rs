@@ -372,6 +372,28 @@ where
         GdMut::from_guard(self.storage().unwrap().get_mut())
     }
 
+    /// Shared reference to the user instance, without a guard.
+    ///
+    /// See [`crate::obj::Gd::as_ref_unchecked()`] for the safety requirements.
+    pub(crate) unsafe fn as_ref_unchecked(&self) -> &T {
+        self.check_rtti("as_ref_unchecked");
+        let instance = self.storage().unwrap().get_ptr_unchecked();
+
+        // SAFETY: the caller guarantees that no exclusive borrow of the instance is active for the returned lifetime.
+        unsafe { &*instance }
+    }
+
+    /// Exclusive reference to the user instance, without a guard.
+    ///
+    /// See [`crate::obj::Gd::as_mut_unchecked()`] for the safety requirements.
+    pub(crate) unsafe fn as_mut_unchecked(&mut self) -> &mut T {
+        self.check_rtti("as_mut_unchecked");
+        let instance = self.storage().unwrap().get_ptr_unchecked();
+
+        // SAFETY: the caller guarantees that no other borrow of the instance is active for the returned lifetime.
+        unsafe { &mut *instance }
+    }
+
     /// Storage object associated with the extension instance.
     ///
     /// Returns `None` if self is null.
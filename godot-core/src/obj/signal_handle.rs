@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{Callable, StringName};
+use crate::engine::Object;
+use crate::obj::{Gd, InstanceId};
+
+/// Connection between a signal and a Rust closure, created with [`Gd::subscribe()`].
+///
+/// The connection is removed when the handle is dropped, so signal management follows Rust's ownership: store the handle in a field
+/// of the subscriber, and the connection lives exactly as long as that subscriber. If the emitting object has been freed in the
+/// meantime, dropping the handle does nothing.
+///
+/// To keep the connection for the lifetime of the emitter instead, call [`forget()`](Self::forget).
+#[must_use = "dropping the handle immediately disconnects the signal"]
+pub struct SignalHandle {
+    // Instance ID rather than Gd<Object>, so that the handle neither keeps a RefCounted emitter alive nor observes a freed one.
+    object_id: InstanceId,
+    signal: StringName,
+    callable: Callable,
+    disconnect_on_drop: bool,
+}
+
+impl SignalHandle {
+    pub(crate) fn new(object_id: InstanceId, signal: StringName, callable: Callable) -> Self {
+        Self {
+            object_id,
+            signal,
+            callable,
+            disconnect_on_drop: true,
+        }
+    }
+
+    /// Name of the subscribed signal.
+    pub fn signal(&self) -> &StringName {
+        &self.signal
+    }
+
+    /// The callable connected to the signal, wrapping the Rust closure.
+    pub fn callable(&self) -> &Callable {
+        &self.callable
+    }
+
+    /// Whether the emitting object is alive and the closure is still connected.
+    pub fn is_connected(&self) -> bool {
+        self.emitter().map_or(false, |object| {
+            object.is_connected(self.signal.clone(), self.callable.clone())
+        })
+    }
+
+    /// Disconnects the closure now. Equivalent to dropping the handle.
+    pub fn disconnect(self) {
+        drop(self)
+    }
+
+    /// Keeps the connection alive until the emitting object is freed, or until it is disconnected through Godot APIs.
+    pub fn forget(mut self) {
+        // Only skip the disconnect; the signal name and callable are still released.
+        self.disconnect_on_drop = false;
+    }
+
+    fn emitter(&self) -> Option<Gd<Object>> {
+        Gd::try_from_instance_id(self.object_id).ok()
+    }
+}
+
+impl Drop for SignalHandle {
+    fn drop(&mut self) {
+        if !self.disconnect_on_drop {
+            return;
+        }
+
+        let Some(mut object) = self.emitter() else {
+            return;
+        };
+
+        // The connection may already have been removed, e.g. for one-shot connections or through Object::disconnect().
+        if object.is_connected(self.signal.clone(), self.callable.clone()) {
+            object.disconnect(self.signal.clone(), self.callable.clone());
+        }
+    }
+}
+
+impl fmt::Debug for SignalHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignalHandle")
+            .field("object_id", &self.object_id)
+            .field("signal", &self.signal)
+            .finish()
+    }
+}
@@ -143,6 +143,12 @@ pub trait UserClass: Bounds<Declarer = bounds::DeclUser> {
     fn __default_virtual_call(_method_name: &str) -> sys::GDExtensionClassCallVirtual {
         None
     }
+
+    /// The Godot object behind `self`, or `None` if the class has no `Base<T>` field.
+    #[doc(hidden)]
+    fn __base_object(&self) -> Option<Gd<crate::engine::Object>> {
+        None
+    }
 }
 
 /// Auto-implemented for all engine-provided enums.
@@ -406,6 +412,7 @@ where
 /// Capability traits, providing dedicated functionalities for Godot classes
 pub mod cap {
     use super::*;
+    use crate::builtin::meta::PropertyInfo;
     use crate::builtin::{StringName, Variant};
     use crate::obj::{Base, Bounds, Gd};
 
@@ -486,6 +493,55 @@ pub mod cap {
         fn __godot_set_property(&mut self, property: StringName, value: Variant) -> bool;
     }
 
+    /// Auto-implemented for `#[derive(GodotClass)]` structs with `#[export(default = ...)]` fields.
+    #[doc(hidden)]
+    pub trait GodotPropertyRevert: GodotClass {
+        /// Returns the value `property` is reverted to in the editor, or `None` if it cannot be reverted.
+        #[doc(hidden)]
+        fn __godot_property_get_revert(&self, property: StringName) -> Option<Variant>;
+    }
+
+    /// Auto-implemented for `#[derive(GodotClass)]` enums, whose properties depend on the current variant.
+    #[doc(hidden)]
+    pub trait GodotGetPropertyList: GodotClass {
+        #[doc(hidden)]
+        fn __godot_get_property_list(&self) -> Vec<PropertyInfo>;
+    }
+
+    /// Auto-implemented for `#[derive(GodotClass)]` structs with `#[class(before_save_fn = ...)]` or `#[class(after_load_fn = ...)]`.
+    #[doc(hidden)]
+    pub trait GodotStorageHooks: GodotClass {
+        /// Whether `property` is one of the class's own `#[var]` or `#[export]` fields.
+        #[doc(hidden)]
+        fn __godot_is_hooked_property(property: &StringName) -> bool;
+
+        /// Processes `value` before it is handed out to Godot, e.g. to be saved.
+        #[doc(hidden)]
+        fn __godot_before_save(&self, property: StringName, value: Variant) -> Variant;
+
+        /// Runs after `property` has been assigned by Godot, e.g. while loading.
+        #[doc(hidden)]
+        fn __godot_after_load(&mut self, property: StringName);
+    }
+
+    #[doc(hidden)]
+    pub trait GodotUserValidateProperty: GodotClass {
+        #[doc(hidden)]
+        fn __godot_user_validate_property(&self, property: &mut PropertyInfo);
+    }
+
+    /// Auto-implemented for `#[derive(GodotClass)]` structs; only adjusts `#[export(range_max_from = ...)]` and
+    /// `#[export(read_only)]` fields.
+    #[doc(hidden)]
+    pub trait GodotValidateProperty: GodotClass {
+        /// Returns the adjustments to hint and usage of the property named `property_name`, or `None` if it is left as-is.
+        #[doc(hidden)]
+        fn __godot_validate_property(
+            &self,
+            property_name: &StringName,
+        ) -> Option<crate::private::PropertyAdjustment>;
+    }
+
     /// Auto-implemented for `#[godot_api] impl MyClass` blocks
     pub trait ImplementsGodotApi: GodotClass {
         #[doc(hidden)]
@@ -499,6 +555,12 @@ pub mod cap {
         fn __register_exports();
     }
 
+    /// Auto-implemented for `#[derive(GodotClass)]` structs with `#[mixin]` fields.
+    pub trait ImplementsGodotMixins: GodotClass {
+        #[doc(hidden)]
+        fn __register_mixin_methods();
+    }
+
     /// Auto-implemented for `#[godot_api] impl XyVirtual for MyClass` blocks
     pub trait ImplementsGodotVirtual: GodotClass {
         #[doc(hidden)]
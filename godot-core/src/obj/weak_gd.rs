@@ -0,0 +1,92 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+/// Non-owning reference to a Godot object, created with [`Gd::downgrade()`].
+///
+/// A `WeakGd<T>` only stores the object's instance ID. It neither keeps a `RefCounted` object alive nor prevents a manually managed
+/// object from being freed. To access the object, [`upgrade()`](Self::upgrade) it to a `Gd<T>`, which fails once the object is gone.
+///
+/// This is useful for caches and back-references, e.g. from a child to its parent, where a `Gd<T>` would create a reference cycle
+/// between `RefCounted` objects.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::obj::WeakGd;
+///
+/// let node = Node::new_alloc();
+/// let weak: WeakGd<Node> = node.downgrade();
+/// assert!(weak.upgrade().is_some());
+///
+/// node.free();
+/// assert!(weak.upgrade().is_none());
+/// ```
+pub struct WeakGd<T: GodotClass> {
+    instance_id: InstanceId,
+
+    // Like Gd<T>, not thread-safe.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> WeakGd<T> {
+    pub(crate) fn from_instance_id(instance_id: InstanceId) -> Self {
+        Self {
+            instance_id,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a strong reference to the object, or `None` if it has been destroyed.
+    ///
+    /// For `RefCounted` objects, the returned `Gd<T>` keeps the object alive as usual.
+    pub fn upgrade(&self) -> Option<Gd<T>> {
+        Gd::try_from_instance_id(self.instance_id).ok()
+    }
+
+    /// Instance ID of the referenced object, which may no longer be alive.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+}
+
+impl<T: GodotClass> Clone for WeakGd<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GodotClass> Copy for WeakGd<T> {}
+
+impl<T: GodotClass> PartialEq for WeakGd<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: GodotClass> Eq for WeakGd<T> {}
+
+impl<T: GodotClass> std::hash::Hash for WeakGd<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.instance_id.hash(state);
+    }
+}
+
+impl<T: GodotClass> fmt::Debug for WeakGd<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "WeakGd {{ id: {}, class: {} }}",
+            self.instance_id,
+            T::class_name()
+        )
+    }
+}
@@ -8,7 +8,11 @@
 use std::sync::{Arc, Mutex};
 
 pub use crate::gen::classes::class_macros;
-pub use crate::registry::{callbacks, ClassPlugin, ErasedRegisterFn, PluginItem};
+#[cfg(since_api = "4.3")]
+pub use crate::registry::class_docs_xml;
+pub use crate::registry::{
+    callbacks, ClassPlugin, ErasedRegisterFn, InherentImplDocs, PluginItem, StructDocs,
+};
 pub use crate::storage::{as_storage, Storage};
 pub use sys::out;
 
@@ -74,6 +78,135 @@ pub fn auto_init<T>(l: &mut crate::obj::OnReady<T>) {
     l.init_auto();
 }
 
+pub fn auto_init_before_ready<T>(l: &mut crate::obj::OnReady<T>) {
+    l.init_auto_before_ready();
+}
+
+/// Binds the instance for a `#[func]` or virtual method with `&mut self` receiver.
+///
+/// # Panics
+/// If the instance is already bound, with a message naming the method.
+pub fn bind_mut_for_call<'a, T: crate::obj::GodotClass>(
+    storage: &'a crate::storage::InstanceStorage<T>,
+    method_name: &str,
+) -> godot_cell::MutGuard<'a, T> {
+    storage.try_get_mut().unwrap_or_else(|err| {
+        panic!(
+            "{}\n  Use #[func(try_mut)] to report this as an error instead of panicking.",
+            reentrant_call_message::<T>(method_name, &*err)
+        )
+    })
+}
+
+/// Binds the instance for a `#[func(try_mut)]` method; if it is already bound, reports an error naming the method and returns `None`.
+pub fn try_bind_mut_for_call<'a, T: crate::obj::GodotClass>(
+    storage: &'a crate::storage::InstanceStorage<T>,
+    method_name: &str,
+) -> Option<godot_cell::MutGuard<'a, T>> {
+    match storage.try_get_mut() {
+        Ok(guard) => Some(guard),
+        Err(err) => {
+            push_func_error(&reentrant_call_message::<T>(method_name, &*err));
+            None
+        }
+    }
+}
+
+fn reentrant_call_message<T: crate::obj::GodotClass>(
+    method_name: &str,
+    err: &dyn std::error::Error,
+) -> String {
+    let class = T::class_name();
+    format!(
+        "\
+        cannot call {class}::{method_name}(): it takes `&mut self`, but the object is already bound.\n  \
+        This is usually a re-entrant call, e.g. an engine callback invoking the method while the object is borrowed.\n  \
+        Make sure to use `self.base_mut()` or `self.base()` instead of `self.to_gd()` when possible.\n  \
+        Details: {err}.\
+        "
+    )
+}
+
+/// Property name, replication mode and whether the property is sent on spawn, for one `#[remote_sync]` field.
+#[cfg(since_api = "4.2")]
+pub type RemoteSyncProperty<'a> = (
+    &'a str,
+    crate::engine::scene_replication_config::ReplicationMode,
+    bool,
+);
+
+/// Creates the replication config of the `#[remote_sync]` synchronizer, with paths relative to the synchronized node.
+#[cfg(since_api = "4.2")]
+pub fn make_remote_sync_config(
+    properties: &[RemoteSyncProperty],
+) -> crate::obj::Gd<crate::engine::SceneReplicationConfig> {
+    use crate::builtin::NodePath;
+    use crate::obj::NewGd;
+
+    // Paths are relative to the synchronizer's root path, which defaults to its parent, i.e. this node.
+    let mut config = crate::engine::SceneReplicationConfig::new_gd();
+    for &(property, mode, is_spawn) in properties {
+        let path = NodePath::from(format!(".:{property}"));
+        config.add_property(path.clone());
+        config.property_set_replication_mode(path.clone(), mode);
+        config.property_set_spawn(path, is_spawn);
+    }
+
+    config
+}
+
+/// Backs `#[remote_sync]`: adds a `MultiplayerSynchronizer` child that replicates the given properties of the node.
+///
+/// Invoked on every `ready()`, but adds the synchronizer only once. It is added deferred, as adding a child emits signals that may
+/// call back into the bound instance.
+#[cfg(since_api = "4.2")]
+pub fn add_remote_synchronizer<T>(this: &T, properties: &[RemoteSyncProperty])
+where
+    T: crate::obj::GodotClass + crate::obj::UserClass,
+    T::Base: crate::obj::Inherits<crate::engine::Node>,
+{
+    use crate::engine::{MultiplayerSynchronizer, Node, NodeExt};
+    use crate::obj::NewAlloc;
+
+    const SYNCHRONIZER_NAME: &str = "RemoteSynchronizer";
+
+    if crate::engine::Engine::singleton().is_editor_hint() {
+        return;
+    }
+
+    let Some(object) = this.__base_object() else {
+        panic!(
+            "cannot add synchronizer to class {class}: #[remote_sync] requires a `Base<T>` field",
+            class = T::class_name()
+        );
+    };
+    let mut node = object.cast::<Node>();
+    if node.has_node(SYNCHRONIZER_NAME.into()) {
+        return;
+    }
+
+    let mut synchronizer = MultiplayerSynchronizer::new_alloc();
+    synchronizer.set_name(SYNCHRONIZER_NAME.into());
+    synchronizer.set_replication_config(make_remote_sync_config(properties));
+    node.add_child_deferred(synchronizer.upcast());
+}
+
+/// Creates an unset `OnEditor` field in the generated `init`. `property_name` has the form `Class.field`.
+pub fn new_on_editor<T>(property_name: &'static str) -> crate::obj::OnEditor<T> {
+    crate::obj::OnEditor::with_property_name(property_name)
+}
+
+/// Validates an `OnEditor` field before `ready()`. `property_name` has the form `Class.field`.
+pub fn validate_on_editor<T>(field: &mut crate::obj::OnEditor<T>, property_name: &'static str) {
+    if crate::engine::Engine::singleton().is_editor_hint() {
+        return;
+    }
+
+    if !field.validate(property_name) {
+        log::godot_error!("property `{property_name}` must be set in the inspector");
+    }
+}
+
 fn print_panic_message(msg: &str) {
     // If the message contains newlines, print all of the lines after a line break, and indent them.
     let lbegin = "\n  ";
@@ -155,6 +288,72 @@ pub unsafe fn has_virtual_script_method(
     sys::interface_fn!(object_has_script_method)(sys::to_const_ptr(object_ptr), method_sname) != 0
 }
 
+/// Adjustments of `#[export(range_max_from = ...)]` and `#[export(read_only)]` to one property, applied in `validate_property`.
+pub struct PropertyAdjustment {
+    /// Replaces hint and hint string.
+    pub hint: Option<crate::property::PropertyHintInfo>,
+    /// Adds `PropertyUsageFlags::READ_ONLY`.
+    pub is_read_only: bool,
+}
+
+/// Measures the execution time of a `#[profile]` method, until dropped.
+///
+/// On drop, the elapsed time in microseconds is stored as the current value of a `Performance` custom monitor.
+/// The monitor is registered the first time a sample is recorded under its name.
+#[cfg(since_api = "4.2")]
+pub struct ProfileScope {
+    monitor_name: &'static str,
+    start_usec: u64,
+}
+
+#[cfg(since_api = "4.2")]
+impl ProfileScope {
+    pub fn new(monitor_name: &'static str) -> Self {
+        Self {
+            monitor_name,
+            start_usec: crate::engine::Time::singleton().get_ticks_usec(),
+        }
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl Drop for ProfileScope {
+    fn drop(&mut self) {
+        let elapsed_usec = crate::engine::Time::singleton()
+            .get_ticks_usec()
+            .saturating_sub(self.start_usec);
+
+        push_profile_sample(self.monitor_name, elapsed_usec);
+    }
+}
+
+#[cfg(since_api = "4.2")]
+fn push_profile_sample(monitor_name: &'static str, elapsed_usec: u64) {
+    use crate::builtin::meta::ToGodot;
+    use crate::builtin::{Callable, StringName};
+    use std::collections::HashMap;
+
+    static SAMPLES: sys::Global<HashMap<&'static str, u64>> = sys::Global::default();
+
+    let is_new = SAMPLES.lock().insert(monitor_name, elapsed_usec).is_none();
+    if !is_new {
+        return;
+    }
+
+    let monitor_id = StringName::from(monitor_name);
+    let mut performance = crate::engine::Performance::singleton();
+
+    // Monitor may still be registered, e.g. after hot reload.
+    if !performance.has_custom_monitor(monitor_id.clone()) {
+        let callable = Callable::from_fn(monitor_name, move |_args| {
+            let elapsed_usec = SAMPLES.lock().get(monitor_name).copied().unwrap_or(0);
+            Ok((elapsed_usec as i64).to_variant())
+        });
+
+        performance.add_custom_monitor(monitor_id, callable);
+    }
+}
+
 pub fn flush_stdout() {
     use std::io::Write;
     std::io::stdout().flush().expect("flush stdout");
@@ -162,3 +361,221 @@ pub fn flush_stdout() {
 
 /// Ensure `T` is an editor plugin.
 pub const fn is_editor_plugin<T: crate::obj::Inherits<crate::engine::EditorPlugin>>() {}
+
+/// Ensure `T` can be used as a `#[signal]` parameter. Emitted per parameter, so errors point to the offending type.
+pub const fn is_signal_param<T>()
+where
+    T: crate::builtin::meta::ToGodot + crate::builtin::meta::FromGodot + std::fmt::Debug,
+{
+}
+
+/// Backs `Option<T>` parameters of `#[func]` methods that are transported as `Variant`: checks that argument `index` is null or
+/// convertible to `T`.
+///
+/// Otherwise, reports an invalid argument through `err` -- the same error that Godot raises for arguments of the wrong type -- and
+/// returns `false`. Missing arguments are accepted, since the argument count is checked separately.
+///
+/// # Safety
+/// `args_ptr` must point to `arg_count` valid variant pointers, and it must be safe to write to `err`.
+pub unsafe fn check_option_arg<T: crate::builtin::meta::FromGodot>(
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    index: usize,
+    err: *mut sys::GDExtensionCallError,
+) -> bool {
+    use crate::builtin::meta::GodotType;
+    use sys::GodotFfi;
+
+    if index >= arg_count as usize {
+        return true;
+    }
+
+    let arg = &*crate::builtin::Variant::ptr_from_sys(*args_ptr.add(index));
+    if arg.is_nil() || arg.try_to::<T>().is_ok() {
+        return true;
+    }
+
+    *err = sys::default_call_error();
+    (*err).error = sys::GDEXTENSION_CALL_ERROR_INVALID_ARGUMENT;
+    (*err).argument = index as i32;
+    (*err).expected = <T::Via as GodotType>::Ffi::variant_type().sys() as i32;
+    false
+}
+
+/// Backs the setters of `#[export(element = E)]` fields: the editor assigns typed arrays to them, which are copied into an untyped
+/// array. Panics if `value` is not an array.
+pub fn untyped_array_from_variant<T>(value: &crate::builtin::Variant, property_name: &str) -> T
+where
+    T: crate::builtin::meta::GodotType<Ffi = crate::builtin::VariantArray>,
+{
+    crate::builtin::VariantArray::from_variant_untyped(value)
+        .and_then(T::try_from_ffi)
+        .unwrap_or_else(|err| panic!("property `{property_name}` expects an array: {err}"))
+}
+
+/// Ensure `T` can be the `Ok` type of a `#[func]` returning `Result`: on error, Godot receives `T::default()`.
+pub const fn is_func_result_ok_type<T: Default>() {}
+
+#[cfg(feature = "itest")]
+type FuncErrorHook = Box<dyn Fn(&str) + Send>;
+
+#[cfg(feature = "itest")]
+static FUNC_ERROR_HOOK: Mutex<Option<FuncErrorHook>> = Mutex::new(None);
+
+/// Installs a hook that additionally receives every error message logged by `#[func]` methods returning `Err` or by
+/// `#[func(try_mut)]` methods that cannot bind the object, or removes it with `None`. Only available for integration tests, which verify the messages.
+#[cfg(feature = "itest")]
+pub fn set_func_error_hook(hook: Option<FuncErrorHook>) {
+    *FUNC_ERROR_HOOK.lock().unwrap() = hook;
+}
+
+/// Pushes an error reported by `#[func]` glue code to Godot.
+fn push_func_error(message: &str) {
+    #[cfg(feature = "itest")]
+    if let Some(hook) = FUNC_ERROR_HOOK.lock().unwrap().as_ref() {
+        hook(message);
+    }
+
+    log::godot_error!("{message}");
+}
+
+/// Backs `#[func]` methods returning `Result<T, GString>` or `Result<T, String>`: pushes the error message to Godot and returns
+/// `T::default()` in its place.
+pub fn unwrap_func_result<T, E>(result: Result<T, E>) -> T
+where
+    T: Default,
+    E: std::fmt::Display,
+{
+    match result {
+        Ok(value) => value,
+        Err(message) => {
+            push_func_error(&message.to_string());
+            T::default()
+        }
+    }
+}
+
+/// Backs the generated `disconnect_all_signals()` method: disconnects every connection of the given signals.
+pub fn disconnect_all_signals<T: crate::obj::GodotClass + crate::obj::UserClass>(
+    this: &T,
+    signal_names: &[&str],
+) {
+    let Some(object) = this.__base_object() else {
+        panic!(
+            "cannot disconnect signals of class {class}: this requires a `Base<T>` field",
+            class = T::class_name()
+        );
+    };
+
+    for &signal_name in signal_names {
+        let signal = crate::builtin::Signal::from_object_signal(&object, signal_name);
+
+        // Signals without connections have an empty list, so nothing happens for them.
+        for connection in signal.connections().iter_shared() {
+            let callable = connection
+                .get_or_nil("callable")
+                .to::<crate::builtin::Callable>();
+            signal.disconnect(callable);
+        }
+    }
+}
+
+/// Backs the generated `connect_<signal>()` methods: subscribes `function` to `signal_name` of the object's base.
+///
+/// `function` converts the arguments and returns `false` if one of them has the wrong type. Such calls, as well as calls with
+/// fewer than `param_count` arguments, are reported as errors, since the closure runs inside the engine and must not panic.
+#[cfg(since_api = "4.2")]
+pub fn connect_signal_closure<T, F>(
+    this: &T,
+    signal_name: &str,
+    param_count: usize,
+    mut function: F,
+) -> crate::obj::SignalHandle
+where
+    T: crate::obj::GodotClass + crate::obj::UserClass,
+    F: FnMut(&[&crate::builtin::Variant]) -> bool + Send + Sync + 'static,
+{
+    let Some(mut object) = this.__base_object() else {
+        panic!(
+            "cannot connect signal '{signal_name}' of class {class}: this requires a `Base<T>` field",
+            class = T::class_name()
+        );
+    };
+
+    let class = T::class_name();
+    let name = signal_name.to_string();
+    object.subscribe(signal_name, move |args| {
+        if args.len() < param_count || !function(args) {
+            log::godot_error!(
+                "signal '{name}' of class {class}: arguments {args:?} do not match the {param_count} declared parameter(s)"
+            );
+        }
+    })
+}
+
+/// Backs `on_child_entered_tree()` and `on_child_exiting_tree()`: connects the signal `signal_name` of `node` to `hook`.
+///
+/// Invoked on every `ready()`, but connects only once, so that `request_ready()` doesn't duplicate calls.
+#[cfg(since_api = "4.2")]
+pub fn connect_child_tree_hook<T>(
+    node: crate::obj::Gd<T>,
+    signal_name: &str,
+    hook: fn(&mut T, crate::obj::Gd<crate::engine::Node>),
+) where
+    T: crate::obj::GodotClass + crate::obj::Bounds<Declarer = crate::obj::bounds::DeclUser>,
+{
+    use crate::builtin::{Callable, Signal, Variant};
+
+    let signal = Signal::from_object_signal(&node, signal_name);
+    let callable_name = format!("{}::{signal_name}", T::class_name());
+
+    let is_connected = signal.connections().iter_shared().any(|connection| {
+        connection
+            .get_or_nil("callable")
+            .to::<Callable>()
+            .to_string()
+            == callable_name
+    });
+    if is_connected {
+        return;
+    }
+
+    let instance_id = node.instance_id();
+    let callable = Callable::from_fn(callable_name, move |args| {
+        let child = args
+            .first()
+            .expect("child tree signal without node argument")
+            .to::<crate::obj::Gd<crate::engine::Node>>();
+
+        // Goes through the same borrow checks as a #[func] call, so the hook may run while the node calls base_mut().
+        let mut node = crate::obj::Gd::<T>::from_instance_id(instance_id);
+        hook(&mut *node.bind_mut(), child);
+
+        Ok(Variant::nil())
+    });
+
+    // Godot removes the connection when the node is freed, as it's both emitter and target.
+    signal.connect(callable, 0);
+}
+
+/// Backs the generated `emit_<signal>_deferred()` methods: emits `signal_name` through `call_deferred("emit_signal", ...)`.
+pub fn emit_signal_deferred<T: crate::obj::GodotClass + crate::obj::UserClass>(
+    this: &T,
+    signal_name: &str,
+    args: &[crate::builtin::Variant],
+) {
+    use crate::builtin::meta::ToGodot;
+
+    let Some(mut object) = this.__base_object() else {
+        panic!(
+            "cannot emit signal '{signal_name}' of class {class}: deferred emission requires a `Base<T>` field",
+            class = T::class_name()
+        );
+    };
+
+    let mut call_args = Vec::with_capacity(args.len() + 1);
+    call_args.push(crate::builtin::StringName::from(signal_name).to_variant());
+    call_args.extend_from_slice(args);
+
+    object.call_deferred("emit_signal".into(), &call_args);
+}
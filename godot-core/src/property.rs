@@ -117,10 +117,14 @@ impl PropertyHintInfo {
 
 /// Functions used to translate user-provided arguments into export hints.
 pub mod export_info_functions {
+    use crate::builtin::meta::GodotType;
     use crate::builtin::GString;
     use crate::engine::global::PropertyHint;
 
-    use super::PropertyHintInfo;
+    use crate::engine::Resource;
+    use crate::obj::{Gd, GodotClass, Inherits};
+
+    use super::{Export, PropertyHintInfo, Var};
 
     /// Turn a list of variables into a comma separated string containing only the identifiers corresponding
     /// to a true boolean variable.
@@ -173,6 +177,26 @@ pub mod export_info_functions {
         }
     }
 
+    /// Numeric field types that can provide the upper bound for `#[export(range_max_from = "field")]`.
+    pub trait RangeBound {
+        fn to_range_bound(&self) -> f64;
+    }
+
+    macro_rules! impl_range_bound {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                impl RangeBound for $ty {
+                    #[allow(clippy::unnecessary_cast)] // f64 as f64
+                    fn to_range_bound(&self) -> f64 {
+                        *self as f64
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_range_bound!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
     pub struct ExportValueWithKey<T> {
         variant: String,
         key: Option<T>,
@@ -279,6 +303,107 @@ pub mod export_info_functions {
         }
     }
 
+    /// Export info for `#[export(secret)]` fields, i.e. strings shown obscured in the inspector ([`PropertyHint::PASSWORD`]).
+    ///
+    /// The bound ensures that `T` is passed to Godot as a string.
+    pub fn export_secret<T>() -> PropertyHintInfo
+    where
+        T: Var,
+        T::Via: GodotType<Ffi = GString>,
+    {
+        PropertyHintInfo {
+            hint: PropertyHint::PASSWORD,
+            hint_string: GString::new(),
+        }
+    }
+
+    /// Export info for integer fields with an advanced hint, such as [`PropertyHint::INT_IS_OBJECTID`] or
+    /// [`PropertyHint::INT_IS_POINTER`].
+    ///
+    /// The bound ensures that `T` is passed to Godot as an integer.
+    pub fn export_integer_hint<T>(hint: PropertyHint) -> PropertyHintInfo
+    where
+        T: Var,
+        T::Via: GodotType<Ffi = i64>,
+    {
+        PropertyHintInfo {
+            hint,
+            hint_string: GString::new(),
+        }
+    }
+
+    /// Field types holding a `Resource`-derived object, which can be used with `#[export(new_resource)]`.
+    pub trait ExportNewResource: Export {}
+
+    impl<T> ExportNewResource for Gd<T> where T: GodotClass + Inherits<Resource> {}
+
+    impl<T> ExportNewResource for Option<Gd<T>> where T: GodotClass + Inherits<Resource> {}
+
+    /// Export info for `#[export(new_resource)]` fields, i.e. the [`PropertyHint::RESOURCE_TYPE`] hint of the held resource class.
+    ///
+    /// The bound restricts this to `Resource`-derived object fields.
+    pub fn export_new_resource<T: ExportNewResource>() -> PropertyHintInfo {
+        T::default_export_info()
+    }
+
+    /// Export info for `#[export(dict = "Key:Value")]` fields, i.e. typed dictionaries ([`PropertyHint::DICTIONARY_TYPE`]).
+    ///
+    /// The bound ensures that `T` is passed to Godot as a dictionary.
+    #[cfg(since_api = "4.4")]
+    pub fn export_dictionary_type<T>(key_type: &str, value_type: &str) -> PropertyHintInfo
+    where
+        T: Var,
+        T::Via: GodotType<Ffi = crate::builtin::Dictionary>,
+    {
+        PropertyHintInfo {
+            hint: PropertyHint::DICTIONARY_TYPE,
+            hint_string: format!("{key_type};{value_type}").into(),
+        }
+    }
+
+    /// Export info for `#[export(node_path, types = [...])]` fields, i.e. node paths restricted to certain node types
+    /// ([`PropertyHint::NODE_PATH_VALID_TYPES`]).
+    ///
+    /// The bound ensures that `T` is passed to Godot as a node path. An empty `types` list allows all nodes.
+    pub fn export_node_path<T>(types: &[crate::builtin::meta::ClassName]) -> PropertyHintInfo
+    where
+        T: Var,
+        T::Via: GodotType<Ffi = crate::builtin::NodePath>,
+    {
+        let hint_string = types
+            .iter()
+            .map(|class_name| class_name.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        PropertyHintInfo {
+            hint: PropertyHint::NODE_PATH_VALID_TYPES,
+            hint_string: hint_string.into(),
+        }
+    }
+
+    /// Export info for `#[export(element = E)]` fields, i.e. untyped arrays that the editor restricts to elements of type `E`
+    /// ([`PropertyHint::ARRAY_TYPE`]).
+    ///
+    /// The bounds ensure that `T` is passed to Godot as an untyped array, and that `E` can be stored in a `Variant`.
+    pub fn export_array_element<T, E>() -> PropertyHintInfo
+    where
+        T: Var,
+        T::Via: GodotType<Ffi = crate::builtin::VariantArray>,
+        E: GodotType,
+    {
+        use godot_ffi::GodotFfi as _;
+
+        if E::Ffi::variant_type() == godot_ffi::VariantType::Nil {
+            return PropertyHintInfo::with_hint_none("");
+        }
+
+        PropertyHintInfo {
+            hint: PropertyHint::ARRAY_TYPE,
+            hint_string: E::godot_type_name().into(),
+        }
+    }
+
     macro_rules! default_export_funcs {
         (
             $( $function_name:ident => $property_hint:ident, )*
@@ -11,8 +11,10 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::builder::ClassBuilder;
+use crate::builtin::meta::PropertyInfo;
 use crate::builtin::{StringName, Variant};
-use crate::obj::{cap, Base, GodotClass, UserClass};
+use crate::engine::{global, ClassDb, Object};
+use crate::obj::{cap, Base, GodotClass, Inherits, UserClass};
 use crate::storage::{as_storage, InstanceStorage, Storage, StorageRefCounted};
 use godot_ffi as sys;
 use std::any::Any;
@@ -181,6 +183,110 @@ pub unsafe extern "C" fn get_property<T: cap::GodotGet>(
     }
 }
 
+#[cfg(since_api = "4.2")]
+pub unsafe extern "C" fn validate_property<T: cap::GodotValidateProperty>(
+    instance: sys::GDExtensionClassInstancePtr,
+    property_info: *mut sys::GDExtensionPropertyInfo,
+) -> sys::GDExtensionBool {
+    let storage = as_storage::<T>(instance);
+    let instance = storage.get();
+
+    adjust_property(&*instance, &mut *property_info) as sys::GDExtensionBool
+}
+
+/// Applies the derived adjustments to the property info owned by Godot. Returns whether it was changed.
+///
+/// Only the name is read and only the adjusted values are written, since this runs for every property of the class, including
+/// inherited ones -- whose hints need not be known to this API version, so they cannot always be converted to a [`PropertyInfo`].
+#[cfg(since_api = "4.2")]
+unsafe fn adjust_property<T: cap::GodotValidateProperty>(
+    instance: &T,
+    property_info: &mut sys::GDExtensionPropertyInfo,
+) -> bool {
+    use crate::obj::{EngineBitfield as _, EngineEnum as _};
+
+    // Godot keeps ownership of the string name, so the copy needs its own ref-count.
+    let property_name = StringName::from_string_sys(property_info.name);
+    std::mem::forget(property_name.clone());
+
+    let Some(adjustment) = instance.__godot_validate_property(&property_name) else {
+        return false;
+    };
+
+    if let Some(hint_info) = adjustment.hint {
+        property_info.hint = u32::try_from(hint_info.hint.ord()).expect("hint.ord()");
+        // Replaces (and destroys) the string owned by Godot.
+        hint_info
+            .hint_string
+            .move_string_ptr(property_info.hint_string);
+    }
+
+    if adjustment.is_read_only {
+        let read_only =
+            u32::try_from(global::PropertyUsageFlags::READ_ONLY.ord()).expect("usage.ord()");
+        property_info.usage |= read_only;
+    }
+
+    true
+}
+
+/// Like [`validate_property`], additionally calling the user's `validate_property()` afterwards.
+#[cfg(since_api = "4.2")]
+pub unsafe extern "C" fn validate_property_user<
+    T: cap::GodotValidateProperty + cap::GodotUserValidateProperty,
+>(
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    property_info: *mut sys::GDExtensionPropertyInfo,
+) -> sys::GDExtensionBool {
+    let storage = as_storage::<T>(instance_ptr);
+    let instance = storage.get();
+    let property_info = &mut *property_info;
+
+    let is_adjusted = adjust_property(&*instance, property_info) as sys::GDExtensionBool;
+
+    // Skip the user callback for hints unknown to this API version, rather than panicking across the FFI boundary.
+    let Some(mut property) = PropertyInfo::try_from_sys_ref(property_info) else {
+        return is_adjusted;
+    };
+    T::__godot_user_validate_property(&*instance, &mut property);
+    property.write_to_sys(property_info);
+
+    true as sys::GDExtensionBool
+}
+
+pub unsafe extern "C" fn property_can_revert<T: cap::GodotPropertyRevert>(
+    instance: sys::GDExtensionClassInstancePtr,
+    name: sys::GDExtensionConstStringNamePtr,
+) -> sys::GDExtensionBool {
+    let storage = as_storage::<T>(instance);
+    let instance = storage.get();
+    let property = StringName::from_string_sys(sys::force_mut_ptr(name));
+
+    std::mem::forget(property.clone());
+
+    T::__godot_property_get_revert(&*instance, property).is_some() as sys::GDExtensionBool
+}
+
+pub unsafe extern "C" fn property_get_revert<T: cap::GodotPropertyRevert>(
+    instance: sys::GDExtensionClassInstancePtr,
+    name: sys::GDExtensionConstStringNamePtr,
+    ret: sys::GDExtensionVariantPtr,
+) -> sys::GDExtensionBool {
+    let storage = as_storage::<T>(instance);
+    let instance = storage.get();
+    let property = StringName::from_string_sys(sys::force_mut_ptr(name));
+
+    std::mem::forget(property.clone());
+
+    match T::__godot_property_get_revert(&*instance, property) {
+        Some(value) => {
+            value.move_var_ptr(ret);
+            true as sys::GDExtensionBool
+        }
+        None => false as sys::GDExtensionBool,
+    }
+}
+
 pub unsafe extern "C" fn set_property<T: cap::GodotSet>(
     instance: sys::GDExtensionClassInstancePtr,
     name: sys::GDExtensionConstStringNamePtr,
@@ -198,6 +304,154 @@ pub unsafe extern "C" fn set_property<T: cap::GodotSet>(
     T::__godot_set_property(&mut *instance, property, value) as sys::GDExtensionBool
 }
 
+/// Reads `name` through the registered getter, then passes the value through `#[class(before_save_fn)]`.
+pub unsafe extern "C" fn get_property_hooked<T>(
+    instance: sys::GDExtensionClassInstancePtr,
+    name: sys::GDExtensionConstStringNamePtr,
+    ret: sys::GDExtensionVariantPtr,
+) -> sys::GDExtensionBool
+where
+    T: cap::GodotStorageHooks,
+    T::Base: Inherits<Object>,
+{
+    let storage = as_storage::<T>(instance);
+    let property = StringName::from_string_sys(sys::force_mut_ptr(name));
+
+    std::mem::forget(property.clone());
+
+    if !T::__godot_is_hooked_property(&property) {
+        return false as sys::GDExtensionBool;
+    }
+
+    // The instance must not be bound here, as the getter binds it itself.
+    let object = storage.base().to_gd().upcast::<Object>();
+    let value = ClassDb::singleton().class_get_property(object, property.clone());
+
+    let value = T::__godot_before_save(&*storage.get(), property, value);
+    value.move_var_ptr(ret);
+
+    true as sys::GDExtensionBool
+}
+
+/// Assigns `name` through the registered setter, then runs `#[class(after_load_fn)]`.
+pub unsafe extern "C" fn set_property_hooked<T>(
+    instance: sys::GDExtensionClassInstancePtr,
+    name: sys::GDExtensionConstStringNamePtr,
+    value: sys::GDExtensionConstVariantPtr,
+) -> sys::GDExtensionBool
+where
+    T: cap::GodotStorageHooks,
+    T::Base: Inherits<Object>,
+{
+    let storage = as_storage::<T>(instance);
+    let property = StringName::from_string_sys(sys::force_mut_ptr(name));
+    let value = Variant::from_var_sys(sys::force_mut_ptr(value));
+
+    std::mem::forget(property.clone());
+    std::mem::forget(value.clone());
+
+    if !T::__godot_is_hooked_property(&property) {
+        return false as sys::GDExtensionBool;
+    }
+
+    // The instance must not be bound here, as the setter binds it itself.
+    let object = storage.base().to_gd().upcast::<Object>();
+    let error = ClassDb::singleton().class_set_property(object, property.clone(), value);
+    if error != global::Error::OK {
+        return false as sys::GDExtensionBool;
+    }
+
+    T::__godot_after_load(&mut *storage.get_mut(), property);
+
+    true as sys::GDExtensionBool
+}
+
+/// Like [`set_property`], additionally notifying the editor if the assignment changed the property list.
+pub unsafe extern "C" fn set_property_list_changing<T>(
+    instance: sys::GDExtensionClassInstancePtr,
+    name: sys::GDExtensionConstStringNamePtr,
+    value: sys::GDExtensionConstVariantPtr,
+) -> sys::GDExtensionBool
+where
+    T: cap::GodotSet + cap::GodotGetPropertyList,
+    T::Base: Inherits<Object>,
+{
+    let property_names = |instance: &T| -> Vec<StringName> {
+        T::__godot_get_property_list(instance)
+            .into_iter()
+            .map(|info| info.property_name)
+            .collect()
+    };
+
+    let storage = as_storage::<T>(instance);
+    let before = property_names(&*storage.get());
+    let result = set_property::<T>(instance, name, value);
+    let after = property_names(&*storage.get());
+
+    // The instance must not be bound here, as Godot queries the new property list right away.
+    if before != after {
+        let mut object = storage.base().to_gd().upcast::<Object>();
+        object.notify_property_list_changed();
+    }
+
+    result
+}
+
+/// Returns the properties listed by `T`, terminated by an empty entry.
+pub unsafe extern "C" fn get_property_list<T: cap::GodotGetPropertyList>(
+    instance: sys::GDExtensionClassInstancePtr,
+    count: *mut u32,
+) -> *const sys::GDExtensionPropertyInfo {
+    let storage = as_storage::<T>(instance);
+    let instance = storage.get();
+
+    let mut list: Vec<_> = T::__godot_get_property_list(&*instance)
+        .into_iter()
+        .map(PropertyInfo::into_owned_property_sys)
+        .collect();
+
+    *count = u32::try_from(list.len()).expect("property count fits in u32");
+
+    // Before Godot 4.3, the free callback is not passed the length, so the end is marked with a null name.
+    list.push(PropertyInfo::empty_sys());
+
+    Box::leak(list.into_boxed_slice()).as_ptr()
+}
+
+#[cfg(before_api = "4.3")]
+pub unsafe extern "C" fn free_property_list<T: cap::GodotGetPropertyList>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    list: *const sys::GDExtensionPropertyInfo,
+) {
+    let mut count = 0;
+    while !(*list.add(count)).name.is_null() {
+        count += 1;
+    }
+
+    free_property_list_entries(list, count);
+}
+
+#[cfg(since_api = "4.3")]
+pub unsafe extern "C" fn free_property_list<T: cap::GodotGetPropertyList>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    list: *const sys::GDExtensionPropertyInfo,
+    count: u32,
+) {
+    free_property_list_entries(list, count as usize);
+}
+
+/// Frees a list created by [`get_property_list`] with `count` entries, not including the terminating one.
+unsafe fn free_property_list_entries(list: *const sys::GDExtensionPropertyInfo, count: usize) {
+    let list = Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+        sys::force_mut_ptr(list),
+        count + 1,
+    ));
+
+    for info in &list[..count] {
+        PropertyInfo::free_owned_property_sys(info);
+    }
+}
+
 pub unsafe extern "C" fn reference<T: GodotClass>(instance: sys::GDExtensionClassInstancePtr) {
     let storage = as_storage::<T>(instance);
     storage.on_inc_ref();
@@ -236,6 +490,10 @@ pub fn register_user_properties<T: cap::ImplementsGodotExports>(_class_builder:
     T::__register_exports();
 }
 
+pub fn register_user_mixin_methods<T: cap::ImplementsGodotMixins>(_class_builder: &mut dyn Any) {
+    T::__register_mixin_methods();
+}
+
 pub fn register_user_methods_constants<T: cap::ImplementsGodotApi>(_class_builder: &mut dyn Any) {
     // let class_builder = class_builder
     //     .downcast_mut::<ClassBuilder<T>>()
@@ -0,0 +1,238 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Registers doc comments of classes, properties, `#[func]` methods and `#[signal]`s with the editor's help (class reference).
+//!
+//! The macros only collect names and doc comments. Signatures are read back from `ClassDb` once all classes are registered,
+//! so that parameter and return types appear exactly as Godot sees them.
+
+use crate::builtin::meta::ClassName;
+use crate::builtin::{Array, Dictionary, GString, StringName, VariantType};
+use crate::engine::{utilities, ClassDb};
+use crate::registry::{ClassPlugin, InherentImplDocs, PluginItem, StructDocs};
+use godot_ffi as sys;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Loads documentation of all registered classes into the editor help. Must be called once all classes are registered.
+pub(crate) fn register_docs() {
+    for (class_name, (base_class_name, struct_docs, impl_docs)) in collect_docs() {
+        if struct_docs.is_none() && impl_docs.is_empty() {
+            continue;
+        }
+
+        let xml = make_class_xml(
+            class_name,
+            base_class_name,
+            struct_docs.as_ref(),
+            &impl_docs,
+        );
+
+        // SAFETY: Godot copies the passed UTF-8 buffer, which is valid for `len` bytes.
+        unsafe {
+            sys::interface_fn!(editor_help_load_xml_from_utf8_chars_and_len)(
+                xml.as_ptr() as *const std::ffi::c_char,
+                xml.len() as sys::GDExtensionInt,
+            );
+        }
+    }
+}
+
+/// Returns the class reference XML that [`register_docs()`] loads into the editor help for `class_name`, or `None` if the class has
+/// no doc comments.
+///
+/// The editor help cannot be queried at runtime, so this lets tests check what is registered.
+pub fn class_docs_xml(class_name: ClassName) -> Option<String> {
+    let (base_class_name, struct_docs, impl_docs) = collect_docs().remove(&class_name)?;
+    if struct_docs.is_none() && impl_docs.is_empty() {
+        return None;
+    }
+
+    Some(make_class_xml(
+        class_name,
+        base_class_name,
+        struct_docs.as_ref(),
+        &impl_docs,
+    ))
+}
+
+/// Base class, struct docs and impl docs of every registered class.
+fn collect_docs() -> HashMap<ClassName, (ClassName, Option<StructDocs>, Vec<InherentImplDocs>)> {
+    let mut base_classes = HashMap::<ClassName, ClassName>::new();
+    let mut struct_docs = HashMap::<ClassName, StructDocs>::new();
+    let mut class_docs = HashMap::<ClassName, Vec<InherentImplDocs>>::new();
+
+    crate::private::iterate_plugins(|elem: &ClassPlugin| match &elem.item {
+        PluginItem::Struct {
+            base_class_name,
+            docs,
+            ..
+        } => {
+            base_classes.insert(elem.class_name, *base_class_name);
+            if let Some(docs) = docs {
+                struct_docs.insert(elem.class_name, *docs);
+            }
+        }
+        PluginItem::InherentImpl {
+            docs: Some(docs), ..
+        } => {
+            class_docs.entry(elem.class_name).or_default().push(*docs);
+        }
+        _ => {}
+    });
+
+    base_classes
+        .into_iter()
+        .map(|(class_name, base_class_name)| {
+            let struct_docs = struct_docs.get(&class_name).copied();
+            let impl_docs = class_docs.remove(&class_name).unwrap_or_default();
+
+            (class_name, (base_class_name, struct_docs, impl_docs))
+        })
+        .collect()
+}
+
+fn make_class_xml(
+    class_name: ClassName,
+    base_class_name: ClassName,
+    struct_docs: Option<&StructDocs>,
+    docs: &[InherentImplDocs],
+) -> String {
+    let class = class_name.to_string_name();
+    let mut xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" ?><class name="{class_name}" inherits="{base_class_name}">"#
+    );
+
+    // Godot shows the brief description in lists and tooltips, and the full text on the class page.
+    let description = struct_docs.map_or("", |docs| docs.description);
+    let brief = description.split("\n\n").next().unwrap_or_default();
+    let _ = write!(
+        xml,
+        "<brief_description>{brief}</brief_description><description>{description}</description>",
+        brief = format_description(brief),
+        description = format_description(description),
+    );
+
+    // Lists are limited to the class itself, so inherited methods, properties and signals are not documented again.
+    let methods = ClassDb::singleton()
+        .class_get_method_list_ex(class.clone())
+        .no_inheritance(true)
+        .done();
+    let properties = ClassDb::singleton()
+        .class_get_property_list_ex(class.clone())
+        .no_inheritance(true)
+        .done();
+    let signals = ClassDb::singleton()
+        .class_get_signal_list_ex(class)
+        .no_inheritance(true)
+        .done();
+
+    xml.push_str("<methods>");
+    for (name, description) in docs.iter().flat_map(|docs| docs.methods) {
+        // Methods removed by #[cfg] are not registered.
+        let Some(method) = find_by_name(&methods, name) else {
+            continue;
+        };
+
+        let ret: Dictionary = method.get_or_nil("return").to();
+        let _ = write!(
+            xml,
+            r#"<method name="{name}"><return type="{ty}"/>{params}<description>{description}</description></method>"#,
+            ty = type_name(&ret, "void"),
+            params = make_params_xml(&method),
+            description = format_description(description),
+        );
+    }
+    xml.push_str("</methods>");
+
+    xml.push_str("<members>");
+    for (name, description) in struct_docs.iter().flat_map(|docs| docs.members) {
+        let Some(property) = find_by_name(&properties, name) else {
+            continue;
+        };
+
+        let _ = write!(
+            xml,
+            r#"<member name="{name}" type="{ty}">{description}</member>"#,
+            ty = type_name(&property, "Variant"),
+            description = format_description(description),
+        );
+    }
+    xml.push_str("</members>");
+
+    xml.push_str("<signals>");
+    for (name, description) in docs.iter().flat_map(|docs| docs.signals) {
+        let Some(signal) = find_by_name(&signals, name) else {
+            continue;
+        };
+
+        let _ = write!(
+            xml,
+            r#"<signal name="{name}">{params}<description>{description}</description></signal>"#,
+            params = make_params_xml(&signal),
+            description = format_description(description),
+        );
+    }
+    xml.push_str("</signals>");
+
+    xml.push_str("</class>");
+    xml
+}
+
+fn find_by_name(list: &Array<Dictionary>, name: &str) -> Option<Dictionary> {
+    list.iter_shared()
+        .find(|dict| dict.get_or_nil("name").to::<GString>() == name.into())
+}
+
+fn make_params_xml(method_or_signal: &Dictionary) -> String {
+    let args: Array<Dictionary> = method_or_signal.get_or_nil("args").to();
+
+    let mut xml = String::new();
+    for (index, arg) in args.iter_shared().enumerate() {
+        let _ = write!(
+            xml,
+            r#"<param index="{index}" name="{name}" type="{ty}"/>"#,
+            name = arg.get_or_nil("name").to::<GString>(),
+            ty = type_name(&arg, "Variant"),
+        );
+    }
+    xml
+}
+
+/// Godot type name of a property dictionary, as used in the class reference.
+fn type_name(property: &Dictionary, nil_name: &str) -> String {
+    let variant_type: i64 = property.get_or_nil("type").to();
+
+    if variant_type == VariantType::Nil as i64 {
+        return nil_name.to_string();
+    }
+
+    let class_name: StringName = property.get_or_nil("class_name").to();
+    if variant_type == VariantType::Object as i64 && !class_name.is_empty() {
+        return class_name.to_string();
+    }
+
+    utilities::type_string(variant_type).to_string()
+}
+
+/// Turns the lines of a `///` comment into XML text.
+fn format_description(doc: &str) -> String {
+    let text = doc
+        .lines()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    escape_xml(text.trim())
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -6,8 +6,9 @@
  */
 
 use crate::builtin::meta::ClassName;
+use crate::engine::{ClassDb, Engine, Object};
 use crate::init::InitLevel;
-use crate::obj::{cap, GodotClass};
+use crate::obj::{cap, Gd, GodotClass, InstanceId};
 use crate::out;
 use godot_ffi as sys;
 use std::any::Any;
@@ -17,6 +18,12 @@ use sys::{interface_fn, Global, GlobalGuard, GlobalLockError};
 
 pub mod callbacks;
 
+#[cfg(since_api = "4.3")]
+mod docs;
+
+#[cfg(since_api = "4.3")]
+pub use docs::class_docs_xml;
+
 // Needed for class unregistering. The variable is populated during class registering. There is no actual concurrency here, because Godot
 // calls register/unregister in the main thread. Mutex is just casual way to ensure safety in this non-performance-critical path.
 // Note that we panic on concurrent access instead of blocking (fail-fast approach). If that happens, most likely something changed on Godot
@@ -80,11 +87,39 @@ pub enum PluginItem {
         /// Callback to library-generated function which registers properties in the `struct` definition.
         register_properties_fn: ErasedRegisterFn,
 
+        /// Callback to library-generated function which registers the `#[func]` methods of `#[mixin]` fields.
+        register_mixin_methods_fn: Option<ErasedRegisterFn>,
+
         free_fn: unsafe extern "C" fn(
             _class_user_data: *mut std::ffi::c_void,
             instance: sys::GDExtensionClassInstancePtr,
         ),
 
+        /// Adjusts property hints at runtime, used for `#[export(range_max_from = ...)]`.
+        validate_property_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_property: *mut sys::GDExtensionPropertyInfo,
+            ) -> sys::GDExtensionBool,
+        >,
+
+        /// Whether a property can be reverted in the inspector, used for `#[export(default = ...)]`.
+        property_can_revert_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+            ) -> sys::GDExtensionBool,
+        >,
+
+        /// Value a property is reverted to in the inspector, used for `#[export(default = ...)]`.
+        property_get_revert_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+                r_ret: sys::GDExtensionVariantPtr,
+            ) -> sys::GDExtensionBool,
+        >,
+
         /// Calls `__before_ready()`, if there is at least one `OnReady` field. Used if there is no `#[godot_api] impl` block
         /// overriding ready.
         default_get_virtual_fn: Option<
@@ -94,6 +129,33 @@ pub enum PluginItem {
             ) -> sys::GDExtensionClassCallVirtual,
         >,
 
+        /// `get` function for `#[class(before_save_fn = ...)]` and enum classes; a user-defined `get_property()` takes precedence.
+        generated_get_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+                r_ret: sys::GDExtensionVariantPtr,
+            ) -> sys::GDExtensionBool,
+        >,
+
+        /// `set` function for `#[class(after_load_fn = ...)]` and enum classes; a user-defined `set_property()` takes precedence.
+        generated_set_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+                p_value: sys::GDExtensionConstVariantPtr,
+            ) -> sys::GDExtensionBool,
+        >,
+
+        /// Lists the properties of the current variant, used for enum classes.
+        get_property_list_fn: sys::GDExtensionClassGetPropertyList,
+
+        /// Frees the list returned by `get_property_list_fn`.
+        #[cfg(before_api = "4.3")]
+        free_property_list_fn: sys::GDExtensionClassFreePropertyList,
+        #[cfg(since_api = "4.3")]
+        free_property_list_fn: sys::GDExtensionClassFreePropertyList2,
+
         /// Whether `#[class(tool)]` was used.
         is_tool: bool,
 
@@ -103,8 +165,14 @@ pub enum PluginItem {
         /// Whether `#[class(hidden)]` was used.
         is_hidden: bool,
 
+        /// Name of the engine singleton, if `#[class(singleton)]` was used.
+        singleton_name: Option<&'static str>,
+
         /// Whether the class has a default constructor.
         is_instantiable: bool,
+
+        /// Doc comments of the struct and its properties, if any.
+        docs: Option<StructDocs>,
     },
 
     /// Collected from `#[godot_api] impl MyClass`.
@@ -113,6 +181,18 @@ pub enum PluginItem {
         ///
         /// Always present since that's the entire point of this `impl` block.
         register_methods_constants_fn: ErasedRegisterFn,
+
+        /// `to_string` function, if the `impl` block declares `fn _to_string(&self)`.
+        user_to_string_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                r_is_valid: *mut sys::GDExtensionBool,
+                r_out: sys::GDExtensionStringPtr,
+            ),
+        >,
+
+        /// Doc comments of `#[func]` methods and `#[signal]`s, shown in the editor help (requires Godot 4.3).
+        docs: Option<InherentImplDocs>,
     },
 
     /// Collected from `#[godot_api] impl I... for MyClass`.
@@ -176,6 +256,14 @@ pub enum PluginItem {
             ) -> sys::GDExtensionBool,
         >,
 
+        /// User-defined `validate_property` function; also applies the hints of `validate_property_fn` in [`PluginItem::Struct`].
+        user_validate_property_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_property: *mut sys::GDExtensionPropertyInfo,
+            ) -> sys::GDExtensionBool,
+        >,
+
         /// Callback for other virtuals.
         get_virtual_fn: unsafe extern "C" fn(
             p_userdata: *mut std::os::raw::c_void,
@@ -184,12 +272,36 @@ pub enum PluginItem {
     },
 }
 
+/// Doc comments collected from a `#[derive(GodotClass)]` struct.
+#[derive(Copy, Clone, Debug)]
+pub struct StructDocs {
+    /// Doc comment of the struct itself; may be empty.
+    pub description: &'static str,
+
+    /// Name and doc comment of each documented `#[var]` or `#[export]` field.
+    pub members: &'static [(&'static str, &'static str)],
+}
+
+/// Doc comments collected from a `#[godot_api] impl` block.
+#[derive(Copy, Clone, Debug)]
+pub struct InherentImplDocs {
+    /// Godot name and doc comment of each documented `#[func]`.
+    pub methods: &'static [(&'static str, &'static str)],
+
+    /// Name and doc comment of each documented `#[signal]`.
+    pub signals: &'static [(&'static str, &'static str)],
+}
+
 /// Represents a class who is currently loaded and retained in memory.
 ///
 /// Besides the name, this type holds information relevant for the deregistration of the class.
 pub struct LoadedClass {
     name: ClassName,
     is_editor_plugin: bool,
+    singleton_name: Option<&'static str>,
+
+    /// Instance registered as engine singleton; created once all classes of the init level are registered.
+    singleton_instance: Option<InstanceId>,
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -200,6 +312,7 @@ struct ClassRegistrationInfo {
     parent_class_name: Option<ClassName>,
     // Following functions are stored separately, since their order matters.
     register_methods_constants_fn: Option<ErasedRegisterFn>,
+    register_mixin_methods_fn: Option<ErasedRegisterFn>,
     register_properties_fn: Option<ErasedRegisterFn>,
     user_register_fn: Option<ErasedRegisterFn>,
     default_virtual_fn: sys::GDExtensionClassGetVirtual, // Option (set if there is at least one OnReady field)
@@ -216,6 +329,7 @@ struct ClassRegistrationInfo {
     #[allow(dead_code)] // Currently unused; may be useful for diagnostics in the future.
     init_level: InitLevel,
     is_editor_plugin: bool,
+    singleton_name: Option<&'static str>,
 
     /// Used to ensure that each component is only filled once.
     component_already_filled: [bool; 3],
@@ -283,10 +397,11 @@ pub fn register_class<
         "cannot register () or unnamed class"
     );
 
-    register_class_raw(ClassRegistrationInfo {
+    let mut info = ClassRegistrationInfo {
         class_name: T::class_name(),
         parent_class_name: Some(T::Base::class_name()),
         register_methods_constants_fn: None,
+        register_mixin_methods_fn: None,
         register_properties_fn: None,
         user_register_fn: Some(ErasedRegisterFn {
             raw: callbacks::register_class_by_builder::<T>,
@@ -296,8 +411,12 @@ pub fn register_class<
         godot_params,
         init_level: T::INIT_LEVEL,
         is_editor_plugin: false,
+        singleton_name: None,
         component_already_filled: Default::default(), // [false; N]
-    });
+    };
+
+    register_class_raw(&mut info);
+    register_class_symbols(&info);
 }
 
 /// Lets Godot know about all classes that have self-registered through the plugin system.
@@ -327,12 +446,18 @@ pub fn auto_register_classes(init_level: InitLevel) {
     });
 
     let mut loaded_classes_by_level = global_loaded_classes();
-    for info in map.into_values() {
+    let mut infos: Vec<ClassRegistrationInfo> = map.into_values().collect();
+
+    // Classes are registered before any of their methods and properties. This way, classes that refer to each other
+    // (e.g. through `Gd<Other>` fields) see each other already registered, independent of registration order.
+    for info in infos.iter_mut() {
         let class_name = info.class_name;
         out!("Register class:   {class_name} at level `{init_level:?}`");
         let loaded_class = LoadedClass {
             name: class_name,
             is_editor_plugin: info.is_editor_plugin,
+            singleton_name: info.singleton_name,
+            singleton_instance: None,
         };
         loaded_classes_by_level
             .entry(init_level)
@@ -340,7 +465,25 @@ pub fn auto_register_classes(init_level: InitLevel) {
             .push(loaded_class);
 
         register_class_raw(info);
-        out!("Class {class_name} loaded");
+    }
+
+    for info in infos.iter() {
+        register_class_symbols(info);
+        out!("Class {} loaded", info.class_name);
+    }
+
+    // Singletons are only instantiated now, as their constructors may use other classes of the same level.
+    for loaded_class in loaded_classes_by_level.entry(init_level).or_default() {
+        if let Some(singleton_name) = loaded_class.singleton_name {
+            loaded_class.singleton_instance =
+                Some(register_singleton(loaded_class.name, singleton_name));
+        }
+    }
+
+    // The editor level comes last, so all classes and their methods are known at this point.
+    #[cfg(since_api = "4.3")]
+    if init_level == InitLevel::Editor {
+        docs::register_docs();
     }
 
     out!("All classes for level `{init_level:?}` auto-registered.");
@@ -352,6 +495,16 @@ pub fn unregister_classes(init_level: InitLevel) {
         .remove(&init_level)
         .unwrap_or_default();
     out!("Unregistering classes of level {init_level:?}...");
+
+    // Free singletons before any class is unregistered, in case their destructors access other classes.
+    for class in loaded_classes_current_level.iter() {
+        if let (Some(singleton_name), Some(instance_id)) =
+            (class.singleton_name, class.singleton_instance)
+        {
+            unregister_singleton(singleton_name, instance_id);
+        }
+    }
+
     for class_name in loaded_classes_current_level.into_iter().rev() {
         unregister_class_raw(class_name);
     }
@@ -382,16 +535,28 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             generated_create_fn,
             generated_recreate_fn,
             register_properties_fn,
+            register_mixin_methods_fn,
             free_fn,
+            validate_property_fn,
+            property_can_revert_fn,
+            property_get_revert_fn,
             default_get_virtual_fn,
+            generated_get_fn,
+            generated_set_fn,
+            get_property_list_fn,
+            free_property_list_fn,
             is_tool,
             is_editor_plugin,
             is_hidden,
             is_instantiable,
+            singleton_name,
+            docs: _,
         } => {
             c.parent_class_name = Some(base_class_name);
+            c.singleton_name = singleton_name;
             c.default_virtual_fn = default_get_virtual_fn;
             c.register_properties_fn = Some(register_properties_fn);
+            c.register_mixin_methods_fn = register_mixin_methods_fn;
             c.is_editor_plugin = is_editor_plugin;
 
             // Classes marked #[class(no_init)] are translated to "abstract" in Godot. This disables their default constructor.
@@ -404,6 +569,18 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             // See also: https://github.com/godotengine/godot/pull/58972
             c.godot_params.is_abstract = (!is_instantiable) as sys::GDExtensionBool;
             c.godot_params.free_instance_func = Some(free_fn);
+            c.godot_params.property_can_revert_func = property_can_revert_fn;
+            c.godot_params.property_get_revert_func = property_get_revert_fn;
+
+            // User-defined get_property()/set_property() take precedence over the storage hooks.
+            if c.godot_params.get_func.is_none() {
+                c.godot_params.get_func = generated_get_fn;
+            }
+            if c.godot_params.set_func.is_none() {
+                c.godot_params.set_func = generated_set_fn;
+            }
+            c.godot_params.get_property_list_func = get_property_list_fn;
+            c.godot_params.free_property_list_func = free_property_list_fn;
 
             fill_into(
                 &mut c.godot_params.create_instance_func,
@@ -420,11 +597,18 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
                 .expect("duplicate: recreate_instance_func (def)");
 
                 c.godot_params.is_exposed = (!is_hidden) as sys::GDExtensionBool;
+                // A user-defined validate_property() takes precedence, as it applies the derived hints itself.
+                if c.godot_params.validate_property_func.is_none() {
+                    c.godot_params.validate_property_func = validate_property_fn;
+                }
             }
 
             #[cfg(before_api = "4.2")]
             assert!(generated_recreate_fn.is_none()); // not used
 
+            #[cfg(before_api = "4.2")]
+            assert!(validate_property_fn.is_none()); // not used
+
             #[cfg(since_api = "4.3")]
             {
                 c.godot_params.is_runtime =
@@ -434,8 +618,13 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
 
         PluginItem::InherentImpl {
             register_methods_constants_fn,
+            user_to_string_fn,
+            docs: _, // registered separately, see docs::register_docs()
         } => {
             c.register_methods_constants_fn = Some(register_methods_constants_fn);
+
+            fill_into(&mut c.godot_params.to_string_func, user_to_string_fn)
+                .expect("duplicate: to_string_func (inherent)");
         }
 
         PluginItem::ITraitImpl {
@@ -446,6 +635,7 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             user_on_notification_fn,
             user_set_fn,
             user_get_fn,
+            user_validate_property_fn,
             get_virtual_fn,
         } => {
             c.user_register_fn = user_register_fn;
@@ -463,10 +653,24 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             #[cfg(before_api = "4.2")]
             assert!(user_recreate_fn.is_none()); // not used
 
-            c.godot_params.to_string_func = user_to_string_fn;
+            fill_into(&mut c.godot_params.to_string_func, user_to_string_fn)
+                .expect("duplicate: to_string_func (i)");
             c.godot_params.notification_func = user_on_notification_fn;
-            c.godot_params.set_func = user_set_fn;
-            c.godot_params.get_func = user_get_fn;
+            if user_set_fn.is_some() {
+                c.godot_params.set_func = user_set_fn;
+            }
+            if user_get_fn.is_some() {
+                c.godot_params.get_func = user_get_fn;
+            }
+
+            #[cfg(since_api = "4.2")]
+            if user_validate_property_fn.is_some() {
+                c.godot_params.validate_property_func = user_validate_property_fn;
+            }
+
+            #[cfg(before_api = "4.2")]
+            assert!(user_validate_property_fn.is_none()); // not used
+
             c.user_virtual_fn = Some(get_virtual_fn);
         }
     }
@@ -485,9 +689,7 @@ fn fill_into<T>(dst: &mut Option<T>, src: Option<T>) -> Result<(), ()> {
 }
 
 /// Registers a class with given the dynamic type information `info`.
-fn register_class_raw(mut info: ClassRegistrationInfo) {
-    // First register class...
-
+fn register_class_raw(info: &mut ClassRegistrationInfo) {
     let class_name = info.class_name;
     let parent_class_name = info
         .parent_class_name
@@ -536,20 +738,27 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
             "failed to register class `{class_name}`; check preceding Godot stderr messages",
         );
     }
+}
 
-    // ...then custom symbols
+/// Registers methods, constants and properties of a class, once all classes of the same init level are registered.
+fn register_class_symbols(info: &ClassRegistrationInfo) {
+    let class_name = info.class_name;
 
     //let mut class_builder = crate::builder::ClassBuilder::<?>::new();
     let mut class_builder = 0; // TODO dummy argument; see callbacks
 
     // Order of the following registrations is crucial:
-    // 1. Methods and constants.
+    // 1. Methods and constants, including methods of mixins.
     // 2. Properties (they may depend on get/set methods).
     // 3. User-defined registration function (intuitively, user expects their own code to run after proc-macro generated code).
     if let Some(register_fn) = info.register_methods_constants_fn {
         (register_fn.raw)(&mut class_builder);
     }
 
+    if let Some(register_fn) = info.register_mixin_methods_fn {
+        (register_fn.raw)(&mut class_builder);
+    }
+
     if let Some(register_fn) = info.register_properties_fn {
         (register_fn.raw)(&mut class_builder);
     }
@@ -564,6 +773,30 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
     }
 }
 
+/// Instantiates the class and registers the instance as an engine singleton, accessible via `Engine::get_singleton()`.
+fn register_singleton(class_name: ClassName, singleton_name: &'static str) -> InstanceId {
+    let instance = ClassDb::singleton()
+        .instantiate(class_name.to_string_name())
+        .try_to::<Gd<Object>>()
+        .unwrap_or_else(|_| panic!("failed to instantiate singleton class {class_name}"));
+
+    let instance_id = instance.instance_id();
+    Engine::singleton().register_singleton(singleton_name.into(), instance);
+    out!("Singleton {singleton_name} registered");
+
+    instance_id
+}
+
+fn unregister_singleton(singleton_name: &'static str, instance_id: InstanceId) {
+    Engine::singleton().unregister_singleton(singleton_name.into());
+
+    // The user may have freed the instance already.
+    if let Ok(instance) = Gd::<Object>::try_from_instance_id(instance_id) {
+        instance.free();
+    }
+    out!("Singleton {singleton_name} unregistered");
+}
+
 fn unregister_class_raw(class: LoadedClass) {
     let class_name = class.name;
     out!("Unregister class: {class_name}");
@@ -599,6 +832,7 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         class_name,
         parent_class_name: None,
         register_methods_constants_fn: None,
+        register_mixin_methods_fn: None,
         register_properties_fn: None,
         user_register_fn: None,
         default_virtual_fn: None,
@@ -606,6 +840,7 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         godot_params: default_creation_info(),
         init_level: InitLevel::Scene,
         is_editor_plugin: false,
+        singleton_name: None,
         component_already_filled: Default::default(), // [false; N]
     }
 }
@@ -85,6 +85,16 @@ pub unsafe trait Storage {
     /// they are violated.
     fn get_mut(&self) -> godot_cell::MutGuard<'_, Self::Instance>;
 
+    /// Returns a mutable/exclusive reference to this storage's instance, or an error if the Rust borrow rules would be violated.
+    fn try_get_mut(
+        &self,
+    ) -> Result<godot_cell::MutGuard<'_, Self::Instance>, Box<dyn std::error::Error>>;
+
+    /// Returns a raw pointer to this storage's instance, without registering a borrow.
+    ///
+    /// Rust's rules surrounding references are **not** checked; the caller must uphold them when dereferencing the pointer.
+    fn get_ptr_unchecked(&self) -> *mut Self::Instance;
+
     /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`.
     ///
     /// This can use the provided `instance` to provide extra safety guarantees such as allowing reentrant
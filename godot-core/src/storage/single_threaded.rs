@@ -83,6 +83,14 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
             })
     }
 
+    fn try_get_mut(&self) -> Result<godot_cell::MutGuard<'_, T>, Box<dyn std::error::Error>> {
+        self.user_instance.as_ref().borrow_mut()
+    }
+
+    fn get_ptr_unchecked(&self) -> *mut T {
+        self.user_instance.as_ref().as_ptr()
+    }
+
     fn get_inaccessible<'a: 'b, 'b>(
         &'a self,
         value: &'b mut Self::Instance,
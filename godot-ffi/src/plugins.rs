@@ -6,6 +6,15 @@
  */
 
 /// Distributed self-registration of "plugins" without central list
+///
+/// Each `plugin_add!` emits a static constructor: a function pointer in the platform's initializer section (`.init_array`,
+/// `__mod_init_func` or `.CRT$XCU`), which the dynamic loader runs when the library is loaded. Linkers always keep these sections,
+/// so registration does not depend on any symbol being referenced -- plugins from all modules and codegen units of the `cdylib` crate
+/// are collected, without `#[no_mangle]` exports. On Wasm, which has no such section, exported `rust_gdext_registrant_*` functions are
+/// called by the embedder instead.
+///
+/// One limitation is inherent to static linking: object files of a dependency (`rlib`) are only linked if the `cdylib` references
+/// something in them. Classes defined in a separate library crate are thus only registered if that crate is otherwise used.
 
 // Note: code in this file is safe, however it seems that some annotations fall into the "unsafe" category.
 // For example, adding #![forbid(unsafe_code)] causes this error:
@@ -6,7 +6,9 @@
  */
 
 use crate::class::{FieldExport, FieldVar};
-use proc_macro2::{Ident, TokenStream};
+use crate::util;
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::format_ident;
 
 pub struct Field {
     pub name: Ident,
@@ -14,7 +16,26 @@ pub struct Field {
     pub default: Option<TokenStream>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
+    /// Value restored by the editor's "revert" button, from `#[export(default = expr)]`.
+    pub export_default: Option<TokenStream>,
+    /// Position in the inspector, from `#[export(order = N)]`. Declaration index if absent.
+    pub export_order: Option<usize>,
+    /// Inspector tooltip from `#[export(tooltip = "...")]`; takes precedence over the doc comment in the editor help.
+    pub export_tooltip: Option<TokenStream>,
+    /// Whether the property is shown but not editable in the inspector, from `#[export(read_only)]`.
+    pub export_read_only: bool,
+    /// Whether the generated setter emits `Resource`'s `changed` signal; true for `#[export]` fields of classes with a `Base<T>`
+    /// field, unless `#[export(no_emit_changed)]` is given.
+    pub export_emit_changed: bool,
     pub is_onready: bool,
+    /// Whether the field has type `OnEditor<T>`, which is validated before `ready()`.
+    pub is_oneditor: bool,
+    /// Synchronization settings from `#[remote_sync(...)]`.
+    pub remote_sync: Option<FieldRemoteSync>,
+    /// Whether the field is a `#[mixin]`, whose `#[godot_api(mixin)]` methods are registered on the class.
+    pub is_mixin: bool,
+    /// String literals of the field's `///` doc comment, one per line.
+    pub doc_lines: Vec<TokenTree>,
 }
 
 impl Field {
@@ -25,11 +46,35 @@ impl Field {
             default: None,
             var: None,
             export: None,
+            export_default: None,
+            export_order: None,
+            export_tooltip: None,
+            export_read_only: false,
+            export_emit_changed: false,
             is_onready: false,
+            is_oneditor: false,
+            remote_sync: None,
+            is_mixin: false,
+            doc_lines: util::extract_doc_literals(&field.attributes),
         }
     }
 }
 
+impl Field {
+    /// Name of the generated function which registers the methods of a `#[mixin]` field.
+    pub fn mixin_register_fn(&self) -> Ident {
+        format_ident!("__godot_register_mixin_{}", self.name)
+    }
+}
+
+/// Settings of a property synchronized by the class's generated `MultiplayerSynchronizer`.
+pub struct FieldRemoteSync {
+    /// Variant of `scene_replication_config::ReplicationMode`, e.g. `REPLICATION_MODE_ALWAYS`.
+    pub replication_mode: Ident,
+    /// Whether the property is sent when the node is spawned on remote peers.
+    pub is_spawn: bool,
+}
+
 pub struct Fields {
     /// All fields except `base_field`.
     pub all_fields: Vec<Field>,
@@ -37,6 +82,9 @@ pub struct Fields {
     /// The field with type `Base<T>`, if available.
     pub base_field: Option<Field>,
 
+    /// Number of fields in `all_fields` declared before `base_field`, i.e. the base field's position among the non-base ones.
+    pub base_field_index: usize,
+
     /// Whether a deprecated `#[base]` was used.
     pub has_deprecated_base: bool,
 }
@@ -5,12 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use proc_macro2::{Delimiter, Ident, Literal, Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
 use std::collections::HashSet;
 
 use crate::class::FieldHint;
-use crate::util::{KvParser, ListParser};
+use crate::util::{bail, require_api_version, KvParser, KvValue, ListParser};
 use crate::ParseResult;
 
 /// Store info from `#[export]` attribute.
@@ -39,6 +39,8 @@ pub enum FieldExport {
         radians: bool,
         degrees: bool,
         hide_slider: bool,
+        /// Field whose current value replaces `max` in the editor, from `range_max_from = "field"`.
+        max_from: Option<Ident>,
     },
 
     /// ### GDScript annotations
@@ -112,12 +114,68 @@ pub enum FieldExport {
     /// - `PLACEHOLDER_TEXT`
     PlaceholderText { placeholder: TokenStream },
 
+    /// ### GDScript annotations
+    /// None, only available through `@export` with a custom property list.
+    ///
+    /// ### Property hints
+    /// - `PASSWORD`
+    Secret,
+
     /// ### GDScript annotations
     /// - `@export_color_no_alpha`
     ///
     /// ### Property hints
     /// - `COLOR_NO_ALPHA`
     ColorNoAlpha,
+
+    /// ### GDScript annotations
+    /// None, only available through `@export` with a custom property list.
+    ///
+    /// ### Property hints
+    /// - `INT_IS_OBJECTID`
+    /// - `INT_IS_POINTER`
+    IntegerHint { hint: Ident },
+
+    /// ### GDScript annotations
+    /// None; `@export` on a resource field, with additional usage flag.
+    ///
+    /// ### Property hints
+    /// - `RESOURCE_TYPE`, together with usage `EDITOR_INSTANTIATE_OBJECT`
+    NewResource,
+
+    /// ### GDScript annotations
+    /// - `@export` on a typed `Dictionary[K, V]`
+    ///
+    /// ### Property hints
+    /// - `DICTIONARY_TYPE` (Godot 4.4+)
+    TypedDictionary {
+        key_type: String,
+        value_type: String,
+    },
+
+    /// ### GDScript annotations
+    /// - `@export_node_path`
+    ///
+    /// ### Property hints
+    /// - `NODE_PATH_VALID_TYPES`
+    NodePath { types: Vec<Ident> },
+
+    /// ### GDScript annotations
+    /// None; `@export` on a typed `Array[T]`, but keeping the field an untyped `Array`.
+    ///
+    /// ### Property hints
+    /// - `ARRAY_TYPE`
+    ArrayElement { element: TokenStream },
+
+    /// ### GDScript annotations
+    /// None; escape hatch for hints without a dedicated key.
+    ///
+    /// ### Property hints
+    /// - Any, passed through verbatim together with the hint string.
+    CustomHint {
+        hint: Ident,
+        hint_string: Option<TokenStream>,
+    },
 }
 
 impl FieldExport {
@@ -133,11 +191,27 @@ impl FieldExport {
     ///   `#[export(flags/enum = (elem1, elem2 = key2, ...))]`
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         if let Some(list_parser) = parser.handle_list("range")? {
-            return Self::new_range_list(list_parser);
+            let max_from = Self::handle_range_max_from(parser)?;
+            return Self::new_range_list(list_parser, max_from);
         }
 
-        if let Some(list_parser) = parser.handle_list("enum")? {
-            return Self::new_enum_export(list_parser);
+        if let Some(max_from) = Self::handle_range_max_from(parser)? {
+            return Ok(Self::Range {
+                min: quote! { 0.0 },
+                max: quote! { 0.0 },
+                step: quote! { None },
+                or_greater: false,
+                or_less: false,
+                exp: false,
+                radians: false,
+                degrees: false,
+                hide_slider: false,
+                max_from: Some(max_from),
+            });
+        }
+
+        if let Some(value) = parser.handle_any("enum") {
+            return Self::new_enum_from_value(value);
         }
 
         if let Some(list_parser) = parser.handle_list("exp_easing")? {
@@ -248,14 +322,157 @@ impl FieldExport {
             return Ok(Self::PlaceholderText { placeholder });
         }
 
+        if parser.handle_alone("secret")? {
+            return Ok(Self::Secret);
+        }
+
         if parser.handle_alone("color_no_alpha")? {
             return Ok(Self::ColorNoAlpha);
         }
 
+        if let Some(hint) = parser.handle_ident("custom_type_hint")? {
+            return Self::new_custom_hint(hint, parser);
+        }
+
+        if let Some(hint) = parser.handle_ident("hint")? {
+            return Self::new_integer_hint(hint);
+        }
+
+        if parser.handle_alone("new_resource")? {
+            return Ok(Self::NewResource);
+        }
+
+        if let Some(expr) = parser.handle_expr("dict")? {
+            return Self::new_typed_dictionary(expr);
+        }
+
+        if parser.handle_alone("node_path")? {
+            return Self::new_node_path(parser);
+        }
+
+        if let Some(element) = parser.handle_expr("element")? {
+            return Ok(Self::ArrayElement { element });
+        }
+
         Ok(FieldExport::Default)
     }
 
-    fn new_range_list(mut parser: ListParser) -> ParseResult<FieldExport> {
+    /// Parses the optional `types = [Class1, Class2, ...]` following `node_path`.
+    fn new_node_path(parser: &mut KvParser) -> ParseResult<Self> {
+        let mut types = Vec::new();
+
+        if let Some(mut list) = parser.handle_array("types")? {
+            while let Some(ty) = list.next_ident()? {
+                types.push(ty);
+            }
+
+            list.finish()?;
+        }
+
+        Ok(Self::NodePath { types })
+    }
+
+    /// Parses `dict = "Key:Value"`, with Godot type names for key and value, e.g. `"String:int"` or `"int:Node"`.
+    fn new_typed_dictionary(expr: TokenStream) -> ParseResult<Self> {
+        require_api_version!("4.4", &expr, "#[export(dict)]")?;
+
+        let mut tokens = expr.clone().into_iter();
+        let (Some(TokenTree::Literal(literal)), None) = (tokens.next(), tokens.next()) else {
+            return bail!(
+                expr,
+                "expected `dict` to be followed by a string literal of the form \"KeyType:ValueType\""
+            );
+        };
+
+        let types = literal.to_string();
+        let types = types.trim_matches('"');
+        let is_type_name = |name: &str| {
+            name.starts_with(|c: char| c.is_alphabetic() || c == '_')
+                && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        };
+
+        match types.split_once(':') {
+            Some((key_type, value_type)) if is_type_name(key_type) && is_type_name(value_type) => {
+                Ok(Self::TypedDictionary {
+                    key_type: key_type.to_string(),
+                    value_type: value_type.to_string(),
+                })
+            }
+            _ => bail!(
+                literal,
+                "`dict` expects two Godot type names separated by `:`, e.g. \"String:int\""
+            ),
+        }
+    }
+
+    fn new_integer_hint(hint: Ident) -> ParseResult<Self> {
+        const ALLOWED_HINTS: [&str; 2] = ["INT_IS_OBJECTID", "INT_IS_POINTER"];
+
+        if !ALLOWED_HINTS.iter().any(|allowed| hint == *allowed) {
+            return bail!(
+                &hint,
+                "#[export(hint = ...)] only supports the following hints: {}",
+                ALLOWED_HINTS.join(", ")
+            );
+        }
+
+        Ok(Self::IntegerHint { hint })
+    }
+
+    /// Parses `custom_type_hint = HINT, hint_string = expr`. Accepts the hint with or without Godot's `PROPERTY_HINT_` prefix.
+    fn new_custom_hint(hint: Ident, parser: &mut KvParser) -> ParseResult<Self> {
+        let hint_string = parser.handle_expr("hint_string")?;
+
+        let name = hint.to_string();
+        let hint = match name.strip_prefix("PROPERTY_HINT_") {
+            // `Ident::new()` panics on text that is not an identifier.
+            Some(stripped)
+                if stripped.is_empty() || stripped.starts_with(|c: char| c.is_ascii_digit()) =>
+            {
+                return bail!(
+                    &hint,
+                    "expected a hint name after `PROPERTY_HINT_`, e.g. `PROPERTY_HINT_NODE_PATH_VALID_TYPES`"
+                );
+            }
+            Some(stripped) => Ident::new(stripped, hint.span()),
+            None => hint,
+        };
+
+        Ok(Self::CustomHint { hint, hint_string })
+    }
+
+    /// Parses `range_max_from = "field"`, which can be used alone or together with `range = (...)`.
+    fn handle_range_max_from(parser: &mut KvParser) -> ParseResult<Option<Ident>> {
+        let Some(expr) = parser.handle_expr("range_max_from")? else {
+            return Ok(None);
+        };
+
+        require_api_version!("4.2", &expr, "#[export(range_max_from)]")?;
+
+        let mut tokens = expr.clone().into_iter();
+        let (Some(TokenTree::Literal(literal)), None) = (tokens.next(), tokens.next()) else {
+            return bail!(
+                expr,
+                "expected `range_max_from` to be followed by a string literal with a field name"
+            );
+        };
+
+        let field_name = literal.to_string();
+        let field_name = field_name.trim_matches('"');
+        let is_identifier = field_name.starts_with(|c: char| c.is_alphabetic() || c == '_')
+            && field_name.chars().all(|c| c.is_alphanumeric() || c == '_');
+
+        if !is_identifier {
+            return bail!(
+                literal,
+                "`range_max_from` must name a field of the same struct"
+            );
+        }
+
+        Ok(Some(Ident::new(field_name, literal.span())))
+    }
+
+    fn new_range_list(mut parser: ListParser, max_from: Option<Ident>) -> ParseResult<FieldExport> {
         const ALLOWED_OPTIONS: [&str; 6] = [
             "or_greater",
             "or_less",
@@ -296,9 +513,58 @@ impl FieldExport {
             radians: options.contains("radians"),
             degrees: options.contains("degrees"),
             hide_slider: options.contains("hide_slider"),
+            max_from,
         })
     }
 
+    /// Parses `enum = (A, B = 2, ...)` or `enum = ["A", "B", ...]`.
+    fn new_enum_from_value(value: Option<KvValue>) -> ParseResult<Self> {
+        // Key without list: no variants.
+        let Some(value) = value else {
+            return Ok(Self::Enum {
+                variants: Vec::new(),
+            });
+        };
+
+        let tree = value.single()?;
+        match &tree {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Bracket => {
+                Self::new_enum_from_strings(ListParser::new_from_tree(tree, Delimiter::Bracket)?)
+            }
+            _ => Self::new_enum_export(ListParser::new_from_tree(tree, Delimiter::Parenthesis)?),
+        }
+    }
+
+    /// Parses the elements of `enum = ["A", "B", ...]`, which must be string literals. Values are implicit (0, 1, ...).
+    fn new_enum_from_strings(mut parser: ListParser) -> ParseResult<Self> {
+        let mut variants = Vec::new();
+
+        while parser.peek().is_some() {
+            let expr = parser.next_expr()?;
+            let mut tokens = expr.clone().into_iter();
+
+            let literal = match (tokens.next(), tokens.next()) {
+                (Some(TokenTree::Literal(lit)), None) if lit.to_string().starts_with('"') => lit,
+                _ => return bail!(expr, "expected string literal"),
+            };
+
+            // Godot's hint string separates variants with `,` and values with `:`.
+            let text = literal.to_string();
+            if text.contains(',') || text.contains(':') {
+                return bail!(literal, "enum variant must not contain `,` or `:`");
+            }
+
+            variants.push(ValueWithKey {
+                key: literal,
+                value: None,
+            });
+        }
+
+        parser.finish()?;
+
+        Ok(Self::Enum { variants })
+    }
+
     fn new_enum_export(mut parser: ListParser) -> ParseResult<Self> {
         let mut variants = Vec::new();
 
@@ -306,7 +572,7 @@ impl FieldExport {
             let integer = kv.map(|kv| kv.expr()).transpose()?;
 
             variants.push(ValueWithKey {
-                key,
+                key: Literal::string(&key.to_string()),
                 value: integer,
             });
         }
@@ -340,7 +606,7 @@ impl FieldExport {
             let integer = kv.map(|kv| kv.expr()).transpose()?;
 
             bits.push(ValueWithKey {
-                key,
+                key: Literal::string(&key.to_string()),
                 value: integer,
             });
         }
@@ -360,7 +626,7 @@ macro_rules! quote_export_func {
 }
 
 impl FieldExport {
-    pub fn to_field_hint(&self) -> FieldHint {
+    pub fn to_field_hint(&self, field_type: &venial::TyExpr) -> FieldHint {
         match self {
             FieldExport::Default => FieldHint::Inferred,
 
@@ -374,6 +640,7 @@ impl FieldExport {
                 radians,
                 degrees,
                 hide_slider,
+                max_from: _,
             } => quote_export_func! {
                 export_range(#min, #max, #step, #or_greater, #or_less, #exp, #radians, #degrees, #hide_slider)
             },
@@ -456,8 +723,90 @@ impl FieldExport {
                 export_placeholder(#placeholder)
             },
             FieldExport::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
+
+            // Needs the field type, to verify at compile time that the hint is applied to a string field.
+            FieldExport::Secret => FieldHint::HintFromExportFunction(quote! {
+                ::godot::register::property::export_info_functions::export_secret::<#field_type>()
+            }),
+
+            // Needs the field type, to verify at compile time that the hint is applied to an integer field.
+            FieldExport::IntegerHint { hint } => FieldHint::HintFromExportFunction(quote! {
+                ::godot::register::property::export_info_functions::export_integer_hint::<#field_type>(
+                    ::godot::engine::global::PropertyHint::#hint
+                )
+            }),
+
+            // Needs the field type, to verify at compile time that the field holds a resource.
+            FieldExport::NewResource => FieldHint::HintFromExportFunction(quote! {
+                ::godot::register::property::export_info_functions::export_new_resource::<#field_type>()
+            }),
+
+            // Needs the field type, to verify at compile time that the field holds a node path.
+            // Class names are taken from the types, so that renamed classes are respected.
+            FieldExport::NodePath { types } => FieldHint::HintFromExportFunction(quote! {
+                ::godot::register::property::export_info_functions::export_node_path::<#field_type>(&[
+                    #( <#types as ::godot::obj::GodotClass>::class_name(), )*
+                ])
+            }),
+
+            // Needs the field type, to verify at compile time that the field holds an untyped array.
+            // Spanned to the element type, so that types not storable in a Variant are reported there.
+            FieldExport::ArrayElement { element } => {
+                let element_span = element
+                    .clone()
+                    .into_iter()
+                    .next()
+                    .map_or_else(Span::call_site, |token| token.span());
+
+                FieldHint::HintFromExportFunction(quote_spanned! { element_span=>
+                    ::godot::register::property::export_info_functions::export_array_element::<#field_type, #element>()
+                })
+            }
+
+            // Needs the field type, to verify at compile time that the field holds a dictionary.
+            FieldExport::TypedDictionary {
+                key_type,
+                value_type,
+            } => FieldHint::HintFromExportFunction(quote! {
+                ::godot::register::property::export_info_functions::export_dictionary_type::<#field_type>(
+                    #key_type, #value_type
+                )
+            }),
+
+            FieldExport::CustomHint { hint, hint_string } => {
+                FieldHint::new(hint.clone(), hint_string.clone())
+            }
         }
     }
+
+    /// For `#[export(range_max_from = "field")]`, returns an expression computing the range hint from the current value
+    /// of the referenced field (evaluated with `self` in scope).
+    pub fn to_dynamic_range_hint(&self) -> Option<TokenStream> {
+        let FieldExport::Range {
+            min,
+            step,
+            or_greater,
+            or_less,
+            exp,
+            radians,
+            degrees,
+            hide_slider,
+            max_from: Some(max_from),
+            ..
+        } = self
+        else {
+            return None;
+        };
+
+        let max = quote! {
+            ::godot::register::property::export_info_functions::RangeBound::to_range_bound(&self.#max_from)
+        };
+        Some(quote! {
+            ::godot::register::property::export_info_functions::export_range(
+                #min, #max, #step, #or_greater, #or_less, #exp, #radians, #degrees, #hide_slider
+            )
+        })
+    }
 }
 
 /// The dimension of a `@export_flags_{dimension}_{layer}` annotation.
@@ -481,13 +830,13 @@ pub enum FileKind {
 
 /// A `key = value` pair used for enums and bitflags.
 ///
-/// `key` must be an identifier, and `value` some tokenstream that can be coerced into the appropriate
+/// `key` is a string literal, written as an identifier or (for enums) a string literal, and `value` some tokenstream that can be coerced into the appropriate
 /// integer type for the context. For enums that is i64, and for bitflags that is u32.
 ///
 /// `key = value` becomes `key:value` in the hint_string.
 #[derive(Clone)]
 pub struct ValueWithKey {
-    key: Ident,
+    key: Literal,
     value: Option<TokenStream>,
 }
 
@@ -495,7 +844,6 @@ impl ValueWithKey {
     /// Create an expression like `(key, value)` that can be passed to the relevant export info function.
     pub fn to_tuple_expression(&self) -> TokenStream {
         let ValueWithKey { key, value } = self;
-        let key = key.to_string();
 
         match value {
             Some(value) => quote! {
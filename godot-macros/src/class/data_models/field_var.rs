@@ -9,7 +9,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 
 use crate::class::{
-    make_existence_check, make_method_registration, Field, FieldHint, FuncDefinition,
+    make_existence_check, make_method_registration, Field, FieldExport, FieldHint, FuncDefinition,
 };
 use crate::util::KvParser;
 use crate::{util, ParseResult};
@@ -175,11 +175,45 @@ impl GetterSetterImpl {
                 };
             }
             GetSet::Set => {
+                // The editor assigns typed arrays to `#[export(element = E)]` fields, so their setter accepts any array.
+                let (param_type, conversion) = if matches!(
+                    field.export,
+                    Some(FieldExport::ArrayElement { .. })
+                ) {
+                    let property_name = field_name.to_string();
+                    (
+                        quote! { ::godot::builtin::Variant },
+                        quote! {
+                            let #field_name = ::godot::private::untyped_array_from_variant(&#field_name, #property_name);
+                        },
+                    )
+                } else {
+                    (
+                        quote! { <#field_type as ::godot::builtin::meta::GodotConvert>::Via },
+                        TokenStream::new(),
+                    )
+                };
+
                 signature = quote! {
-                    fn #function_name(&mut self, #field_name: <#field_type as ::godot::builtin::meta::GodotConvert>::Via)
+                    fn #function_name(&mut self, #field_name: #param_type)
                 };
+                // Only Resource-derived classes emit `changed`. The check is cheap and lets macros stay unaware of the engine hierarchy.
+                let emit_changed = field.export_emit_changed.then(|| {
+                    quote! {
+                        if <<Self as ::godot::obj::GodotClass>::Base as ::godot::obj::GodotClass>::inherits::<::godot::engine::Resource>() {
+                            use ::godot::obj::WithBaseField as _;
+
+                            // Through base_mut(), so that handlers of the signal may access this object.
+                            self.base_mut()
+                                .upcast_mut::<::godot::engine::Object>()
+                                .emit_signal(::godot::builtin::StringName::from("changed"), &[]);
+                        }
+                    }
+                });
                 function_body = quote! {
+                    #conversion
                     <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
+                    #emit_changed
                 };
             }
         }
@@ -204,6 +238,9 @@ impl GetterSetterImpl {
                 rename: None,
                 is_virtual: false,
                 has_gd_self: false,
+                is_hidden_in_editor: false,
+                is_try_mut: false,
+                type_arg: None,
             },
         );
 
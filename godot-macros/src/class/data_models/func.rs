@@ -8,7 +8,7 @@
 use crate::util::{bail_fn, ident};
 use crate::{util, ParseResult};
 use proc_macro2::{Group, Ident, TokenStream, TokenTree};
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, quote_spanned};
 
 /// Information used for registering a Rust function with Godot.
 pub struct FuncDefinition {
@@ -20,20 +20,29 @@ pub struct FuncDefinition {
     pub rename: Option<String>,
     pub is_virtual: bool,
     pub has_gd_self: bool,
+    /// Whether `#[hide_in_editor]` was used.
+    pub is_hidden_in_editor: bool,
+    /// Whether `#[func(try_mut)]` was used: if the instance is already bound, report an error instead of panicking.
+    pub is_try_mut: bool,
+    /// For instances of generic methods (`#[func(instantiate = [...])]`), the type argument passed to the Rust method.
+    pub type_arg: Option<Ident>,
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
 //
 // There are currently no virtual static methods. Additionally, virtual static methods dont really make a lot
 // of sense. Therefore there is no need to support them.
+//
+// `prelude` runs before the instance is bound, with `storage` in scope; it's used to connect the child tree hooks in `ready()`.
 pub fn make_virtual_callback(
     class_name: &Ident,
     signature_info: SignatureInfo,
     before_kind: BeforeKind,
+    prelude: TokenStream,
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
 
-    let wrapped_method = make_forwarding_closure(class_name, &signature_info, before_kind);
+    let wrapped_method = make_forwarding_closure(class_name, &signature_info, before_kind, prelude);
     let sig_tuple = signature_info.tuple_type();
 
     let call_ctx = make_call_context(
@@ -63,21 +72,94 @@ pub fn make_method_registration(
     class_name: &Ident,
     func_definition: FuncDefinition,
 ) -> ParseResult<TokenStream> {
-    let signature_info = into_signature_info(
+    let mut signature_info = into_signature_info(
         func_definition.signature,
         class_name,
         func_definition.has_gd_self,
     );
-    let sig_tuple = signature_info.tuple_type();
+    if let Some(type_arg) = &func_definition.type_arg {
+        signature_info.turbofish = quote! { ::<#type_arg> };
+    }
+    if func_definition.is_try_mut {
+        if signature_info.receiver_type != ReceiverType::Mut {
+            return bail_fn(
+                "#[func(try_mut)] requires a `&mut self` receiver",
+                signature_info.method_name,
+            );
+        }
+        signature_info.is_try_mut = true;
+    }
 
     let is_virtual = func_definition.is_virtual;
-    let method_flags = match make_method_flags(signature_info.receiver_type, is_virtual) {
+    let method_flags = match make_method_flags(
+        signature_info.receiver_type,
+        is_virtual,
+        func_definition.is_hidden_in_editor,
+    ) {
         Ok(mf) => mf,
         Err(msg) => return bail_fn(msg, signature_info.method_name),
     };
 
-    let forwarding_closure =
-        make_forwarding_closure(class_name, &signature_info, BeforeKind::Without);
+    // Trailing `Option<T>` parameters become optional arguments with `null` default. Those with non-nullable `T`
+    // are transported as `Variant`, since `Option<T>` has no Godot representation for them.
+    let mut default_count = 0;
+    let mut is_trailing = true;
+    for (param, ty) in signature_info
+        .param_idents
+        .iter()
+        .zip(signature_info.param_types.iter_mut())
+        .rev()
+    {
+        // `&mut Gd<T>` parameters are received as `Gd<T>`, which validates the object's class on entry.
+        if let Some(gd_ty) = extract_mut_ref_gd(ty) {
+            signature_info.mut_ref_params.push(param.clone());
+            *ty = gd_ty;
+            is_trailing = false;
+            continue;
+        }
+
+        // `&GString` and `&StringName` parameters are received by value and lent to the method.
+        if let Some(string_ty) = extract_ref_string(ty) {
+            signature_info.ref_params.push(param.clone());
+            *ty = string_ty;
+            is_trailing = false;
+            continue;
+        }
+
+        let Some(inner_ty) = extract_option_inner(ty) else {
+            is_trailing = false;
+            continue;
+        };
+
+        if is_trailing {
+            default_count += 1;
+        }
+
+        if !util::path_ends_with_complex(&inner_ty, "Gd") {
+            signature_info
+                .variant_option_params
+                .push((param.clone(), inner_ty));
+            *ty = venial::TyExpr {
+                tokens: quote! { ::godot::builtin::Variant }.into_iter().collect(),
+            };
+        }
+    }
+
+    // `Result<T, GString>` and `Result<T, String>` returns are registered as `T`; errors are logged in the forwarding closure.
+    if let Some(ok_ty) = extract_result_with_message(&signature_info.ret_type) {
+        signature_info.ret_type = ok_ty;
+        signature_info.has_error_result = true;
+    }
+
+    let sig_tuple = signature_info.tuple_type();
+    let param_count = signature_info.param_types.len();
+
+    let forwarding_closure = make_forwarding_closure(
+        class_name,
+        &signature_info,
+        BeforeKind::Without,
+        TokenStream::new(),
+    );
 
     // String literals
     let method_name = &signature_info.method_name;
@@ -89,8 +171,30 @@ pub fn make_method_registration(
     };
 
     let call_ctx = make_call_context(&class_name_str, &method_name_str);
-    let varcall_func = make_varcall_func(&call_ctx, &sig_tuple, &forwarding_closure);
-    let ptrcall_func = make_ptrcall_func(&call_ctx, &sig_tuple, &forwarding_closure);
+
+    // Zero-argument `&self` getters returning a scalar are called many times (e.g. per frame), so they get a leaner trampoline.
+    let is_scalar_getter = signature_info.receiver_type == ReceiverType::Ref
+        && signature_info.param_types.is_empty()
+        && signature_info.turbofish.is_empty()
+        && !is_virtual
+        && is_scalar_type(&signature_info.ret_type);
+
+    let (varcall_func, ptrcall_func) = if is_scalar_getter {
+        make_getter_funcs(&call_ctx, class_name, &signature_info)
+    } else {
+        let option_arg_checks = make_option_arg_checks(&signature_info);
+        let varcall_func = make_varcall_func(
+            &call_ctx,
+            &sig_tuple,
+            &forwarding_closure,
+            &option_arg_checks,
+            param_count,
+            default_count,
+        );
+        let ptrcall_func = make_ptrcall_func(&call_ctx, &sig_tuple, &forwarding_closure);
+
+        (varcall_func, ptrcall_func)
+    };
 
     // String literals II
     let param_ident_strs = signature_info
@@ -132,7 +236,7 @@ pub fn make_method_registration(
                     &[
                         #( #param_ident_strs ),*
                     ],
-                    Vec::new()
+                    vec![Variant::nil(); #default_count]
                 )
             };
 
@@ -168,6 +272,18 @@ pub struct SignatureInfo {
     pub param_idents: Vec<Ident>,
     pub param_types: Vec<venial::TyExpr>,
     pub ret_type: TokenStream,
+    /// Parameters of type `Option<T>` with non-nullable `T`; transported as `Variant` and converted before the call.
+    pub variant_option_params: Vec<(Ident, venial::TyExpr)>,
+    /// Parameters of type `&mut Gd<T>`; transported as `Gd<T>` and passed by mutable reference.
+    pub mut_ref_params: Vec<Ident>,
+    /// Parameters of type `&GString` or `&StringName`; transported by value and passed by shared reference.
+    pub ref_params: Vec<Ident>,
+    /// Whether the method returns `Result<T, GString>` or `Result<T, String>`, while `ret_type` holds `T`.
+    pub has_error_result: bool,
+    /// Whether an already bound instance is reported as an error, returning `Default::default()`, instead of panicking.
+    pub is_try_mut: bool,
+    /// Turbofish for instances of generic methods, e.g. `::<i64>`; empty otherwise.
+    pub turbofish: TokenStream,
 }
 
 impl SignatureInfo {
@@ -178,6 +294,12 @@ impl SignatureInfo {
             param_idents: vec![],
             param_types: vec![],
             ret_type: quote! { () },
+            variant_option_params: vec![],
+            mut_ref_params: vec![],
+            ref_params: vec![],
+            has_error_result: false,
+            is_try_mut: false,
+            turbofish: TokenStream::new(),
         }
     }
 
@@ -203,16 +325,25 @@ fn make_forwarding_closure(
     class_name: &Ident,
     signature_info: &SignatureInfo,
     before_kind: BeforeKind,
+    prelude: TokenStream,
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
+    let turbofish = &signature_info.turbofish;
     let params = &signature_info.param_idents;
+    let method_name_str = method_name.to_string();
 
     let instance_decl = match &signature_info.receiver_type {
         ReceiverType::Ref => quote! {
             let instance = ::godot::private::Storage::get(storage);
         },
+        // Binding failures name the method, since re-entrant calls are otherwise hard to trace.
+        ReceiverType::Mut if signature_info.is_try_mut => quote! {
+            let Some(mut instance) = ::godot::private::try_bind_mut_for_call(storage, #method_name_str) else {
+                return ::std::default::Default::default();
+            };
+        },
         ReceiverType::Mut => quote! {
-            let mut instance = ::godot::private::Storage::get_mut(storage);
+            let mut instance = ::godot::private::bind_mut_for_call(storage, #method_name_str);
         },
         _ => quote! {},
     };
@@ -225,6 +356,46 @@ fn make_forwarding_closure(
         BeforeKind::Without => TokenStream::new(),
     };
 
+    // Convert `Variant` parameters back to the user-declared `Option<T>`; null maps to `None`.
+    let option_conversions =
+        signature_info
+            .variant_option_params
+            .iter()
+            .map(|(param, inner_ty)| {
+                // Varcalls are already checked in `check_option_arg()`; ptrcalls have no way to report an error.
+                let param_str = param.to_string();
+                quote! {
+                    let #param: Option<#inner_ty> = if #param.is_nil() {
+                        None
+                    } else {
+                        match #param.try_to::<#inner_ty>() {
+                            Ok(value) => Some(value),
+                            Err(err) => panic!("parameter `{}` of type Option<{}>: {err}", #param_str, stringify!(#inner_ty)),
+                        }
+                    };
+                }
+            });
+    let mut_ref_params = &signature_info.mut_ref_params;
+    let params_decl = quote! {
+        let ( #(#params,)* ) = params;
+        #( #option_conversions )*
+        #( let mut #mut_ref_params = #mut_ref_params; )*
+    };
+
+    // Arguments of the user method call; `&mut Gd<T>`, `&GString` and `&StringName` parameters are borrowed from their local binding.
+    let call_args: Vec<TokenStream> = params
+        .iter()
+        .map(|param| {
+            if mut_ref_params.contains(param) {
+                quote! { &mut #param }
+            } else if signature_info.ref_params.contains(param) {
+                quote! { &#param }
+            } else {
+                quote! { #param }
+            }
+        })
+        .collect();
+
     match signature_info.receiver_type {
         ReceiverType::Ref | ReceiverType::Mut => {
             // Generated default virtual methods (e.g. for ready) may not have an actual implementation (user code), so
@@ -232,16 +403,20 @@ fn make_forwarding_closure(
             let method_call = if matches!(before_kind, BeforeKind::OnlyBefore) {
                 TokenStream::new()
             } else {
-                quote! { instance.#method_name(#(#params),*) }
+                wrap_error_result(
+                    signature_info,
+                    quote! { instance.#method_name #turbofish (#(#call_args),*) },
+                )
             };
 
             quote! {
                 |instance_ptr, params| {
-                    let ( #(#params,)* ) = params;
+                    #params_decl
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
+                    #prelude
                     #instance_decl
                     #before_method_call
                     #method_call
@@ -251,30 +426,62 @@ fn make_forwarding_closure(
         ReceiverType::GdSelf => {
             // Method call is always present, since GdSelf implies that the user declares the method.
             // (Absent method is only used in the case of a generated default virtual method, e.g. for ready()).
+            let method_call = wrap_error_result(
+                signature_info,
+                quote! { <#class_name>::#method_name #turbofish (::godot::private::Storage::get_gd(storage), #(#call_args),*) },
+            );
+
             quote! {
                 |instance_ptr, params| {
-                    let ( #(#params,)* ) = params;
+                    #params_decl
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
                     #before_method_call
-                    <#class_name>::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+                    #method_call
                 }
             }
         }
         ReceiverType::Static => {
             // No before-call needed, since static methods are not virtual.
+            let method_call = wrap_error_result(
+                signature_info,
+                quote! { <#class_name>::#method_name #turbofish (#(#call_args),*) },
+            );
+
             quote! {
                 |_, params| {
-                    let ( #(#params,)* ) = params;
-                    <#class_name>::#method_name(#(#params),*)
+                    #params_decl
+                    #method_call
                 }
             }
         }
     }
 }
 
+/// For methods returning `Result<T, GString>` or `Result<T, String>`, logs the error message and returns `T::default()` instead.
+fn wrap_error_result(signature_info: &SignatureInfo, method_call: TokenStream) -> TokenStream {
+    if signature_info.has_error_result {
+        // Spanned to the `Ok` type, so that a missing `Default` impl is reported at the return type rather than the attribute.
+        let ok_type = &signature_info.ret_type;
+        let span = ok_type
+            .clone()
+            .into_iter()
+            .next()
+            .map_or_else(proc_macro2::Span::call_site, |token| token.span());
+
+        quote_spanned! { span=>
+            {
+                ::godot::private::is_func_result_ok_type::<#ok_type>();
+                ::godot::private::unwrap_func_result(#method_call)
+            }
+        }
+    } else {
+        method_call
+    }
+}
+
 /// Maps each usage of `Self` to the struct it's referencing,
 /// since `Self` can't be used inside nested functions.
 fn map_self_to_class_name<In, Out>(tokens: In, class_name: &Ident) -> Out
@@ -360,22 +567,36 @@ pub(crate) fn into_signature_info(
         param_idents,
         param_types,
         ret_type,
+        variant_option_params: vec![],
+        mut_ref_params: vec![],
+        ref_params: vec![],
+        has_error_result: false,
+        is_try_mut: false,
+        turbofish: TokenStream::new(),
     }
 }
 
 fn make_method_flags(
     method_type: ReceiverType,
     is_rust_virtual: bool,
+    is_hidden_in_editor: bool,
 ) -> Result<TokenStream, String> {
     let scope = quote! { ::godot::engine::global::MethodFlags };
 
+    // Methods hidden from the editor are registered without NORMAL (Godot never sets EDITOR for extension methods).
+    let normal = if is_hidden_in_editor {
+        quote! { <#scope as ::godot::obj::EngineBitfield>::from_ord(0) }
+    } else {
+        quote! { #scope::NORMAL }
+    };
+
     let base_flags = match method_type {
         ReceiverType::Ref => {
-            quote! { #scope::NORMAL | #scope::CONST }
+            quote! { #normal | #scope::CONST }
         }
         // Conservatively assume Gd<Self> receivers to mutate the object, since user can call bind_mut().
         ReceiverType::Mut | ReceiverType::GdSelf => {
-            quote! { #scope::NORMAL }
+            quote! { #normal }
         }
         ReceiverType::Static => {
             if is_rust_virtual {
@@ -394,13 +615,151 @@ fn make_method_flags(
     Ok(flags)
 }
 
+/// If `ty` is `&mut Gd<T>`, returns `Gd<T>`.
+fn extract_mut_ref_gd(ty: &venial::TyExpr) -> Option<venial::TyExpr> {
+    let [TokenTree::Punct(ampersand), TokenTree::Ident(mutability), referenced @ ..] =
+        ty.tokens.as_slice()
+    else {
+        return None;
+    };
+
+    if ampersand.as_char() != '&' || mutability != "mut" {
+        return None;
+    }
+
+    let referenced = venial::TyExpr {
+        tokens: referenced.to_vec(),
+    };
+    util::path_ends_with_complex(&referenced, "Gd").then_some(referenced)
+}
+
+/// If `ty` is `&GString` or `&StringName`, returns the referenced type.
+fn extract_ref_string(ty: &venial::TyExpr) -> Option<venial::TyExpr> {
+    let [TokenTree::Punct(ampersand), referenced @ ..] = ty.tokens.as_slice() else {
+        return None;
+    };
+
+    let is_mut = matches!(referenced.first(), Some(TokenTree::Ident(ident)) if ident == "mut");
+    if ampersand.as_char() != '&' || is_mut {
+        return None;
+    }
+
+    let referenced = venial::TyExpr {
+        tokens: referenced.to_vec(),
+    };
+    let is_string = util::path_ends_with_complex(&referenced, "GString")
+        || util::path_ends_with_complex(&referenced, "StringName");
+
+    is_string.then_some(referenced)
+}
+
+/// If `ret_type` is `Result<T, GString>` or `Result<T, String>`, returns `T`.
+fn extract_result_with_message(ret_type: &TokenStream) -> Option<TokenStream> {
+    let tokens: Vec<TokenTree> = ret_type.clone().into_iter().collect();
+    let result_pos = tokens
+        .iter()
+        .position(|tt| matches!(tt, TokenTree::Ident(ident) if ident == "Result"))?;
+
+    // Only accept plain paths such as `Result<T, E>` or `std::result::Result<T, E>`.
+    let is_path_prefix = tokens[..result_pos].iter().all(|tt| match tt {
+        TokenTree::Ident(_) => true,
+        TokenTree::Punct(punct) => punct.as_char() == ':',
+        _ => false,
+    });
+    let is_generic = matches!(tokens.get(result_pos + 1), Some(TokenTree::Punct(punct)) if punct.as_char() == '<')
+        && matches!(tokens.last(), Some(TokenTree::Punct(punct)) if punct.as_char() == '>');
+
+    if !is_path_prefix || !is_generic || tokens.len() < result_pos + 6 {
+        return None;
+    }
+
+    // Split the generic arguments at the top-level comma, skipping commas of nested generics such as `Result<Array<i64>, GString>`.
+    let generic_args = &tokens[result_pos + 2..tokens.len() - 1];
+    let mut depth = 0;
+    let comma_pos = generic_args.iter().position(|tt| {
+        if let TokenTree::Punct(punct) = tt {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                ',' if depth == 0 => return true,
+                _ => {}
+            }
+        }
+        false
+    })?;
+
+    let (ok_ty, err_ty) = (&generic_args[..comma_pos], &generic_args[comma_pos + 1..]);
+    let is_message = matches!(err_ty.last(), Some(TokenTree::Ident(ident)) if ident == "GString" || ident == "String");
+
+    (is_message && !ok_ty.is_empty()).then(|| ok_ty.iter().cloned().collect())
+}
+
+/// If `ty` is `Option<T>`, returns `T`.
+fn extract_option_inner(ty: &venial::TyExpr) -> Option<venial::TyExpr> {
+    let tokens = &ty.tokens;
+    let option_pos = tokens
+        .iter()
+        .position(|tt| matches!(tt, TokenTree::Ident(ident) if ident == "Option"))?;
+
+    // Only accept plain paths such as `Option<T>` or `std::option::Option<T>`.
+    let is_path_prefix = tokens[..option_pos].iter().all(|tt| match tt {
+        TokenTree::Ident(_) => true,
+        TokenTree::Punct(punct) => punct.as_char() == ':',
+        _ => false,
+    });
+    let is_generic = matches!(tokens.get(option_pos + 1), Some(TokenTree::Punct(punct)) if punct.as_char() == '<')
+        && matches!(tokens.last(), Some(TokenTree::Punct(punct)) if punct.as_char() == '>');
+
+    if !is_path_prefix || !is_generic || tokens.len() < option_pos + 4 {
+        return None;
+    }
+
+    Some(venial::TyExpr {
+        tokens: tokens[option_pos + 2..tokens.len() - 1].to_vec(),
+    })
+}
+
 /// Generate code for a C FFI function that performs a varcall.
+///
+/// If `default_count` is non-zero, calls omitting up to that many trailing arguments are padded with `null`.
 fn make_varcall_func(
     call_ctx: &TokenStream,
     sig_tuple: &TokenStream,
     wrapped_method: &TokenStream,
+    option_arg_checks: &TokenStream,
+    param_count: usize,
+    default_count: usize,
 ) -> TokenStream {
-    let invocation = make_varcall_invocation(call_ctx, sig_tuple, wrapped_method);
+    let call = make_varcall_invocation(call_ctx, sig_tuple, wrapped_method);
+    let invocation = if default_count == 0 {
+        call
+    } else {
+        let required_count = param_count - default_count;
+        quote! {
+            {
+                // Godot does not fill in default arguments for extension methods; this must happen here.
+                let nil = ::godot::builtin::Variant::nil();
+                let padded_args: Vec<sys::GDExtensionConstVariantPtr>;
+                let (args_ptr, arg_count) = if (#required_count..#param_count).contains(&(arg_count as usize)) {
+                    padded_args = (0..#param_count)
+                        .map(|i| {
+                            if i < arg_count as usize {
+                                *args_ptr.add(i)
+                            } else {
+                                nil.var_sys_const()
+                            }
+                        })
+                        .collect();
+
+                    (padded_args.as_ptr(), #param_count as sys::GDExtensionInt)
+                } else {
+                    (args_ptr, arg_count)
+                };
+
+                #call
+            }
+        }
+    };
 
     quote! {
         {
@@ -412,6 +771,8 @@ fn make_varcall_func(
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
             ) {
+                #option_arg_checks
+
                 let success = ::godot::private::handle_panic(
                     || #call_ctx,
                     || #invocation
@@ -432,6 +793,35 @@ fn make_varcall_func(
     }
 }
 
+/// Generates an early return from a varcall, if an `Option<T>` argument transported as `Variant` is neither null nor a `T`.
+fn make_option_arg_checks(signature_info: &SignatureInfo) -> TokenStream {
+    let checks = signature_info
+        .variant_option_params
+        .iter()
+        .map(|(param, inner_ty)| {
+            let index = signature_info
+                .param_idents
+                .iter()
+                .position(|ident| ident == param)
+                .expect("Option parameter is part of the signature");
+
+            quote! {
+                ::godot::private::check_option_arg::<#inner_ty>(args_ptr, arg_count, #index, err)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if checks.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        if !( #( #checks )&&* ) {
+            return;
+        }
+    }
+}
+
 /// Generate code for a C FFI function that performs a ptrcall.
 fn make_ptrcall_func(
     call_ctx: &TokenStream,
@@ -463,6 +853,77 @@ fn make_ptrcall_func(
     }
 }
 
+/// Generates the varcall and ptrcall C functions for a zero-argument `&self` method returning a scalar.
+fn make_getter_funcs(
+    call_ctx: &TokenStream,
+    class_name: &Ident,
+    signature_info: &SignatureInfo,
+) -> (TokenStream, TokenStream) {
+    let method_name = &signature_info.method_name;
+    let ret_type = &signature_info.ret_type;
+    let getter_call = wrap_error_result(signature_info, quote! { instance.#method_name() });
+    let getter = quote! { |instance: &#class_name| #getter_call };
+
+    let varcall_func = quote! {
+        {
+            unsafe extern "C" fn function(
+                _method_data: *mut std::ffi::c_void,
+                instance_ptr: sys::GDExtensionClassInstancePtr,
+                _args_ptr: *const sys::GDExtensionConstVariantPtr,
+                arg_count: sys::GDExtensionInt,
+                ret: sys::GDExtensionVariantPtr,
+                err: *mut sys::GDExtensionCallError,
+            ) {
+                ::godot::builtin::meta::in_getter_varcall::<#class_name, #ret_type>(
+                    instance_ptr,
+                    & #call_ctx,
+                    arg_count,
+                    ret,
+                    err,
+                    #getter,
+                );
+            }
+
+            function
+        }
+    };
+
+    let ptrcall_func = quote! {
+        {
+            unsafe extern "C" fn function(
+                _method_data: *mut std::ffi::c_void,
+                instance_ptr: sys::GDExtensionClassInstancePtr,
+                _args_ptr: *const sys::GDExtensionConstTypePtr,
+                ret: sys::GDExtensionTypePtr,
+            ) {
+                ::godot::builtin::meta::in_getter_ptrcall::<#class_name, #ret_type>(
+                    instance_ptr,
+                    & #call_ctx,
+                    ret,
+                    #getter,
+                );
+            }
+
+            function
+        }
+    };
+
+    (varcall_func, ptrcall_func)
+}
+
+/// Whether `ty` is a primitive `bool`, integer or float type.
+fn is_scalar_type(ty: &TokenStream) -> bool {
+    const SCALARS: &[&str] = &[
+        "bool", "i8", "i16", "i32", "i64", "u8", "u16", "u32", "f32", "f64",
+    ];
+
+    let mut tokens = ty.clone().into_iter();
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Ident(ident)), None) => SCALARS.contains(&ident.to_string().as_str()),
+        _ => false,
+    }
+}
+
 /// Generate code for a `ptrcall` call expression.
 fn make_ptrcall_invocation(
     call_ctx: &TokenStream,
@@ -7,10 +7,10 @@
 
 //! Parsing the `var` and `export` attributes on fields.
 
-use crate::class::{Field, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
+use crate::class::{Field, FieldExport, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
 use crate::util;
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote, quote_spanned};
 
 #[derive(Default, Clone, Debug)]
 pub enum FieldHint {
@@ -40,13 +40,32 @@ impl FieldHint {
     }
 }
 
-pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
+pub fn make_property_impl(
+    class_name: &Ident,
+    fields: &Fields,
+    is_introspect: bool,
+    is_typed_accessors: bool,
+    is_export_dispatch: bool,
+) -> TokenStream {
     let class_name_obj = util::class_name_obj(class_name);
 
     let mut getter_setter_impls = Vec::new();
+    let mut typed_accessor_impls = Vec::new();
+    let mut exported_fields = Vec::new();
     let mut export_tokens = Vec::new();
+    let mut exported_property_infos = Vec::new();
 
-    for field in &fields.all_fields {
+    // Properties appear in the inspector in registration order. Fields without `order` keep their index among the registered
+    // properties, so unregistered fields don't shift them. Sorting is stable, so ties keep declaration order.
+    let mut ordered_fields: Vec<(usize, &Field)> = fields
+        .all_fields
+        .iter()
+        .filter(|field| field.var.is_some() || field.export.is_some())
+        .enumerate()
+        .collect();
+    ordered_fields.sort_by_key(|(index, field)| field.export_order.unwrap_or(*index));
+
+    for (_, field) in ordered_fields {
         let Field {
             name: field_ident,
             ty: field_type,
@@ -81,7 +100,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         } = var;
 
         if let Some(export) = export {
-            hint = export.to_field_hint();
+            hint = export.to_field_hint(field_type);
 
             if usage_flags.is_inferred() {
                 usage_flags = UsageFlags::InferredExport;
@@ -93,7 +112,14 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
                 quote! { ::godot::engine::global::PropertyUsageFlags::NONE }
             }
             UsageFlags::InferredExport => {
-                quote! { ::godot::engine::global::PropertyUsageFlags::DEFAULT }
+                if matches!(export, Some(FieldExport::NewResource)) {
+                    quote! {
+                        ::godot::engine::global::PropertyUsageFlags::DEFAULT
+                            | ::godot::engine::global::PropertyUsageFlags::EDITOR_INSTANTIATE_OBJECT
+                    }
+                } else {
+                    quote! { ::godot::engine::global::PropertyUsageFlags::DEFAULT }
+                }
             }
             UsageFlags::Custom(flags) => quote! {
                 #(
@@ -102,17 +128,23 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             },
         };
 
+        // Spanned to the field type, so that a missing Var/Export impl (e.g. for enums with data variants) is reported at the field.
+        let field_type_span = field_type
+            .tokens
+            .first()
+            .map_or(field_ident.span(), |token| token.span());
+
         let hint = match hint {
             FieldHint::Inferred => {
                 if export.is_some() {
-                    quote! {
+                    quote_spanned! { field_type_span=>
                         {
                             let default_export_info = <#field_type as ::godot::register::property::Export>::default_export_info();
                             (default_export_info.hint, default_export_info.hint_string)
                         }
                     }
                 } else {
-                    quote! {
+                    quote_spanned! { field_type_span=>
                         {
                             let default_export_info = <#field_type as ::godot::register::property::Var>::property_hint();
                             (default_export_info.hint, default_export_info.hint_string)
@@ -140,6 +172,30 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             },
         };
 
+        let property_info = quote! {
+            {
+                let (hint, hint_string) = #hint;
+                let usage = #usage_flags;
+
+                ::godot::builtin::meta::PropertyInfo {
+                    variant_type: #field_variant_type,
+                    class_name: #field_class_name,
+                    property_name: #field_name.into(),
+                    hint,
+                    hint_string,
+                    usage,
+                }
+            }
+        };
+
+        if export.is_some() {
+            if is_typed_accessors {
+                typed_accessor_impls.push(make_typed_accessors(field_ident, field_type));
+            }
+            exported_fields.push((field_ident, field_type));
+            exported_property_infos.push(property_info.clone());
+        }
+
         let getter_name = make_getter_setter(
             getter.to_impl(class_name, GetSet::Get, field),
             &mut getter_setter_impls,
@@ -154,17 +210,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         export_tokens.push(quote! {
             use ::godot::sys::GodotFfi;
 
-            let (hint, hint_string) = #hint;
-            let usage = #usage_flags;
-
-            let property_info = ::godot::builtin::meta::PropertyInfo {
-                variant_type: #field_variant_type,
-                class_name: #field_class_name,
-                property_name: #field_name.into(),
-                hint,
-                hint_string,
-                usage,
-            };
+            let property_info = #property_info;
 
             let getter_name = ::godot::builtin::StringName::from(#getter_name);
             let setter_name = ::godot::builtin::StringName::from(#setter_name);
@@ -183,9 +229,30 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         });
     }
 
+    // #[class(export_dispatch)]
+    let exported_dispatch_impl =
+        is_export_dispatch.then(|| make_exported_dispatch(&exported_fields));
+    let shadowed_exports_marker = make_shadowed_exports_marker(!exported_fields.is_empty());
+
+    // #[class(introspect)]
+    let introspection_impl = is_introspect.then(|| {
+        quote! {
+            /// Returns name, type, hint and usage of all `#[export]` properties, in registration order.
+            ///
+            /// This is the metadata registered at startup; adjustments made by `validate_property()` are not included.
+            pub fn exported_property_infos() -> ::std::vec::Vec<::godot::builtin::meta::PropertyInfo> {
+                ::std::vec![ #( #exported_property_infos ),* ]
+            }
+        }
+    });
+
     quote! {
         impl #class_name {
             #(#getter_setter_impls)*
+            #(#typed_accessor_impls)*
+            #exported_dispatch_impl
+            #shadowed_exports_marker
+            #introspection_impl
         }
 
         impl ::godot::obj::cap::ImplementsGodotExports for #class_name {
@@ -220,3 +287,113 @@ fn make_getter_setter(
         String::new()
     }
 }
+
+/// Generates `property_get_<field>()` and `property_set_<field>()` for `#[class(typed_accessors)]`, which access an exported property through Godot's
+/// dynamic `Object::get()`/`Object::set()`, but with static types.
+fn make_typed_accessors(field_ident: &Ident, field_type: &venial::TyExpr) -> TokenStream {
+    let field_name = field_ident.to_string();
+    let getter = format_ident!("property_get_{}", field_ident);
+    let setter = format_ident!("property_set_{}", field_ident);
+
+    let get_doc = format!(
+        "Reads the `{field_name}` property through Godot's property system, without binding the object."
+    );
+    let set_doc = format!(
+        "Writes the `{field_name}` property through Godot's property system, without binding the object."
+    );
+
+    quote! {
+        #[doc = #get_doc]
+        pub fn #getter(
+            gd: &::godot::obj::Gd<Self>,
+        ) -> <#field_type as ::godot::builtin::meta::GodotConvert>::Via {
+            let value = gd
+                .upcast_ref::<::godot::engine::Object>()
+                .get(::godot::builtin::StringName::from(#field_name));
+
+            ::godot::builtin::meta::FromGodot::from_variant(&value)
+        }
+
+        #[doc = #set_doc]
+        pub fn #setter(
+            gd: &mut ::godot::obj::Gd<Self>,
+            value: <#field_type as ::godot::builtin::meta::GodotConvert>::Via,
+        ) {
+            gd.upcast_mut::<::godot::engine::Object>().set(
+                ::godot::builtin::StringName::from(#field_name),
+                ::godot::builtin::meta::ToGodot::to_variant(&value),
+            );
+        }
+    }
+}
+
+/// Generates a marker constant that `#[godot_api]` references for `get_property()`/`set_property()` overrides which don't forward
+/// to the exported property dispatch. It is deprecated if there are exported fields, so that such overrides cause a warning.
+fn make_shadowed_exports_marker(has_exported_fields: bool) -> TokenStream {
+    let deprecation = has_exported_fields.then(|| {
+        quote! {
+            #[deprecated = "get_property() or set_property() is called before #[export] fields are accessed, and may shadow them.\n\
+                Add #[class(export_dispatch)] and forward unknown properties to get_exported_property() and set_exported_property(), respectively."]
+        }
+    });
+
+    quote! {
+        #[doc(hidden)]
+        #deprecation
+        pub const __GODOT_SHADOWED_EXPORTS: () = ();
+    }
+}
+
+/// Generates `get_exported_property()` and `set_exported_property()` for `#[class(export_dispatch)]`, which access `#[export]`
+/// fields by name, e.g. to forward unknown properties from `get_property()`/`set_property()` overrides.
+fn make_exported_dispatch(exported_fields: &[(&Ident, &venial::TyExpr)]) -> TokenStream {
+    let field_names = exported_fields
+        .iter()
+        .map(|(field_ident, _)| field_ident.to_string())
+        .collect::<Vec<_>>();
+    let field_idents = exported_fields.iter().map(|(field_ident, _)| field_ident);
+    let field_idents_set = field_idents.clone();
+    let field_types = exported_fields.iter().map(|(_, field_type)| field_type);
+
+    quote! {
+        /// Returns the value of the `#[export]` field named `property`, or `None` if there is no such field.
+        ///
+        /// Reads the field directly and does not invoke custom getters.
+        pub fn get_exported_property(
+            &self,
+            property: &::godot::builtin::StringName,
+        ) -> Option<::godot::builtin::Variant> {
+            match property.to_string().as_str() {
+                #(
+                    #field_names => Some(::godot::builtin::meta::ToGodot::to_variant(
+                        &::godot::register::property::Var::get_property(&self.#field_idents),
+                    )),
+                )*
+                _ => None,
+            }
+        }
+
+        /// Assigns `value` to the `#[export]` field named `property`.
+        ///
+        /// Returns `false` if there is no such field, or if `value` cannot be converted to the field's type. Writes the field
+        /// directly and does not invoke custom setters.
+        pub fn set_exported_property(
+            &mut self,
+            property: &::godot::builtin::StringName,
+            value: &::godot::builtin::Variant,
+        ) -> bool {
+            match property.to_string().as_str() {
+                #(
+                    #field_names => match value.try_to::<<#field_types as ::godot::builtin::meta::GodotConvert>::Via>() {
+                        Ok(value) => {
+                            ::godot::register::property::Var::set_property(&mut self.#field_idents_set, value);
+                            true
+                        }
+                        Err(_) => false,
+                    },
+                )*
+                _ => false,
+            }
+        }
+    }
+}
@@ -5,42 +5,61 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, Punct, TokenStream};
-use quote::{format_ident, quote};
+use proc_macro2::{Ident, Punct, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned};
 use venial::{Declaration, NamedField, Struct, StructFields};
 
 use crate::class::{
-    make_property_impl, make_virtual_callback, BeforeKind, Field, FieldExport, FieldVar, Fields,
-    SignatureInfo,
+    derive_state_machine, make_property_impl, make_virtual_callback, BeforeKind, Field,
+    FieldExport, FieldRemoteSync, FieldVar, Fields, SignatureInfo,
 };
 use crate::util::{bail, ident, path_ends_with_complex, require_api_version, KvParser};
 use crate::{util, ParseResult};
 
 pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
+    if let Some(enum_) = decl.as_enum() {
+        return derive_state_machine(enum_);
+    }
+
     let class = decl
         .as_struct()
         .ok_or_else(|| venial::Error::new("Not a valid struct"))?;
 
     let struct_cfg = parse_struct_attributes(class)?;
-    let fields = parse_fields(class, struct_cfg.init_strategy)?;
+    let fields = parse_fields(class, struct_cfg.init_strategy, struct_cfg.is_export_all)?;
 
     let class_name = &class.name;
-    let class_name_str: String = struct_cfg
-        .rename
-        .map_or_else(|| class.name.clone(), |rename| rename)
-        .to_string();
-    let class_name_cstr = util::cstr_u8_slice(&class_name_str);
-    let class_name_obj = util::class_name_obj(class_name);
+    let godot_name = struct_cfg.rename.unwrap_or_else(|| class.name.clone());
+    let class_name_str = godot_name.to_string();
+    if util::is_engine_class_name(&class_name_str) {
+        return bail!(
+            godot_name,
+            "'{class_name_str}' is a built-in Godot class name; rename your struct (or use #[class(rename = ...)]) to avoid conflicts"
+        );
+    }
 
-    let is_editor_plugin = struct_cfg.is_editor_plugin;
-    let is_hidden = struct_cfg.is_hidden;
     let base_ty = &struct_cfg.base_ty;
-    let base_class = quote! { ::godot::engine::#base_ty };
-    let base_class_name_obj = util::class_name_obj(&base_class);
-    let inherits_macro = format_ident!("unsafe_inherits_transitive_{}", base_ty);
 
     let prv = quote! { ::godot::private };
-    let godot_exports_impl = make_property_impl(class_name, &fields);
+    let godot_exports_impl = make_property_impl(
+        class_name,
+        &fields,
+        struct_cfg.is_introspect,
+        struct_cfg.is_typed_accessors,
+        struct_cfg.is_export_dispatch,
+    );
+    let (mixin_impls, register_mixin_methods_fn) = make_mixin_impls(class_name, &fields.all_fields);
+    let (validate_property_impl, validate_property_fn) =
+        make_validate_property_impl(class_name, &fields.all_fields);
+    let (property_revert_impl, property_can_revert_fn, property_get_revert_fn) =
+        make_property_revert_impl(class_name, &fields.all_fields)?;
+    let docs = make_struct_docs(class, &fields.all_fields);
+    let (storage_hooks_impl, generated_get_fn, generated_set_fn) = make_storage_hooks_impl(
+        class_name,
+        struct_cfg.before_save_fn.as_ref(),
+        struct_cfg.after_load_fn.as_ref(),
+        &fields.all_fields,
+    );
 
     let godot_withbase_impl = if let Some(Field { name, .. }) = &fields.base_field {
         quote! {
@@ -64,8 +83,39 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
         TokenStream::new()
     };
 
-    let (user_class_impl, has_default_virtual) =
-        make_user_class_impl(class_name, struct_cfg.is_tool, &fields.all_fields);
+    let queue_free_safe_impl = if let Some(attr_key) = &struct_cfg.queue_free_safe {
+        make_queue_free_safe_impl(class_name, base_ty, attr_key, &fields)?
+    } else {
+        TokenStream::new()
+    };
+
+    let scene_impl = if let Some(path) = &struct_cfg.scene {
+        make_scene_impl(class_name, path)
+    } else {
+        TokenStream::new()
+    };
+
+    let (singleton_impl, singleton_name) = if let Some(name) = &struct_cfg.singleton {
+        (
+            make_singleton_impl(class_name, name),
+            quote! { Some(#name) },
+        )
+    } else {
+        (TokenStream::new(), quote! { None })
+    };
+
+    let (user_class_impl, has_default_virtual) = make_user_class_impl(
+        class_name,
+        struct_cfg.is_tool,
+        &fields.all_fields,
+        fields.base_field.as_ref(),
+    );
+
+    let reset_impl = if struct_cfg.is_resettable {
+        make_reset_impl(class_name, &fields, struct_cfg.is_init_from_default)
+    } else {
+        TokenStream::new()
+    };
 
     let mut init_expecter = TokenStream::new();
     let mut godot_init_impl = TokenStream::new();
@@ -75,7 +125,12 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
 
     match struct_cfg.init_strategy {
         InitStrategy::Generated => {
-            godot_init_impl = make_godot_init_impl(class_name, fields);
+            godot_init_impl = make_godot_init_impl(
+                class_name,
+                fields,
+                struct_cfg.after_init,
+                struct_cfg.is_init_from_default,
+            );
             create_fn = quote! { Some(#prv::callbacks::create::<#class_name>) };
 
             if cfg!(since_api = "4.2") {
@@ -104,9 +159,119 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
         quote! { None }
     };
 
-    let is_tool = struct_cfg.is_tool;
+    let class_definition = make_class_definition(ClassDefinition {
+        class_name,
+        class_name_str: &class_name_str,
+        base_ty,
+        is_tool: struct_cfg.is_tool,
+        is_editor_plugin: struct_cfg.is_editor_plugin,
+        is_hidden: struct_cfg.is_hidden,
+        is_instantiable,
+        create_fn,
+        recreate_fn,
+        register_mixin_methods_fn,
+        validate_property_fn,
+        property_can_revert_fn,
+        property_get_revert_fn,
+        default_get_virtual_fn,
+        generated_get_fn,
+        generated_set_fn,
+        get_property_list_fn: quote! { None },
+        free_property_list_fn: quote! { None },
+        singleton_name,
+        docs,
+    });
 
     Ok(quote! {
+        #class_definition
+        #godot_init_impl
+        #godot_withbase_impl
+        #godot_exports_impl
+        #mixin_impls
+        #validate_property_impl
+        #property_revert_impl
+        #storage_hooks_impl
+        #queue_free_safe_impl
+        #reset_impl
+        #scene_impl
+        #singleton_impl
+        #user_class_impl
+        #init_expecter
+        #deprecated_base_warning
+    })
+}
+
+/// Registration data of a user class, filled in by the struct and the enum derives.
+pub(crate) struct ClassDefinition<'a> {
+    pub class_name: &'a Ident,
+    /// Name under which the class is registered with Godot, i.e. after `#[class(rename)]`.
+    pub class_name_str: &'a str,
+    pub base_ty: &'a Ident,
+    pub is_tool: bool,
+    pub is_editor_plugin: bool,
+    pub is_hidden: bool,
+    pub is_instantiable: bool,
+    /// Expressions for the corresponding `PluginItem::Struct` fields.
+    pub create_fn: TokenStream,
+    pub recreate_fn: TokenStream,
+    pub register_mixin_methods_fn: TokenStream,
+    pub validate_property_fn: TokenStream,
+    pub property_can_revert_fn: TokenStream,
+    pub property_get_revert_fn: TokenStream,
+    pub default_get_virtual_fn: TokenStream,
+    pub generated_get_fn: TokenStream,
+    pub generated_set_fn: TokenStream,
+    pub get_property_list_fn: TokenStream,
+    pub free_property_list_fn: TokenStream,
+    pub singleton_name: TokenStream,
+    pub docs: TokenStream,
+}
+
+/// Implements `GodotClass` and `Bounds`, and registers the class with its base.
+pub(crate) fn make_class_definition(def: ClassDefinition) -> TokenStream {
+    let ClassDefinition {
+        class_name,
+        class_name_str,
+        base_ty,
+        is_tool,
+        is_editor_plugin,
+        is_hidden,
+        is_instantiable,
+        create_fn,
+        recreate_fn,
+        register_mixin_methods_fn,
+        validate_property_fn,
+        property_can_revert_fn,
+        property_get_revert_fn,
+        default_get_virtual_fn,
+        generated_get_fn,
+        generated_set_fn,
+        get_property_list_fn,
+        free_property_list_fn,
+        singleton_name,
+        docs,
+    } = def;
+
+    let class_name_cstr = util::cstr_u8_slice(class_name_str);
+    let class_name_obj = util::class_name_obj(class_name);
+
+    let base_class = quote! { ::godot::engine::#base_ty };
+    let base_class_name_obj = util::class_name_obj(&base_class);
+    let inherits_macro = format_ident!("unsafe_inherits_transitive_{}", base_ty);
+
+    let prv = quote! { ::godot::private };
+
+    // A base class that doesn't exist is reported by every use below. This item adds a "cannot find type" error pointing at the
+    // `base` key, which comes first and is more descriptive than e.g. the missing `unsafe_inherits_transitive_*` macro.
+    let base_check = quote_spanned! { base_ty.span()=>
+        const _: () = {
+            let _ = ::std::marker::PhantomData::<::godot::engine::#base_ty>;
+        };
+    };
+
+    quote! {
+        #base_check
+
         impl ::godot::obj::GodotClass for #class_name {
             type Base = #base_class;
 
@@ -121,12 +286,6 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
             type Declarer = ::godot::obj::bounds::DeclUser;
         }
 
-        #godot_init_impl
-        #godot_withbase_impl
-        #godot_exports_impl
-        #user_class_impl
-        #init_expecter
-
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
             class_name: #class_name_obj,
             item: #prv::PluginItem::Struct {
@@ -136,12 +295,22 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
                 register_properties_fn: #prv::ErasedRegisterFn {
                     raw: #prv::callbacks::register_user_properties::<#class_name>,
                 },
+                register_mixin_methods_fn: #register_mixin_methods_fn,
                 free_fn: #prv::callbacks::free::<#class_name>,
+                validate_property_fn: #validate_property_fn,
+                property_can_revert_fn: #property_can_revert_fn,
+                property_get_revert_fn: #property_get_revert_fn,
                 default_get_virtual_fn: #default_get_virtual_fn,
+                generated_get_fn: #generated_get_fn,
+                generated_set_fn: #generated_set_fn,
+                get_property_list_fn: #get_property_list_fn,
+                free_property_list_fn: #free_property_list_fn,
                 is_tool: #is_tool,
                 is_editor_plugin: #is_editor_plugin,
                 is_hidden: #is_hidden,
                 is_instantiable: #is_instantiable,
+                singleton_name: #singleton_name,
+                docs: #docs,
             },
             init_level: {
                 let level = <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL;
@@ -161,8 +330,45 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
         });
 
         #prv::class_macros::#inherits_macro!(#class_name);
-        #deprecated_base_warning
-    })
+    }
+}
+
+/// Invokes the macro generated by `#[godot_api(mixin)]` for each `#[mixin]` field, adding forwarding methods to the class.
+///
+/// Returns the impls, and the expression for the `register_mixin_methods_fn` plugin field.
+fn make_mixin_impls(class_name: &Ident, all_fields: &[Field]) -> (TokenStream, TokenStream) {
+    let mixin_fields: Vec<&Field> = all_fields.iter().filter(|field| field.is_mixin).collect();
+    if mixin_fields.is_empty() {
+        return (TokenStream::new(), quote! { None });
+    }
+
+    let invocations = mixin_fields.iter().map(|field| {
+        let field_name = &field.name;
+        let mixin_ty = &field.ty;
+        let register_fn = field.mixin_register_fn();
+
+        quote! {
+            #mixin_ty!(#class_name, #field_name, #mixin_ty, #register_fn);
+        }
+    });
+    let register_fns = mixin_fields.iter().map(|field| field.mixin_register_fn());
+
+    let impls = quote! {
+        #( #invocations )*
+
+        impl ::godot::obj::cap::ImplementsGodotMixins for #class_name {
+            fn __register_mixin_methods() {
+                #( Self::#register_fns(); )*
+            }
+        }
+    };
+    let register_fn = quote! {
+        Some(::godot::private::ErasedRegisterFn {
+            raw: ::godot::private::callbacks::register_user_mixin_methods::<#class_name>,
+        })
+    };
+
+    (impls, register_fn)
 }
 
 /// Checks at compile time that a function with the given name exists on `Self`.
@@ -190,41 +396,508 @@ struct ClassAttributes {
     is_tool: bool,
     is_editor_plugin: bool,
     is_hidden: bool,
+    is_export_all: bool,
+    queue_free_safe: Option<Ident>,
+    after_init: Option<Ident>,
+    is_init_from_default: bool,
+    scene: Option<TokenStream>,
+    singleton: Option<TokenStream>,
     rename: Option<Ident>,
+    before_save_fn: Option<Ident>,
+    after_load_fn: Option<Ident>,
+    is_introspect: bool,
+    is_typed_accessors: bool,
+    is_export_dispatch: bool,
+    is_resettable: bool,
 }
 
-fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
-    let base_init = if let Some(Field { name, .. }) = fields.base_field {
-        quote! { #name: base, }
-    } else {
-        TokenStream::new()
+fn make_godot_init_impl(
+    class_name: &Ident,
+    fields: Fields,
+    after_init: Option<Ident>,
+    is_init_from_default: bool,
+) -> TokenStream {
+    // Struct update syntax cannot supply the base field, so classes with one initialize every field explicitly.
+    let use_struct_default = is_init_from_default && fields.base_field.is_none();
+
+    let base_init = fields
+        .base_field
+        .map(|Field { name, .. }| quote! { #name: base, });
+
+    let mut field_inits: Vec<TokenStream> = fields
+        .all_fields
+        .into_iter()
+        .filter_map(|field| {
+            let field_name = field.name;
+            let value_expr = match field.default {
+                Some(default) => default,
+                // Named from the start, so that access before ready() reports the property. Not left to a struct `Default` impl.
+                None if field.is_oneditor => {
+                    let property_name = format!("{class_name}.{field_name}");
+                    quote! { ::godot::private::new_on_editor(#property_name) }
+                }
+                None if use_struct_default => return None,
+                None => quote! { ::std::default::Default::default() },
+            };
+
+            Some(quote! { #field_name: #value_expr, })
+        })
+        .collect();
+
+    // Struct literal fields are evaluated in the order they are written, so keep the declaration order -- including the base
+    // field -- to give side-effecting `#[init(default)]` expressions a well-defined sequence.
+    if let Some(base_init) = base_init {
+        field_inits.insert(fields.base_field_index, base_init);
+    } else if use_struct_default {
+        field_inits.push(quote! { ..::std::default::Default::default() });
+    }
+
+    let body = match after_init {
+        Some(method) => quote! {
+            let mut instance = Self {
+                #( #field_inits )*
+            };
+
+            // Type annotation enforces the `&mut self` signature.
+            let after_init: fn(&mut Self) = Self::#method;
+            after_init(&mut instance);
+
+            instance
+        },
+        None => quote! {
+            Self {
+                #( #field_inits )*
+            }
+        },
     };
 
-    let rest_init = fields.all_fields.into_iter().map(|field| {
-        let field_name = field.name;
-        let value_expr = field
-            .default
-            .unwrap_or_else(|| quote! { ::std::default::Default::default() });
+    quote! {
+        impl ::godot::obj::cap::GodotDefault for #class_name {
+            fn __godot_user_init(base: ::godot::obj::Base<Self::Base>) -> Self {
+                #body
+            }
+        }
+    }
+}
+
+/// Generates the `reset_to_defaults()` method for `#[class(resettable)]`, reusing the default expressions of the generated `init`.
+fn make_reset_impl(class_name: &Ident, fields: &Fields, is_init_from_default: bool) -> TokenStream {
+    // Mirrors `make_godot_init_impl()`: with `from_default`, fields without `#[init(default)]` come from the struct's `Default` impl.
+    // OnReady<T> and OnEditor<T> are initialized in ready() resp. the inspector, not in init; resetting them would lose that value.
+    let reset_fields = fields
+        .all_fields
+        .iter()
+        .filter(|field| !field.is_onready && !field.is_oneditor)
+        .collect::<Vec<_>>();
+
+    let use_struct_default = is_init_from_default && fields.base_field.is_none();
+    let needs_struct_default =
+        use_struct_default && reset_fields.iter().any(|field| field.default.is_none());
+    let struct_default = needs_struct_default.then(|| {
+        quote! { let __defaults: Self = ::std::default::Default::default(); }
+    });
 
-        quote! { #field_name: #value_expr, }
+    let field_resets = reset_fields.iter().map(|field| {
+        let field_name = &field.name;
+        let value_expr = match &field.default {
+            Some(default) => default.clone(),
+            None if use_struct_default => quote! { __defaults.#field_name },
+            None => quote! { ::std::default::Default::default() },
+        };
+
+        quote! { self.#field_name = #value_expr; }
     });
 
     quote! {
-        impl ::godot::obj::cap::GodotDefault for #class_name {
-            fn __godot_user_init(base: ::godot::obj::Base<Self::Base>) -> Self {
-                Self {
-                    #( #rest_init )*
-                    #base_init
+        impl #class_name {
+            /// Restores all fields except the base to the values they receive in the generated `init`.
+            ///
+            /// `OnReady<T>` and `OnEditor<T>` fields are left untouched.
+            ///
+            /// Generated by `#[class(resettable)]`.
+            pub fn reset_to_defaults(&mut self) {
+                #struct_default
+                #( #field_resets )*
+            }
+        }
+    }
+}
+
+/// Generates the `queue_free_safe()` method for `#[class(queue_free_safe)]`.
+fn make_queue_free_safe_impl(
+    class_name: &Ident,
+    base_ty: &Ident,
+    attr_key: &Ident,
+    fields: &Fields,
+) -> ParseResult<TokenStream> {
+    if base_ty == "RefCounted" || base_ty == "Object" {
+        return bail!(
+            attr_key,
+            "#[class(queue_free_safe)] requires a `Node`-derived base, but the base is `{base_ty}`"
+        );
+    }
+
+    if fields.base_field.is_none() {
+        return bail!(
+            attr_key,
+            "#[class(queue_free_safe)] requires a `Base<T>` field"
+        );
+    }
+
+    Ok(quote! {
+        impl #class_name {
+            /// Queues this node for deletion, but only if it is inside the scene tree and not already queued.
+            ///
+            /// Generated by `#[class(queue_free_safe)]`.
+            pub fn queue_free_safe(&mut self) {
+                fn __type_check<T: ::godot::obj::Inherits<::godot::engine::Node>>() {}
+                __type_check::<<Self as ::godot::obj::GodotClass>::Base>();
+
+                let mut base = ::godot::obj::WithBaseField::base_mut(self);
+                if base.is_inside_tree() && !base.is_queued_for_deletion() {
+                    base.queue_free();
                 }
             }
         }
+    })
+}
+
+/// Generates `SCENE_PATH` and `instantiate()` for `#[class(scene = "...")]`.
+fn make_scene_impl(class_name: &Ident, path: &TokenStream) -> TokenStream {
+    quote! {
+        impl #class_name {
+            /// Path of the scene whose root node has this class.
+            ///
+            /// Generated by `#[class(scene = ...)]`.
+            pub const SCENE_PATH: &'static str = #path;
+
+            /// Loads the scene at [`Self::SCENE_PATH`] and instantiates it.
+            ///
+            /// Generated by `#[class(scene = ...)]`.
+            ///
+            /// # Panics
+            /// If the scene cannot be loaded or instantiated, or if its root node does not have this class.
+            pub fn instantiate() -> ::godot::obj::Gd<Self> {
+                fn __type_check<T: ::godot::obj::Inherits<::godot::engine::Node>>() {}
+                __type_check::<<Self as ::godot::obj::GodotClass>::Base>();
+
+                let scene = ::godot::engine::load::<::godot::engine::PackedScene>(Self::SCENE_PATH);
+                let root = scene.instantiate().unwrap_or_else(|| {
+                    panic!("failed to instantiate scene `{}`", Self::SCENE_PATH)
+                });
+
+                root.try_cast::<Self>().unwrap_or_else(|root| {
+                    let actual = root.get_class();
+                    root.free();
+
+                    panic!(
+                        "root node of scene `{}` has class {actual}, expected {}",
+                        Self::SCENE_PATH,
+                        <Self as ::godot::obj::GodotClass>::class_name(),
+                    )
+                })
+            }
+        }
+    }
+}
+
+/// Generates `SINGLETON_NAME` and `singleton()` for `#[class(singleton)]`.
+fn make_singleton_impl(class_name: &Ident, name: &TokenStream) -> TokenStream {
+    quote! {
+        // Singletons are freed when the extension is unloaded, which is only possible for manually managed objects.
+        const _: fn() = || {
+            fn __type_check<T: ::godot::obj::Bounds<Memory = ::godot::obj::bounds::MemManual>>() {}
+            __type_check::<#class_name>();
+        };
+
+        impl #class_name {
+            /// Name under which the instance is registered as an engine singleton.
+            ///
+            /// Generated by `#[class(singleton)]`.
+            pub const SINGLETON_NAME: &'static str = #name;
+
+            /// Returns the engine singleton, as registered under [`Self::SINGLETON_NAME`].
+            ///
+            /// Generated by `#[class(singleton)]`.
+            ///
+            /// # Panics
+            /// If the singleton is not registered, i.e. before class registration or after shutdown.
+            pub fn singleton() -> ::godot::obj::Gd<Self> {
+                ::godot::engine::Engine::singleton()
+                    .get_singleton(Self::SINGLETON_NAME.into())
+                    .unwrap_or_else(|| panic!("engine singleton `{}` is not registered", Self::SINGLETON_NAME))
+                    .cast::<Self>()
+            }
+        }
     }
 }
 
-fn make_user_class_impl(
+/// Generates a `GodotValidateProperty` impl, adjusting fields with `#[export(range_max_from = ...)]` or `#[export(read_only)]`.
+///
+/// All such fields are handled in one function. The impl is always generated, since a user-defined `validate_property()` applies
+/// these adjustments, too. Returns the impl, and the expression for the `validate_property_fn` plugin field (`None` without such fields).
+fn make_validate_property_impl(
+    class_name: &Ident,
+    all_fields: &[Field],
+) -> (TokenStream, TokenStream) {
+    let mut property_names = vec![];
+    let mut property_adjustments = vec![];
+
+    for field in all_fields {
+        let Some(export) = &field.export else {
+            continue;
+        };
+
+        let hint_adjustment = export.to_dynamic_range_hint();
+        let is_read_only = field.export_read_only;
+
+        if hint_adjustment.is_some() || is_read_only {
+            let hint = match hint_adjustment {
+                Some(hint) => quote! { Some(#hint) },
+                None => quote! { None },
+            };
+
+            property_names.push(field.name.to_string());
+            property_adjustments.push(quote! {
+                ::godot::private::PropertyAdjustment {
+                    hint: #hint,
+                    is_read_only: #is_read_only,
+                }
+            });
+        }
+    }
+
+    if property_names.is_empty() {
+        let validate_property_impl = quote! {
+            impl ::godot::obj::cap::GodotValidateProperty for #class_name {
+                fn __godot_validate_property(
+                    &self,
+                    _property_name: &::godot::builtin::StringName,
+                ) -> Option<::godot::private::PropertyAdjustment> {
+                    None
+                }
+            }
+        };
+
+        return (validate_property_impl, quote! { None });
+    }
+
+    let validate_property_impl = quote! {
+        impl ::godot::obj::cap::GodotValidateProperty for #class_name {
+            fn __godot_validate_property(
+                &self,
+                property_name: &::godot::builtin::StringName,
+            ) -> Option<::godot::private::PropertyAdjustment> {
+                match property_name.to_string().as_str() {
+                    #(
+                        #property_names => Some(#property_adjustments),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    };
+
+    let validate_property_fn =
+        quote! { Some(::godot::private::callbacks::validate_property::<#class_name>) };
+
+    (validate_property_impl, validate_property_fn)
+}
+
+/// Generates a `GodotPropertyRevert` impl, if any exported field declares a default.
+///
+/// The revert value is taken from `#[export(default = ...)]`, or otherwise from `#[init(default = ...)]`, which must then be a literal
+/// or constant. The expression is evaluated on every revert query, so other init expressions (e.g. allocating objects) are rejected
+/// rather than silently re-run.
+///
+/// Returns the impl, and the expressions for the `property_can_revert_fn` and `property_get_revert_fn` plugin fields.
+fn make_property_revert_impl(
+    class_name: &Ident,
+    all_fields: &[Field],
+) -> ParseResult<(TokenStream, TokenStream, TokenStream)> {
+    let mut property_names = vec![];
+    let mut revert_values = vec![];
+
+    for field in all_fields {
+        if field.export.is_none() {
+            continue;
+        }
+
+        let default = match (&field.export_default, &field.default) {
+            (Some(export_default), _) => export_default,
+            (None, Some(init_default)) if is_const_like(init_default) => init_default,
+            (None, Some(init_default)) => {
+                return bail!(
+                    init_default,
+                    "#[init(default)] of an #[export] field is its revert value, and must be a literal or constant; \
+                    for other expressions, declare the revert value with #[export(default = ...)]"
+                );
+            }
+            (None, None) => continue,
+        };
+
+        let field_ty = &field.ty;
+        property_names.push(field.name.to_string());
+        revert_values.push(quote! {
+            {
+                let value: #field_ty = #default;
+                ::godot::builtin::meta::ToGodot::to_variant(
+                    &::godot::register::property::Var::get_property(&value)
+                )
+            }
+        });
+    }
+
+    if property_names.is_empty() {
+        return Ok((TokenStream::new(), quote! { None }, quote! { None }));
+    }
+
+    let property_revert_impl = quote! {
+        impl ::godot::obj::cap::GodotPropertyRevert for #class_name {
+            fn __godot_property_get_revert(
+                &self,
+                property: ::godot::builtin::StringName,
+            ) -> Option<::godot::builtin::Variant> {
+                match property.to_string().as_str() {
+                    #(
+                        #property_names => Some(#revert_values),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    };
+
+    let property_can_revert_fn =
+        quote! { Some(::godot::private::callbacks::property_can_revert::<#class_name>) };
+    let property_get_revert_fn =
+        quote! { Some(::godot::private::callbacks::property_get_revert::<#class_name>) };
+
+    Ok((
+        property_revert_impl,
+        property_can_revert_fn,
+        property_get_revert_fn,
+    ))
+}
+
+/// Whether `expr` is a literal, optionally negated, or a path such as `i32::MAX` or `Self::SPEED` -- i.e. cheap and free of side effects.
+fn is_const_like(expr: &TokenStream) -> bool {
+    let mut tokens = expr.clone().into_iter().peekable();
+    if matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == '-') {
+        tokens.next();
+    }
+
+    match tokens.next() {
+        Some(TokenTree::Literal(_)) => tokens.next().is_none(),
+        Some(TokenTree::Ident(_)) => tokens.all(|token| match token {
+            TokenTree::Ident(_) => true,
+            TokenTree::Punct(punct) => punct.as_char() == ':',
+            _ => false,
+        }),
+        _ => false,
+    }
+}
+
+/// Implements `GodotStorageHooks` for `#[class(before_save_fn = ..., after_load_fn = ...)]`.
+///
+/// Returns the impl, and the expressions for the `generated_get_fn` and `generated_set_fn` plugin fields.
+fn make_storage_hooks_impl(
+    class_name: &Ident,
+    before_save_fn: Option<&Ident>,
+    after_load_fn: Option<&Ident>,
+    all_fields: &[Field],
+) -> (TokenStream, TokenStream, TokenStream) {
+    if before_save_fn.is_none() && after_load_fn.is_none() {
+        return (TokenStream::new(), quote! { None }, quote! { None });
+    }
+
+    let property_names = all_fields
+        .iter()
+        .filter(|field| field.var.is_some() || field.export.is_some())
+        .map(|field| field.name.to_string());
+
+    let (before_save_body, generated_get_fn) = match before_save_fn {
+        Some(method) => (
+            quote! { Self::#method(self, property, value) },
+            quote! { Some(::godot::private::callbacks::get_property_hooked::<#class_name>) },
+        ),
+        None => (quote! { value }, quote! { None }),
+    };
+
+    let (after_load_body, generated_set_fn) = match after_load_fn {
+        Some(method) => (
+            quote! { Self::#method(self, property) },
+            quote! { Some(::godot::private::callbacks::set_property_hooked::<#class_name>) },
+        ),
+        None => (TokenStream::new(), quote! { None }),
+    };
+
+    let storage_hooks_impl = quote! {
+        impl ::godot::obj::cap::GodotStorageHooks for #class_name {
+            fn __godot_is_hooked_property(property: &::godot::builtin::StringName) -> bool {
+                let property = property.to_string();
+                [ #( #property_names ),* ].contains(&property.as_str())
+            }
+
+            #[allow(unused_variables)]
+            fn __godot_before_save(
+                &self,
+                property: ::godot::builtin::StringName,
+                value: ::godot::builtin::Variant,
+            ) -> ::godot::builtin::Variant {
+                #before_save_body
+            }
+
+            #[allow(unused_variables)]
+            fn __godot_after_load(&mut self, property: ::godot::builtin::StringName) {
+                #after_load_body
+            }
+        }
+    };
+
+    (storage_hooks_impl, generated_get_fn, generated_set_fn)
+}
+
+/// Collects doc comments of the struct and its properties, registered with the editor help.
+fn make_struct_docs(class: &Struct, all_fields: &[Field]) -> TokenStream {
+    let description_lines = util::extract_doc_literals(&class.attributes);
+
+    let member_docs: Vec<TokenStream> = all_fields
+        .iter()
+        .filter(|field| field.var.is_some() || field.export.is_some())
+        .filter(|field| !field.doc_lines.is_empty() || field.export_tooltip.is_some())
+        .map(|field| {
+            let name = field.name.to_string();
+
+            // The editor shows a property's description as its inspector tooltip; an explicit tooltip replaces the doc comment.
+            if let Some(tooltip) = &field.export_tooltip {
+                return quote! { (#name, #tooltip) };
+            }
+
+            let lines = &field.doc_lines;
+            quote! {
+                (#name, concat!( #( #lines, "\n", )* ))
+            }
+        })
+        .collect();
+
+    if description_lines.is_empty() && member_docs.is_empty() {
+        return quote! { None };
+    }
+
+    quote! {
+        Some(::godot::private::StructDocs {
+            description: concat!( #( #description_lines, "\n", )* ),
+            members: &[ #( #member_docs ),* ],
+        })
+    }
+}
+
+pub(crate) fn make_user_class_impl(
     class_name: &Ident,
     is_tool: bool,
     all_fields: &[Field],
+    base_field: Option<&Field>,
 ) -> (TokenStream, bool) {
     let onready_field_inits = all_fields
         .iter()
@@ -232,15 +905,55 @@ fn make_user_class_impl(
         .map(|field| {
             let field = &field.name;
             quote! {
-                ::godot::private::auto_init(&mut self.#field);
+                ::godot::private::auto_init_before_ready(&mut self.#field);
             }
         });
 
-    let default_virtual_fn = if all_fields.iter().any(|field| field.is_onready) {
+    let oneditor_field_checks = all_fields
+        .iter()
+        .filter(|&field| field.is_oneditor)
+        .map(|field| {
+            let property_name = format!("{class_name}.{}", field.name);
+            let field = &field.name;
+            quote! {
+                ::godot::private::validate_on_editor(&mut self.#field, #property_name);
+            }
+        });
+
+    let remote_sync_properties: Vec<TokenStream> = all_fields
+        .iter()
+        .filter_map(|field| {
+            let FieldRemoteSync {
+                replication_mode,
+                is_spawn,
+            } = field.remote_sync.as_ref()?;
+            let property_name = field.name.to_string();
+
+            Some(quote! {
+                (#property_name, ::godot::engine::scene_replication_config::ReplicationMode::#replication_mode, #is_spawn)
+            })
+        })
+        .collect();
+    let remote_sync_setup = (!remote_sync_properties.is_empty()).then(|| {
+        quote! {
+            ::godot::private::add_remote_synchronizer(self, &[ #( #remote_sync_properties ),* ]);
+        }
+    });
+
+    let has_ready_hooks = remote_sync_setup.is_some()
+        || all_fields
+            .iter()
+            .any(|field| field.is_onready || field.is_oneditor);
+    let default_virtual_fn = if has_ready_hooks {
         let tool_check = util::make_virtual_tool_check();
         let signature_info = SignatureInfo::fn_ready();
 
-        let callback = make_virtual_callback(class_name, signature_info, BeforeKind::OnlyBefore);
+        let callback = make_virtual_callback(
+            class_name,
+            signature_info,
+            BeforeKind::OnlyBefore,
+            TokenStream::new(),
+        );
         let default_virtual_fn = quote! {
             fn __default_virtual_call(name: &str) -> ::godot::sys::GDExtensionClassCallVirtual {
                 use ::godot::obj::UserClass as _;
@@ -258,6 +971,14 @@ fn make_user_class_impl(
         None
     };
 
+    let base_object_fn = base_field.map(|Field { name, .. }| {
+        quote! {
+            fn __base_object(&self) -> Option<::godot::obj::Gd<::godot::engine::Object>> {
+                Some(self.#name.to_gd().upcast())
+            }
+        }
+    });
+
     let user_class_impl = quote! {
         impl ::godot::obj::UserClass for #class_name {
             fn __config() -> ::godot::private::ClassConfig {
@@ -267,10 +988,13 @@ fn make_user_class_impl(
             }
 
             fn __before_ready(&mut self) {
+                #( #oneditor_field_checks )*
                 #( #onready_field_inits )*
+                #remote_sync_setup
             }
 
             #default_virtual_fn
+            #base_object_fn
         }
     };
 
@@ -284,7 +1008,19 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
     let mut is_tool = false;
     let mut is_editor_plugin = false;
     let mut is_hidden = false;
+    let mut is_export_all = false;
+    let mut queue_free_safe = None;
+    let mut after_init = None;
+    let mut is_init_from_default = false;
+    let mut scene = None;
+    let mut singleton = None;
     let mut rename: Option<Ident> = None;
+    let mut before_save_fn = None;
+    let mut after_load_fn = None;
+    let mut is_introspect = false;
+    let mut is_typed_accessors = false;
+    let mut is_export_dispatch = false;
+    let mut is_resettable = false;
 
     // #[class] attribute on struct
     if let Some(mut parser) = KvParser::parse(&class.attributes, "class")? {
@@ -303,6 +1039,30 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
             None => {}
         }
 
+        // #[class(init, after_init = method)]
+        if let Some(method) = parser.handle_ident("after_init")? {
+            if !matches!(init_strategy, InitStrategy::Generated) {
+                return bail!(
+                    &method,
+                    "#[class(after_init)] requires additional key `init`"
+                );
+            }
+
+            after_init = Some(method);
+        }
+
+        // #[class(init, from_default)]
+        if let Some(attr_key) = parser.handle_alone_with_span("from_default")? {
+            if !matches!(init_strategy, InitStrategy::Generated) {
+                return bail!(
+                    &attr_key,
+                    "#[class(from_default)] requires additional key `init`"
+                );
+            }
+
+            is_init_from_default = true;
+        }
+
         // #[class(tool)]
         if parser.handle_alone("tool")? {
             is_tool = true;
@@ -335,6 +1095,72 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
             is_hidden = true;
         }
 
+        // #[class(export_all)]
+        if parser.handle_alone("export_all")? {
+            is_export_all = true;
+        }
+
+        // #[class(queue_free_safe)]
+        if let Some(attr_key) = parser.handle_alone_with_span("queue_free_safe")? {
+            queue_free_safe = Some(attr_key);
+        }
+
+        // #[class(scene = "res://path.tscn")]
+        if let Some(path) = parser.handle_expr("scene")? {
+            scene = Some(parse_scene_path(path)?);
+        }
+
+        // #[class(singleton)], #[class(singleton = "Name")]
+        if let Some((key, value)) = parser.handle_any_entry("singleton") {
+            if matches!(init_strategy, InitStrategy::Absent) {
+                return bail!(
+                    key,
+                    "#[class(singleton)] requires a constructor, cannot be combined with `no_init`"
+                );
+            }
+
+            singleton = Some(match value {
+                Some(value) => parse_singleton_name(value.single()?)?,
+                None => {
+                    let name = rename.as_ref().unwrap_or(&class.name).to_string();
+                    quote! { #name }
+                }
+            });
+        }
+
+        // #[class(before_save_fn = method)]
+        before_save_fn = parser.handle_ident("before_save_fn")?;
+
+        // #[class(after_load_fn = method)]
+        after_load_fn = parser.handle_ident("after_load_fn")?;
+
+        // #[class(typed_accessors)]
+        if parser.handle_alone("typed_accessors")? {
+            is_typed_accessors = true;
+        }
+
+        // #[class(export_dispatch)]
+        if parser.handle_alone("export_dispatch")? {
+            is_export_dispatch = true;
+        }
+
+        // #[class(resettable)]
+        if let Some(attr_key) = parser.handle_alone_with_span("resettable")? {
+            if !matches!(init_strategy, InitStrategy::Generated) {
+                return bail!(
+                    &attr_key,
+                    "#[class(resettable)] requires additional key `init`, whose default values are restored"
+                );
+            }
+
+            is_resettable = true;
+        }
+
+        // #[class(introspect)]
+        if parser.handle_alone("introspect")? {
+            is_introspect = true;
+        }
+
         parser.finish()?;
     }
 
@@ -344,14 +1170,66 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
         is_tool,
         is_editor_plugin,
         is_hidden,
+        is_export_all,
+        queue_free_safe,
+        after_init,
+        is_init_from_default,
+        scene,
+        singleton,
         rename,
+        before_save_fn,
+        after_load_fn,
+        is_introspect,
+        is_typed_accessors,
+        is_export_dispatch,
+        is_resettable,
     })
 }
 
+/// Validates the `#[class(singleton = ...)]` value, which must be a string literal.
+fn parse_singleton_name(value: TokenTree) -> ParseResult<TokenStream> {
+    match value {
+        TokenTree::Literal(literal) if literal.to_string().starts_with('"') => {
+            Ok(TokenTree::Literal(literal).into())
+        }
+        other => bail!(
+            other,
+            "expected `singleton` to be followed by a string literal, e.g. `singleton = \"MySingleton\"`"
+        ),
+    }
+}
+
+/// Validates the `#[class(scene = ...)]` value, which must be a string literal with a `res://` path to a scene file.
+fn parse_scene_path(expr: TokenStream) -> ParseResult<TokenStream> {
+    let mut tokens = expr.clone().into_iter();
+    let (Some(TokenTree::Literal(literal)), None) = (tokens.next(), tokens.next()) else {
+        return bail!(
+            expr,
+            "expected `scene` to be followed by a string literal, e.g. `scene = \"res://my_scene.tscn\"`"
+        );
+    };
+
+    let path = literal.to_string();
+    let path = path.trim_matches('"');
+    if !path.starts_with("res://") || !(path.ends_with(".tscn") || path.ends_with(".scn")) {
+        return bail!(
+            literal,
+            "`scene` must be a `res://` path to a `.tscn` or `.scn` file"
+        );
+    }
+
+    Ok(TokenTree::Literal(literal).into())
+}
+
 /// Returns field names and 1 base field, if available
-fn parse_fields(class: &Struct, init_strategy: InitStrategy) -> ParseResult<Fields> {
+fn parse_fields(
+    class: &Struct,
+    init_strategy: InitStrategy,
+    is_export_all: bool,
+) -> ParseResult<Fields> {
     let mut all_fields = vec![];
     let mut base_field = Option::<Field>::None;
+    let mut base_field_index = 0;
     let mut has_deprecated_base = false;
 
     let named_fields: Vec<(NamedField, Punct)> = match &class.fields {
@@ -386,6 +1264,11 @@ fn parse_fields(class: &Struct, init_strategy: InitStrategy) -> ParseResult<Fiel
             field.is_onready = true;
         }
 
+        // OnEditor<T> type inference
+        if path_ends_with_complex(&field.ty, "OnEditor") {
+            field.is_oneditor = true;
+        }
+
         // #[init]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "init")? {
             // #[init] on fields is useless if there is no generated constructor.
@@ -404,8 +1287,54 @@ fn parse_fields(class: &Struct, init_strategy: InitStrategy) -> ParseResult<Fiel
 
         // #[export]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export")? {
+            // #[export(default = expr)]
+            let export_default = parser.handle_expr("default")?;
+            // #[export(order = N)]
+            field.export_order = parser.handle_usize("order")?;
+            // #[export(tooltip = "...")]
+            if let Some(tooltip) = parser.handle_expr("tooltip")? {
+                // Tooltips are registered through the editor help, which is unavailable before 4.3; don't drop them silently.
+                require_api_version!("4.3", &tooltip, "#[export(tooltip)]")?;
+                field.export_tooltip = Some(tooltip);
+            }
+            // #[export(read_only)]
+            if let Some(key) = parser.handle_alone_with_span("read_only")? {
+                require_api_version!("4.2", &key, "#[export(read_only)]")?;
+                field.export_read_only = true;
+            }
+            // #[export(no_emit_changed)]
+            let no_emit_changed = parser.handle_alone("no_emit_changed")?;
             let export = FieldExport::new_from_kv(&mut parser)?;
             field.export = Some(export);
+            field.export_emit_changed = !no_emit_changed;
+            parser.finish()?;
+
+            // Without explicit #[init(default = ...)], the generated constructor starts out with the revert value.
+            if field.default.is_none() {
+                field.default = export_default.clone();
+            }
+            field.export_default = export_default;
+        }
+
+        // #[no_export]
+        let mut is_no_export = false;
+        if let Some(parser) = KvParser::parse(&named_field.attributes, "no_export")? {
+            // #[no_export] only makes sense to opt out of #[class(export_all)].
+            if !is_export_all {
+                return bail!(
+                    parser.span(),
+                    "field attribute #[no_export] requires struct attribute #[class(export_all)]"
+                );
+            }
+
+            if field.export.is_some() {
+                return bail!(
+                    parser.span(),
+                    "field attributes #[export] and #[no_export] are mutually exclusive"
+                );
+            }
+
+            is_no_export = true;
             parser.finish()?;
         }
 
@@ -416,6 +1345,38 @@ fn parse_fields(class: &Struct, init_strategy: InitStrategy) -> ParseResult<Fiel
             parser.finish()?;
         }
 
+        // #[remote_sync]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "remote_sync")? {
+            require_api_version!("4.2", parser.span(), "#[remote_sync]")?;
+            field.remote_sync = Some(parse_remote_sync(&mut parser)?);
+            parser.finish()?;
+        }
+
+        // #[mixin]
+        if let Some(parser) = KvParser::parse(&named_field.attributes, "mixin")? {
+            if field.is_onready || field.var.is_some() || field.export.is_some() {
+                return bail!(
+                    parser.span(),
+                    "#[mixin] field cannot have type `OnReady<T>` or attributes #[var] or #[export]"
+                );
+            }
+
+            let is_plain_path = field.ty.as_path().map_or(false, |path| {
+                path.segments
+                    .iter()
+                    .all(|segment| segment.generic_args.is_none())
+            });
+            if !is_plain_path {
+                return bail!(
+                    &field.ty,
+                    "#[mixin] field must have a type path without generic arguments"
+                );
+            }
+
+            field.is_mixin = true;
+            parser.finish()?;
+        }
+
         // #[hint] to override type inference (must be at the end).
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "hint")? {
             if let Some(override_base) = handle_opposite_keys(&mut parser, "base", "hint")? {
@@ -441,6 +1402,7 @@ fn parse_fields(class: &Struct, init_strategy: InitStrategy) -> ParseResult<Fiel
                 );
             }
 
+            base_field_index = all_fields.len();
             if let Some(prev_base) = base_field.replace(field) {
                 // Ensure at most one Base<T>.
                 return bail!(
@@ -451,18 +1413,88 @@ fn parse_fields(class: &Struct, init_strategy: InitStrategy) -> ParseResult<Fiel
                 );
             }
         } else {
+            // #[class(export_all)] exports all remaining fields, except OnReady<T> ones (which cannot be exported).
+            if is_export_all
+                && !is_no_export
+                && !field.is_onready
+                && !field.is_mixin
+                && field.export.is_none()
+            {
+                field.export = Some(FieldExport::Default);
+            }
+
+            // The synchronizer reads and writes the field through its Godot property.
+            if field.remote_sync.is_some() && field.var.is_none() && field.export.is_none() {
+                return bail!(
+                    named_field,
+                    "#[remote_sync] requires attribute #[var] or #[export]"
+                );
+            }
+
+            // OnEditor<T> is only ever set through the inspector.
+            if field.is_oneditor && field.export.is_none() {
+                return bail!(
+                    named_field,
+                    "field of type `OnEditor<T>` requires attribute #[export]"
+                );
+            }
+
             all_fields.push(field);
         }
     }
 
+    // Emitting `changed` goes through the base object, so it needs a `Base<T>` field.
+    if base_field.is_none() {
+        // Same for adding the synchronizer as a child.
+        if let Some(field) = all_fields.iter().find(|field| field.remote_sync.is_some()) {
+            return bail!(&field.name, "#[remote_sync] requires a `Base<T>` field");
+        }
+
+        for field in all_fields.iter_mut() {
+            field.export_emit_changed = false;
+        }
+    }
+
     Ok(Fields {
         all_fields,
         base_field,
+        base_field_index,
         has_deprecated_base,
     })
 }
 
-fn handle_opposite_keys(
+/// Parses the keys of `#[remote_sync(replication_mode = ..., no_spawn)]`.
+fn parse_remote_sync(parser: &mut KvParser) -> ParseResult<FieldRemoteSync> {
+    // Godot's `SceneReplicationConfig` has no per-property interpolation, so reject it instead of silently ignoring it.
+    if let Some((key, _)) = parser.handle_any_entry("interpolated") {
+        return bail!(
+            key,
+            "#[remote_sync(interpolated)] is not supported: MultiplayerSynchronizer does not interpolate properties"
+        );
+    }
+
+    let replication_mode = match parser.handle_ident("replication_mode")? {
+        None => ident("REPLICATION_MODE_ALWAYS"),
+        Some(mode) if mode == "always" => ident("REPLICATION_MODE_ALWAYS"),
+        Some(mode) if mode == "on_change" => ident("REPLICATION_MODE_ON_CHANGE"),
+        Some(mode) if mode == "never" => ident("REPLICATION_MODE_NEVER"),
+        Some(mode) => {
+            return bail!(
+                mode,
+                "#[remote_sync(replication_mode)] must be one of `always`, `on_change` or `never`"
+            )
+        }
+    };
+
+    let is_spawn = !parser.handle_alone("no_spawn")?;
+
+    Ok(FieldRemoteSync {
+        replication_mode,
+        is_spawn,
+    })
+}
+
+pub(crate) fn handle_opposite_keys(
     parser: &mut KvParser,
     key: &str,
     attribute: &str,
@@ -0,0 +1,485 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use venial::{Enum, StructFields, TyExpr};
+
+use crate::class::{
+    handle_opposite_keys, make_class_definition, make_method_registration, make_user_class_impl,
+    ClassDefinition, FuncDefinition,
+};
+use crate::util::{bail, ident, require_api_version, KvParser};
+use crate::{util, ParseResult};
+
+/// Derives `GodotClass` for an enum, exposed to Godot as a state machine.
+///
+/// The current variant is available as integer property `state`. The data of a single-field variant is an additional property,
+/// named after the variant in snake_case, which is only listed while that variant is active.
+pub fn derive_state_machine(enum_: &Enum) -> ParseResult<TokenStream> {
+    if enum_.generic_params.is_some() {
+        return bail!(
+            &enum_.name,
+            "#[derive(GodotClass)] does not support generic enums"
+        );
+    }
+
+    let enum_cfg = parse_enum_attributes(enum_)?;
+    let variants = parse_variants(enum_)?;
+
+    let class_name = &enum_.name;
+    let godot_name = enum_cfg.rename.unwrap_or_else(|| enum_.name.clone());
+    let class_name_str = godot_name.to_string();
+    if util::is_engine_class_name(&class_name_str) {
+        return bail!(
+            godot_name,
+            "'{class_name_str}' is a built-in Godot class name; rename your enum (or use #[class(rename = ...)]) to avoid conflicts"
+        );
+    }
+
+    let prv = quote! { ::godot::private };
+    let transitions_impl = make_transitions_impl(class_name, &variants)?;
+    let properties_impl = make_properties_impl(class_name, &variants);
+    let (user_class_impl, _) = make_user_class_impl(class_name, enum_cfg.is_tool, &[], None);
+
+    let mut init_expecter = TokenStream::new();
+    let mut godot_init_impl = TokenStream::new();
+    let mut create_fn = quote! { None };
+    let mut recreate_fn = quote! { None };
+    let mut is_instantiable = true;
+
+    match enum_cfg.init_strategy {
+        Some(true) => {
+            // Starts in the first variant; its data (if any) is default-constructed.
+            let Variant { name, data_ty, .. } = &variants[0];
+            let initial = match data_ty {
+                Some(_) => quote! { Self::#name(::std::default::Default::default()) },
+                None => quote! { Self::#name },
+            };
+
+            godot_init_impl = quote! {
+                impl ::godot::obj::cap::GodotDefault for #class_name {
+                    fn __godot_user_init(_base: ::godot::obj::Base<Self::Base>) -> Self {
+                        #initial
+                    }
+                }
+            };
+            create_fn = quote! { Some(#prv::callbacks::create::<#class_name>) };
+
+            if cfg!(since_api = "4.2") {
+                recreate_fn = quote! { Some(#prv::callbacks::recreate::<#class_name>) };
+            }
+        }
+        None => {
+            let fn_name = format_ident!("class_{}_must_have_an_init_method", class_name);
+            init_expecter = quote! {
+                #[allow(non_snake_case)]
+                fn #fn_name() {
+                    fn __type_check<T: ::godot::obj::cap::GodotDefault>() {}
+
+                    __type_check::<#class_name>();
+                }
+            }
+        }
+        Some(false) => {
+            is_instantiable = false;
+        }
+    }
+
+    let class_definition = make_class_definition(ClassDefinition {
+        class_name,
+        class_name_str: &class_name_str,
+        base_ty: &enum_cfg.base_ty,
+        is_tool: enum_cfg.is_tool,
+        is_editor_plugin: false,
+        is_hidden: enum_cfg.is_hidden,
+        is_instantiable,
+        create_fn,
+        recreate_fn,
+        register_mixin_methods_fn: quote! { None },
+        validate_property_fn: quote! { None },
+        property_can_revert_fn: quote! { None },
+        property_get_revert_fn: quote! { None },
+        default_get_virtual_fn: quote! { None },
+        generated_get_fn: quote! { Some(#prv::callbacks::get_property::<#class_name>) },
+        generated_set_fn: quote! { Some(#prv::callbacks::set_property_list_changing::<#class_name>) },
+        get_property_list_fn: quote! { Some(#prv::callbacks::get_property_list::<#class_name>) },
+        free_property_list_fn: quote! { Some(#prv::callbacks::free_property_list::<#class_name>) },
+        singleton_name: quote! { None },
+        docs: quote! { None },
+    });
+
+    Ok(quote! {
+        #class_definition
+        #user_class_impl
+        #godot_init_impl
+        #transitions_impl
+        #properties_impl
+        #init_expecter
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct EnumAttributes {
+    base_ty: Ident,
+    /// `Some(true)` for `init`, `Some(false)` for `no_init`, `None` for a user-defined `init()`.
+    init_strategy: Option<bool>,
+    is_tool: bool,
+    is_hidden: bool,
+    rename: Option<Ident>,
+}
+
+struct Variant {
+    name: Ident,
+    /// Name of the transition method, and of the data property (if any).
+    snake_name: String,
+    /// Type of the data of a single-field tuple variant.
+    data_ty: Option<TyExpr>,
+}
+
+/// Generates `state_index()` and the transition methods, both for Rust and Godot.
+///
+/// The Godot methods are registered like those of a `#[godot_api]` impl block, which therefore cannot be added to the enum.
+fn make_transitions_impl(class_name: &Ident, variants: &[Variant]) -> ParseResult<TokenStream> {
+    let mut index_arms = vec![];
+    let mut transition_fns = vec![];
+    let mut registrations = vec![];
+
+    for (index, variant) in variants.iter().enumerate() {
+        let Variant {
+            name,
+            snake_name,
+            data_ty,
+        } = variant;
+
+        let index = index as i64;
+        let transition_name = format_ident!("to_{snake_name}");
+        let godot_transition_name = format_ident!("__godot_to_{snake_name}");
+        let doc = format!("Switches to variant `{name}`.");
+
+        let (pattern, params, args, construct) = match data_ty {
+            Some(data_ty) => (
+                quote! { Self::#name(..) },
+                quote! { value: #data_ty },
+                quote! { value },
+                quote! { Self::#name(value) },
+            ),
+            None => (
+                quote! { Self::#name },
+                TokenStream::new(),
+                TokenStream::new(),
+                quote! { Self::#name },
+            ),
+        };
+
+        index_arms.push(quote! { #pattern => #index, });
+
+        transition_fns.push(quote! {
+            #[doc = #doc]
+            pub fn #transition_name(&mut self, #params) {
+                *self = #construct;
+            }
+
+            #[doc(hidden)]
+            fn #godot_transition_name(mut this: ::godot::obj::Gd<Self>, #params) {
+                let previous = this.bind().state_index();
+                this.bind_mut().#transition_name(#args);
+
+                if this.bind().state_index() != previous {
+                    this.upcast::<::godot::engine::Object>().notify_property_list_changed();
+                }
+            }
+        });
+
+        let registration = make_method_registration(
+            class_name,
+            FuncDefinition {
+                signature: util::parse_signature(quote! { fn #godot_transition_name(#params) }),
+                external_attributes: Vec::new(),
+                rename: Some(transition_name.to_string()),
+                is_virtual: false,
+                has_gd_self: true,
+                is_hidden_in_editor: false,
+                is_try_mut: false,
+                type_arg: None,
+            },
+        )?;
+        registrations.push(registration);
+    }
+
+    let class_name_obj = util::class_name_obj(class_name);
+    let prv = quote! { ::godot::private };
+
+    Ok(quote! {
+        impl #class_name {
+            /// Index of the current variant, in declaration order. Exposed to Godot as the `state` property.
+            pub fn state_index(&self) -> i64 {
+                match self {
+                    #( #index_arms )*
+                }
+            }
+
+            #( #transition_fns )*
+        }
+
+        impl ::godot::obj::cap::ImplementsGodotApi for #class_name {
+            fn __register_methods() {
+                #( #registrations )*
+            }
+
+            fn __register_constants() {}
+        }
+
+        // Properties are dynamic, see `make_properties_impl()`.
+        impl ::godot::obj::cap::ImplementsGodotExports for #class_name {
+            fn __register_exports() {}
+        }
+
+        ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
+            class_name: #class_name_obj,
+            item: #prv::PluginItem::InherentImpl {
+                register_methods_constants_fn: #prv::ErasedRegisterFn {
+                    raw: #prv::callbacks::register_user_methods_constants::<#class_name>,
+                },
+                user_to_string_fn: None,
+                docs: None,
+            },
+            init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
+        });
+    })
+}
+
+/// Implements `GodotGet`, `GodotSet` and `GodotGetPropertyList` for the `state` property and the data of the active variant.
+fn make_properties_impl(class_name: &Ident, variants: &[Variant]) -> TokenStream {
+    let hint_string = variants
+        .iter()
+        .map(|variant| variant.name.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let mut state_set_arms = vec![];
+    let mut data_get_arms = vec![];
+    let mut data_set_arms = vec![];
+    let mut data_list_arms = vec![];
+
+    for (index, variant) in variants.iter().enumerate() {
+        let Variant {
+            name,
+            snake_name,
+            data_ty,
+        } = variant;
+
+        let index = index as i64;
+        let Some(data_ty) = data_ty else {
+            state_set_arms.push(quote! { #index => Self::#name, });
+            continue;
+        };
+
+        state_set_arms.push(quote! {
+            #index => Self::#name(::std::default::Default::default()),
+        });
+        data_get_arms.push(quote! {
+            (#snake_name, Self::#name(data)) => Some(::godot::builtin::meta::ToGodot::to_variant(data)),
+        });
+        data_set_arms.push(quote! {
+            (#snake_name, Self::#name(data)) => match value.try_to::<#data_ty>() {
+                Ok(value) => {
+                    *data = value;
+                    true
+                }
+                Err(_) => false,
+            },
+        });
+        data_list_arms.push(quote! {
+            Self::#name(_) => Some(
+                <<#data_ty as ::godot::builtin::meta::GodotConvert>::Via as ::godot::builtin::meta::GodotType>::property_info(#snake_name)
+            ),
+        });
+    }
+
+    quote! {
+        impl ::godot::obj::cap::GodotGet for #class_name {
+            fn __godot_get_property(
+                &self,
+                property: ::godot::builtin::StringName,
+            ) -> Option<::godot::builtin::Variant> {
+                match (property.to_string().as_str(), self) {
+                    ("state", _) => Some(::godot::builtin::meta::ToGodot::to_variant(&self.state_index())),
+                    #( #data_get_arms )*
+                    _ => None,
+                }
+            }
+        }
+
+        impl ::godot::obj::cap::GodotSet for #class_name {
+            // Without data variants, only `state` remains.
+            #[allow(clippy::match_single_binding)]
+            fn __godot_set_property(
+                &mut self,
+                property: ::godot::builtin::StringName,
+                value: ::godot::builtin::Variant,
+            ) -> bool {
+                let property = property.to_string();
+                if property == "state" {
+                    let Ok(index) = value.try_to::<i64>() else {
+                        return false;
+                    };
+
+                    // Staying in the current variant keeps its data, e.g. when a saved resource is loaded.
+                    if index != self.state_index() {
+                        *self = match index {
+                            #( #state_set_arms )*
+                            _ => return false,
+                        };
+                    }
+
+                    return true;
+                }
+
+                match (property.as_str(), self) {
+                    #( #data_set_arms )*
+                    _ => false,
+                }
+            }
+        }
+
+        impl ::godot::obj::cap::GodotGetPropertyList for #class_name {
+            #[allow(clippy::match_single_binding, unreachable_patterns)]
+            fn __godot_get_property_list(&self) -> Vec<::godot::builtin::meta::PropertyInfo> {
+                let state = ::godot::builtin::meta::PropertyInfo {
+                    variant_type: ::godot::builtin::VariantType::Int,
+                    class_name: ::godot::builtin::meta::ClassName::none(),
+                    property_name: "state".into(),
+                    hint: ::godot::engine::global::PropertyHint::ENUM,
+                    hint_string: #hint_string.into(),
+                    usage: ::godot::engine::global::PropertyUsageFlags::DEFAULT,
+                };
+
+                // Listed after `state`, so that loading a saved resource switches to the variant before assigning its data.
+                let data = match self {
+                    #( #data_list_arms )*
+                    _ => None,
+                };
+
+                ::std::iter::once(state).chain(data).collect()
+            }
+        }
+    }
+}
+
+fn parse_enum_attributes(enum_: &Enum) -> ParseResult<EnumAttributes> {
+    let mut base_ty = ident("Resource");
+    let mut init_strategy = None;
+    let mut is_tool = false;
+    let mut is_hidden = false;
+    let mut rename = None;
+
+    // #[class] attribute on enum
+    if let Some(mut parser) = KvParser::parse(&enum_.attributes, "class")? {
+        // #[class(base = Base)]
+        if let Some(base) = parser.handle_ident("base")? {
+            base_ty = base;
+        }
+
+        // #[class(rename = NewName)]
+        rename = parser.handle_ident("rename")?;
+
+        // #[class(init)], #[class(no_init)]
+        init_strategy = handle_opposite_keys(&mut parser, "init", "class")?;
+
+        // #[class(tool)]
+        if parser.handle_alone("tool")? {
+            is_tool = true;
+        }
+
+        // #[class(hidden)]
+        if let Some(span) = parser.handle_alone_with_span("hidden")? {
+            require_api_version!("4.2", span, "#[class(hidden)]")?;
+            is_hidden = true;
+        }
+
+        parser.finish()?;
+    }
+
+    Ok(EnumAttributes {
+        base_ty,
+        init_strategy,
+        is_tool,
+        is_hidden,
+        rename,
+    })
+}
+
+fn parse_variants(enum_: &Enum) -> ParseResult<Vec<Variant>> {
+    let mut variants: Vec<Variant> = vec![];
+
+    for variant in enum_.variants.items() {
+        let data_ty = match &variant.contents {
+            StructFields::Unit => None,
+            StructFields::Tuple(tuple) if tuple.fields.len() == 1 => {
+                Some(tuple.fields[0].0.ty.clone())
+            }
+            _ => {
+                return bail!(
+                    &variant.name,
+                    "#[derive(GodotClass)] on enums supports only unit variants and tuple variants with a single field"
+                );
+            }
+        };
+
+        let snake_name = to_snake_case(&variant.name.to_string());
+        if snake_name == "state" {
+            return bail!(
+                &variant.name,
+                "variant `{}` collides with the generated `state` property",
+                variant.name
+            );
+        }
+        if let Some(other) = variants.iter().find(|v| v.snake_name == snake_name) {
+            return bail!(
+                &variant.name,
+                "variants `{}` and `{}` map to the same name `{snake_name}`",
+                other.name,
+                variant.name
+            );
+        }
+
+        variants.push(Variant {
+            name: variant.name.clone(),
+            snake_name,
+            data_ty,
+        });
+    }
+
+    if variants.is_empty() {
+        return bail!(
+            &enum_.name,
+            "#[derive(GodotClass)] requires the enum to have at least one variant"
+        );
+    }
+
+    Ok(variants)
+}
+
+/// Converts a `PascalCase` variant name to `snake_case`.
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+
+    snake
+}
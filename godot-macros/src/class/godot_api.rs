@@ -5,15 +5,15 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Delimiter, Group, Ident, TokenStream};
+use proc_macro2::{Delimiter, Group, Ident, Span, TokenStream, TokenTree};
 use quote::spanned::Spanned;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, quote_spanned};
 
 use crate::class::{
     into_signature_info, make_method_registration, make_virtual_callback, BeforeKind,
     FuncDefinition, SignatureInfo,
 };
-use crate::util::{bail, require_api_version, KvParser};
+use crate::util::{bail, ident, require_api_version, KvParser};
 use crate::{util, ParseResult};
 
 pub fn attribute_godot_api(input_decl: venial::Declaration) -> ParseResult<TokenStream> {
@@ -43,6 +43,32 @@ pub fn attribute_godot_api(input_decl: venial::Declaration) -> ParseResult<Token
     }
 }
 
+/// Handles `#[godot_api(...)]` with arguments; currently only `#[godot_api(mixin)]`.
+pub fn attribute_godot_api_with_meta(input_decl: venial::Declaration) -> ParseResult<TokenStream> {
+    let mut decl = match input_decl {
+        venial::Declaration::Impl(decl) => decl,
+        _ => bail!(
+            input_decl,
+            "#[godot_api] can only be applied on impl blocks",
+        )?,
+    };
+
+    let mut parser = KvParser::parse_required(&decl.attributes, "godot_api", &decl)?;
+    let is_mixin = parser.handle_alone("mixin")?;
+    parser.finish()?;
+
+    decl.attributes.retain(|attr| {
+        attr.get_single_path_segment()
+            .map_or(true, |name| name != "godot_api")
+    });
+
+    if !is_mixin {
+        return bail!(&decl, "#[godot_api] only accepts the key `mixin`");
+    }
+
+    transform_mixin_impl(decl)
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Attribute for user-declared function
@@ -51,6 +77,10 @@ enum ItemAttrType {
         rename: Option<String>,
         is_virtual: bool,
         has_gd_self: bool,
+        /// Whether `#[func(try_mut)]` was used.
+        is_try_mut: bool,
+        /// Type arguments from `#[func(instantiate = [...])]`, for generic methods.
+        instantiate: Option<Vec<Ident>>,
     },
     Signal(venial::AttributeValue),
     Const(#[allow(dead_code)] venial::AttributeValue),
@@ -79,6 +109,9 @@ struct SignalDefinition {
 
     /// The signal's non-gdext attributes (all except #[signal]).
     external_attributes: Vec<venial::Attribute>,
+
+    /// Visibility of the signal declaration, carried over to generated emit methods.
+    vis_marker: Option<venial::VisMarker>,
 }
 
 /// Codegen for `#[godot_api] impl MyType`
@@ -87,8 +120,13 @@ fn transform_inherent_impl(mut original_impl: venial::Impl) -> ParseResult<Token
     let class_name_obj = util::class_name_obj(&class_name);
     let prv = quote! { ::godot::private };
 
+    process_profile_attributes(&class_name, &mut original_impl)?;
     let (funcs, signals, out_virtual_impl) = process_godot_fns(&class_name, &mut original_impl)?;
 
+    let docs = make_inherent_impl_docs(&funcs, &signals);
+    let signal_emitters = make_signal_emitters(&signals, &class_name);
+    let signal_disconnector = make_signal_disconnector(&signals, &class_name);
+    let signal_connectors = make_signal_connectors(&signals, &class_name);
     let signal_registrations = make_signal_registrations(signals, &class_name_obj);
 
     let method_registrations: Vec<TokenStream> = funcs
@@ -99,9 +137,15 @@ fn transform_inherent_impl(mut original_impl: venial::Impl) -> ParseResult<Token
     let constant_registration =
         make_constant_registration(&mut original_impl, &class_name, &class_name_obj)?;
 
+    let (to_string_impl, to_string_fn) = make_inherent_to_string(&original_impl, &class_name)?;
+
     let result = quote! {
         #original_impl
         #out_virtual_impl
+        #to_string_impl
+        #signal_emitters
+        #signal_disconnector
+        #signal_connectors
 
         impl ::godot::obj::cap::ImplementsGodotApi for #class_name {
             fn __register_methods() {
@@ -120,6 +164,8 @@ fn transform_inherent_impl(mut original_impl: venial::Impl) -> ParseResult<Token
                 register_methods_constants_fn: #prv::ErasedRegisterFn {
                     raw: #prv::callbacks::register_user_methods_constants::<#class_name>,
                 },
+                user_to_string_fn: #to_string_fn,
+                docs: #docs,
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
         });
@@ -128,6 +174,400 @@ fn transform_inherent_impl(mut original_impl: venial::Impl) -> ParseResult<Token
     Ok(result)
 }
 
+/// Detects `fn _to_string(&self) -> GString` in an inherent impl, and wires it up as the object's `to_string()` implementation.
+///
+/// Returns the `GodotToString` impl, and the expression for the `user_to_string_fn` plugin field.
+fn make_inherent_to_string(
+    impl_block: &venial::Impl,
+    class_name: &Ident,
+) -> ParseResult<(TokenStream, TokenStream)> {
+    let to_string_method = impl_block.body_items.iter().find_map(|item| match item {
+        venial::ImplMember::Method(function) if function.name == "_to_string" => Some(function),
+        _ => None,
+    });
+
+    let Some(function) = to_string_method else {
+        return Ok((TokenStream::new(), quote! { None }));
+    };
+
+    let has_ref_self = matches!(
+        function.params.inner.as_slice(),
+        [(venial::FnParam::Receiver(receiver), _)] if receiver.tk_ref.is_some() && receiver.tk_mut.is_none()
+    );
+    if !has_ref_self || function.return_ty.is_none() {
+        return bail!(
+            &function.name,
+            "`_to_string` must have the signature `fn _to_string(&self) -> GString`"
+        );
+    }
+
+    let to_string_impl = quote! {
+        impl ::godot::obj::cap::GodotToString for #class_name {
+            fn __godot_to_string(&self) -> ::godot::builtin::GString {
+                Self::_to_string(self)
+            }
+        }
+    };
+
+    let to_string_fn = quote! { Some(::godot::private::callbacks::to_string::<#class_name>) };
+
+    Ok((to_string_impl, to_string_fn))
+}
+
+/// Codegen for `#[godot_api(mixin)] impl MyMixin`.
+///
+/// The mixin is not a class, so its `#[func]` methods cannot be registered here. Instead, a `macro_rules!` macro with the same name
+/// as the mixin type is generated. `#[derive(GodotClass)]` invokes it for each `#[mixin]` field, which adds forwarding methods to the
+/// host class and registers them. Since the macro shares the type's name, importing the type also brings the macro into scope.
+fn transform_mixin_impl(mut original_impl: venial::Impl) -> ParseResult<TokenStream> {
+    if original_impl.trait_ty.is_some() {
+        return bail!(
+            &original_impl,
+            "#[godot_api(mixin)] can only be applied on inherent impl blocks",
+        );
+    }
+
+    let mixin_name = util::validate_impl(&original_impl, None, "godot_api")?;
+
+    // Registration code is generated for a placeholder class, which is substituted with the host class when the macro is invoked.
+    let host_placeholder = ident(MIXIN_HOST_PLACEHOLDER);
+    let (funcs, signals, out_virtual_impl) = process_godot_fns(&mixin_name, &mut original_impl)?;
+
+    if let Some(signal) = signals.first() {
+        return bail!(
+            &signal.signature.name,
+            "#[signal] is not supported in #[godot_api(mixin)]; declare signals on the class",
+        );
+    }
+
+    if let Some(constant) = process_godot_constants(&mut original_impl)?.first() {
+        return bail!(
+            constant,
+            "#[constant] is not supported in #[godot_api(mixin)]; declare constants on the class",
+        );
+    }
+
+    let mut forwarders = Vec::new();
+    let mut registrations = Vec::new();
+    for func_def in funcs {
+        if func_def.is_virtual || func_def.has_gd_self || func_def.type_arg.is_some() {
+            return bail!(
+                &func_def.signature.name,
+                "#[func] in #[godot_api(mixin)] does not support the keys `virtual`, `gd_self` or `instantiate`",
+            );
+        }
+
+        forwarders.push(make_mixin_forwarder(&func_def));
+
+        let registration = make_method_registration(&host_placeholder, func_def)?;
+        registrations.push(substitute_mixin_host(registration));
+    }
+
+    let macro_name = format_ident!("__godot_mixin_{}", mixin_name);
+
+    Ok(quote! {
+        #original_impl
+        #out_virtual_impl
+
+        #[doc(hidden)]
+        macro_rules! #macro_name {
+            ($host:ident, $field:ident, $mixin:ty, $register_fn:ident) => {
+                impl $host {
+                    #( #forwarders )*
+
+                    #[doc(hidden)]
+                    fn $register_fn() {
+                        #( #registrations )*
+                    }
+                }
+            };
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use #macro_name as #mixin_name;
+    })
+}
+
+const MIXIN_HOST_PLACEHOLDER: &str = "__GodotMixinHost";
+
+/// Generates a method on the host class, which forwards to the mixin stored in `$field` (or to the mixin type, for static methods).
+///
+/// Methods of several mixins, or of a mixin and the class itself, with the same name cause a "duplicate definitions" compile error.
+fn make_mixin_forwarder(func_def: &FuncDefinition) -> TokenStream {
+    let signature = &func_def.signature;
+    let method_name = &signature.name;
+    let vis_marker = &signature.vis_marker;
+    let attributes = &func_def.external_attributes;
+    let return_ty = signature.return_ty.as_ref().map(|ty| quote! { -> #ty });
+
+    let mut receiver = None;
+    let mut params = Vec::new();
+    let mut args = Vec::new();
+    for (index, (param, _punct)) in signature.params.inner.iter().enumerate() {
+        match param {
+            venial::FnParam::Receiver(recv) => receiver = Some(recv),
+            venial::FnParam::Typed(param) => {
+                // Parameters named `_` need a name to be forwarded.
+                let name = if param.name == "_" {
+                    format_ident!("__param_{index}")
+                } else {
+                    param.name.clone()
+                };
+                let ty = &param.ty;
+
+                params.push(quote! { #name: #ty });
+                args.push(name);
+            }
+        }
+    }
+
+    let (receiver, call) = match receiver {
+        Some(recv) => (
+            quote! { #recv, },
+            quote! { self.$field.#method_name(#( #args ),*) },
+        ),
+        None => (
+            TokenStream::new(),
+            quote! { <$mixin>::#method_name(#( #args ),*) },
+        ),
+    };
+
+    quote! {
+        #( #attributes )*
+        #vis_marker fn #method_name(#receiver #( #params ),*) #return_ty {
+            #call
+        }
+    }
+}
+
+/// Replaces the placeholder class in generated registration code with the `$host` metavariable of the mixin macro.
+fn substitute_mixin_host(tokens: TokenStream) -> TokenStream {
+    let placeholder_str = format!("\"{MIXIN_HOST_PLACEHOLDER}\"");
+
+    tokens
+        .into_iter()
+        .flat_map(|tt| match tt {
+            TokenTree::Ident(ident) if ident == MIXIN_HOST_PLACEHOLDER => quote! { $host },
+            TokenTree::Literal(lit) if lit.to_string() == placeholder_str => {
+                quote! { stringify!($host) }
+            }
+            TokenTree::Group(group) => {
+                let mut new_group =
+                    Group::new(group.delimiter(), substitute_mixin_host(group.stream()));
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group).into()
+            }
+            tt => tt.into(),
+        })
+        .collect()
+}
+
+/// Collects `///` comments of `#[func]` methods and `#[signal]`s, returning the expression for the `docs` plugin field.
+fn make_inherent_impl_docs(funcs: &[FuncDefinition], signals: &[SignalDefinition]) -> TokenStream {
+    let make_entry = |godot_name: String, attributes: &[venial::Attribute]| {
+        let lines = util::extract_doc_literals(attributes);
+        if lines.is_empty() {
+            return None;
+        }
+
+        Some(quote! {
+            (#godot_name, concat!( #( #lines, "\n", )* ))
+        })
+    };
+
+    let method_docs: Vec<TokenStream> = funcs
+        .iter()
+        .filter(|func| !func.is_hidden_in_editor)
+        .filter_map(|func| {
+            let godot_name = func
+                .rename
+                .clone()
+                .unwrap_or_else(|| func.signature.name.to_string());
+
+            make_entry(godot_name, &func.external_attributes)
+        })
+        .collect();
+
+    let signal_docs: Vec<TokenStream> = signals
+        .iter()
+        .filter_map(|signal| {
+            make_entry(
+                signal.signature.name.to_string(),
+                &signal.external_attributes,
+            )
+        })
+        .collect();
+
+    if method_docs.is_empty() && signal_docs.is_empty() {
+        return quote! { None };
+    }
+
+    quote! {
+        Some(::godot::private::InherentImplDocs {
+            methods: &[ #( #method_docs ),* ],
+            signals: &[ #( #signal_docs ),* ],
+        })
+    }
+}
+
+/// Generates an `emit_<signal>_deferred()` method per signal, which type-checks the arguments on the Rust side.
+fn make_signal_emitters(signals: &[SignalDefinition], class_name: &Ident) -> TokenStream {
+    let emitters = signals.iter().map(|signal| {
+        let SignalDefinition {
+            signature,
+            external_attributes,
+            vis_marker,
+        } = signal;
+
+        let mut params = Vec::new();
+        let mut param_names = Vec::new();
+        for (param, _punct) in signature.params.inner.iter() {
+            if let venial::FnParam::Typed(param) = param {
+                let name = &param.name;
+                let ty = &param.ty;
+                params.push(quote! { #name: #ty });
+                param_names.push(name.clone());
+            }
+        }
+
+        let cfg_attrs: Vec<&venial::Attribute> =
+            util::extract_cfg_attrs(external_attributes)
+                .into_iter()
+                .collect();
+        let signal_name_str = signature.name.to_string();
+        let emit_name = format_ident!("emit_{}_deferred", signature.name);
+        let doc = format!(
+            "Emits the `{signal_name_str}` signal at the end of the current frame, via `call_deferred(\"emit_signal\", ...)`.\n\n\
+            Connected handlers run once the engine is idle, not during this call."
+        );
+
+        quote! {
+            #(#cfg_attrs)*
+            #[doc = #doc]
+            #[allow(dead_code)]
+            #vis_marker fn #emit_name(&self, #(#params),*) {
+                ::godot::private::emit_signal_deferred(
+                    self,
+                    #signal_name_str,
+                    &[ #( ::godot::builtin::meta::ToGodot::to_variant(&#param_names) ),* ],
+                );
+            }
+        }
+    });
+
+    quote! {
+        impl #class_name {
+            #( #emitters )*
+        }
+    }
+}
+
+/// Generates `disconnect_all_signals()`, which disconnects every connection of the signals declared in this impl block.
+fn make_signal_disconnector(signals: &[SignalDefinition], class_name: &Ident) -> TokenStream {
+    if signals.is_empty() {
+        return TokenStream::new();
+    }
+
+    let signal_names = signals.iter().map(|signal| {
+        let cfg_attrs: Vec<&venial::Attribute> =
+            util::extract_cfg_attrs(&signal.external_attributes)
+                .into_iter()
+                .collect();
+        let signal_name_str = signal.signature.name.to_string();
+
+        quote! {
+            #(#cfg_attrs)*
+            #signal_name_str
+        }
+    });
+
+    quote! {
+        impl #class_name {
+            /// Disconnects all connections of this class's signals, e.g. during cleanup in `exit_tree()`.
+            ///
+            /// Signals without connections are left as-is.
+            #[allow(dead_code)]
+            pub fn disconnect_all_signals(&mut self) {
+                ::godot::private::disconnect_all_signals(self, &[ #( #signal_names ),* ]);
+            }
+        }
+    }
+}
+
+/// Generates a `connect_<signal>()` method per signal, which connects a Rust closure with typed parameters.
+///
+/// Only available from Godot 4.2 on, as custom callables are required.
+fn make_signal_connectors(signals: &[SignalDefinition], class_name: &Ident) -> TokenStream {
+    if !cfg!(since_api = "4.2") {
+        return TokenStream::new();
+    }
+
+    let connectors = signals.iter().map(|signal| {
+        let SignalDefinition {
+            signature,
+            external_attributes,
+            vis_marker,
+        } = signal;
+
+        let param_types: Vec<&venial::TyExpr> = signature
+            .params
+            .inner
+            .iter()
+            .filter_map(|(param, _punct)| match param {
+                venial::FnParam::Typed(param) => Some(&param.ty),
+                venial::FnParam::Receiver(_) => None,
+            })
+            .collect();
+        let param_count = param_types.len();
+        let param_indices = 0..param_count;
+        let arg_names: Vec<Ident> = param_indices
+            .clone()
+            .map(|index| format_ident!("arg{index}"))
+            .collect();
+
+        let cfg_attrs: Vec<&venial::Attribute> =
+            util::extract_cfg_attrs(external_attributes)
+                .into_iter()
+                .collect();
+        let signal_name_str = signature.name.to_string();
+        let connect_name = format_ident!("connect_{}", signature.name);
+        let doc = format!(
+            "Connects the `{signal_name_str}` signal to a Rust closure, which receives the signal arguments with their declared types.\n\n\
+            The returned handle disconnects the closure when dropped; call `forget()` on it to keep the connection for the lifetime \
+            of this object. See [`Gd::subscribe()`](::godot::obj::Gd::subscribe) for the threading constraints."
+        );
+
+        // Arity is checked by connect_signal_closure(); mismatched types are reported there instead of panicking inside the engine.
+        quote! {
+            #(#cfg_attrs)*
+            #[doc = #doc]
+            #[allow(dead_code)]
+            #vis_marker fn #connect_name(
+                &self,
+                mut function: impl FnMut( #(#param_types),* ) + Send + Sync + 'static,
+            ) -> ::godot::obj::SignalHandle {
+                ::godot::private::connect_signal_closure(self, #signal_name_str, #param_count, move |__args| {
+                    match ( #( <#param_types as ::godot::builtin::meta::FromGodot>::try_from_variant(__args[#param_indices]), )* ) {
+                        ( #( Ok(#arg_names), )* ) => {
+                            function( #(#arg_names),* );
+                            true
+                        }
+                        #[allow(unreachable_patterns)]
+                        _ => false,
+                    }
+                })
+            }
+        }
+    });
+
+    quote! {
+        impl #class_name {
+            #( #connectors )*
+        }
+    }
+}
+
 fn make_signal_registrations(
     signals: Vec<SignalDefinition>,
     class_name_obj: &TokenStream,
@@ -138,6 +578,7 @@ fn make_signal_registrations(
         let SignalDefinition {
             signature,
             external_attributes,
+            ..
         } = signal;
         let mut param_types: Vec<venial::TyExpr> = Vec::new();
         let mut param_names: Vec<String> = Vec::new();
@@ -152,6 +593,14 @@ fn make_signal_registrations(
             };
         }
 
+        // Check each type separately, so that compile errors point to the parameter rather than generated glue code.
+        let param_checks = param_types.iter().map(|ty| {
+            let span = ty.__span();
+            quote_spanned! { span=>
+                ::godot::private::is_signal_param::<#ty>();
+            }
+        });
+
         let signature_tuple = util::make_signature_tuple_type(&quote! { () }, &param_types);
         let indexes = 0..param_types.len();
         let param_array_decl = quote! {
@@ -178,6 +627,8 @@ fn make_signal_registrations(
             #(#signal_cfg_attrs)*
             unsafe {
                 use ::godot::sys;
+                #( #param_checks )*
+
                 let parameters_info: [::godot::builtin::meta::PropertyInfo; #signal_parameters_count] = #signal_parameters;
 
                 let mut parameters_info_sys: [sys::GDExtensionPropertyInfo; #signal_parameters_count] =
@@ -263,6 +714,7 @@ fn process_godot_fns(
     let mut func_definitions = vec![];
     let mut signal_definitions = vec![];
     let mut virtual_functions = vec![];
+    let mut default_wrappers = vec![];
 
     let mut removed_indexes = vec![];
     for (index, item) in impl_block.body_items.iter_mut().enumerate() {
@@ -270,13 +722,51 @@ fn process_godot_fns(
             continue;
         };
 
+        // #[hide_in_editor] is removed before the other attributes are looked up, so indexes stay valid.
+        let hide_in_editor_index = function.attributes.iter().position(|attr| {
+            attr.get_single_path_segment()
+                .map_or(false, |name| name == "hide_in_editor")
+        });
+        let is_hidden_in_editor = if let Some(index) = hide_in_editor_index {
+            let hide_attr = function.attributes.remove(index);
+            if !matches!(hide_attr.value, venial::AttributeValue::Empty) {
+                return bail!(&hide_attr, "#[hide_in_editor] does not accept arguments");
+            }
+            true
+        } else {
+            false
+        };
+
+        // #[default_param] is removed for the same reason.
+        let default_params = extract_default_params(function)?;
+
         let Some(attr) = extract_attributes(&function, &function.attributes)? else {
+            if is_hidden_in_editor {
+                return bail!(&function.name, "#[hide_in_editor] requires #[func]");
+            }
+            if let Some((span, _)) = default_params {
+                return bail!(span, "#[default_param] requires #[func]");
+            }
             continue;
         };
 
         // Remaining code no longer has attribute -- rest stays
         function.attributes.remove(attr.index);
 
+        if is_hidden_in_editor && !matches!(attr.ty, ItemAttrType::Func { .. }) {
+            return attr.bail(
+                "#[hide_in_editor] can only be combined with #[func]",
+                function,
+            );
+        }
+
+        if default_params.is_some() && !matches!(attr.ty, ItemAttrType::Func { .. }) {
+            return attr.bail(
+                "#[default_param] can only be combined with #[func]",
+                function,
+            );
+        }
+
         if function.qualifiers.tk_default.is_some()
             || function.qualifiers.tk_const.is_some()
             || function.qualifiers.tk_async.is_some()
@@ -287,8 +777,18 @@ fn process_godot_fns(
             return attr.bail("fn qualifiers are not allowed", function);
         }
 
-        if function.generic_params.is_some() {
-            return attr.bail("generic fn parameters are not supported", function);
+        let is_instantiated = matches!(
+            attr.ty,
+            ItemAttrType::Func {
+                instantiate: Some(_),
+                ..
+            }
+        );
+        if function.generic_params.is_some() && !is_instantiated {
+            return attr.bail(
+                "generic fn parameters are only supported with `#[func(instantiate = [...])]`",
+                function,
+            );
         }
 
         match attr.ty {
@@ -296,9 +796,60 @@ fn process_godot_fns(
                 rename,
                 is_virtual,
                 has_gd_self,
+                is_try_mut,
+                instantiate,
             } => {
                 let external_attributes = function.attributes.clone();
 
+                if let Some((span, defaults)) = default_params {
+                    if is_virtual || has_gd_self || instantiate.is_some() {
+                        return bail!(
+                            span,
+                            "#[default_param] cannot be combined with `virtual`, `gd_self` or `instantiate`"
+                        );
+                    }
+
+                    let wrapper = make_default_param_wrapper(function, &defaults);
+                    func_definitions.push(FuncDefinition {
+                        signature: util::reduce_to_signature(&wrapper),
+                        external_attributes: wrapper.attributes.clone(),
+                        rename: rename.as_ref().map(|name| format!("{name}_default")),
+                        is_virtual: false,
+                        has_gd_self: false,
+                        is_hidden_in_editor,
+                        is_try_mut,
+                        type_arg: None,
+                    });
+                    default_wrappers.push(wrapper);
+                }
+
+                // Generic methods are registered once per type argument, see make_generic_instances().
+                if let Some(type_args) = instantiate {
+                    // Instances are registered from their signatures as-is, which would keep the `Gd<Self>` parameter.
+                    if is_virtual || has_gd_self {
+                        return bail_attr(
+                            attr.attr_name,
+                            "`instantiate` cannot be combined with `virtual` or `gd_self`",
+                            function,
+                        );
+                    }
+
+                    let instances = make_generic_instances(function, &rename, type_args)?;
+                    func_definitions.extend(instances.into_iter().map(
+                        |(signature, rename, type_arg)| FuncDefinition {
+                            signature,
+                            external_attributes: external_attributes.clone(),
+                            rename: Some(rename),
+                            is_virtual: false,
+                            has_gd_self,
+                            is_hidden_in_editor,
+                            is_try_mut,
+                            type_arg: Some(type_arg),
+                        },
+                    ));
+                    continue;
+                }
+
                 // Signatures are the same thing without body.
                 let mut signature = util::reduce_to_signature(function);
                 let gd_self_parameter = if has_gd_self {
@@ -344,6 +895,9 @@ fn process_godot_fns(
                     rename,
                     is_virtual,
                     has_gd_self,
+                    is_hidden_in_editor,
+                    is_try_mut,
+                    type_arg: None,
                 });
             }
             ItemAttrType::Signal(ref _attr_val) => {
@@ -352,11 +906,13 @@ fn process_godot_fns(
                 }
 
                 let external_attributes = function.attributes.clone();
+                let vis_marker = function.vis_marker.clone();
                 let sig = util::reduce_to_signature(function);
 
                 signal_definitions.push(SignalDefinition {
                     signature: sig,
                     external_attributes,
+                    vis_marker,
                 });
 
                 removed_indexes.push(index);
@@ -376,12 +932,13 @@ fn process_godot_fns(
         impl_block.body_items.remove(index);
     }
 
-    let out_virtual_impl = if virtual_functions.is_empty() {
+    let out_virtual_impl = if virtual_functions.is_empty() && default_wrappers.is_empty() {
         TokenStream::new()
     } else {
         quote! {
             impl #class_name {
                 #(#virtual_functions)*
+                #(#default_wrappers)*
             }
         }
     };
@@ -389,6 +946,181 @@ fn process_godot_fns(
     Ok((func_definitions, signal_definitions, out_virtual_impl))
 }
 
+/// Expands a generic `#[func(instantiate = [A, B])]` method into one signature per type argument.
+///
+/// Each instance is registered under `<name>_<type>`, where `<name>` is the `rename` if given, or the Rust method name otherwise.
+/// For example, `fn clamp<T>` with `instantiate = [i64, f64]` is registered as `clamp_i64` and `clamp_f64`.
+fn make_generic_instances(
+    function: &venial::Function,
+    rename: &Option<String>,
+    type_args: Vec<Ident>,
+) -> ParseResult<Vec<(venial::Function, String, Ident)>> {
+    let type_param = match function.generic_params.as_ref() {
+        Some(generics) if generics.params.len() == 1 => {
+            let (param, _punct) = &generics.params.inner[0];
+            if param.tk_prefix.is_some() {
+                return bail!(
+                    &function.name,
+                    "#[func(instantiate)] requires a single type parameter, not a lifetime or const parameter"
+                );
+            }
+            param.name.clone()
+        }
+        Some(_) => {
+            return bail!(
+                &function.name,
+                "#[func(instantiate)] requires exactly one type parameter"
+            )
+        }
+        None => {
+            return bail!(
+                &function.name,
+                "#[func(instantiate)] requires a generic method, e.g. `fn method<T: GodotNumeric>(...)`"
+            )
+        }
+    };
+
+    let base_name = rename.clone().unwrap_or_else(|| function.name.to_string());
+
+    let instances = type_args
+        .into_iter()
+        .map(|type_arg| {
+            let mut signature = util::reduce_to_signature(function);
+            signature.generic_params = None;
+            signature.where_clause = None;
+
+            for (param, _punct) in signature.params.inner.iter_mut() {
+                if let venial::FnParam::Typed(param) = param {
+                    param.ty.tokens = substitute_ident(&param.ty.tokens, &type_param, &type_arg);
+                }
+            }
+            if let Some(return_ty) = signature.return_ty.as_mut() {
+                return_ty.tokens = substitute_ident(&return_ty.tokens, &type_param, &type_arg);
+            }
+
+            let godot_name = format!("{base_name}_{type_arg}");
+            (signature, godot_name, type_arg)
+        })
+        .collect();
+
+    Ok(instances)
+}
+
+/// Removes `#[default_param(name = expr, ...)]` from `function`, returning the attribute's span and the default per parameter.
+fn extract_default_params(
+    function: &mut venial::Function,
+) -> ParseResult<Option<(Span, Vec<(Ident, TokenStream)>)>> {
+    let Some(index) = function.attributes.iter().position(|attr| {
+        attr.get_single_path_segment()
+            .map_or(false, |name| name == "default_param")
+    }) else {
+        return Ok(None);
+    };
+
+    let mut parser = KvParser::parse(&function.attributes, "default_param")?.unwrap();
+    let span = parser.span();
+
+    let mut defaults = vec![];
+    for (param, _punct) in function.params.inner.iter() {
+        if let venial::FnParam::Typed(param) = param {
+            if let Some(default) = parser.handle_expr(&param.name.to_string())? {
+                defaults.push((param.name.clone(), default));
+            }
+        }
+    }
+    parser.finish()?;
+
+    if defaults.is_empty() {
+        return bail!(
+            span,
+            "#[default_param] requires at least one `param = value` entry"
+        );
+    }
+
+    function.attributes.remove(index);
+    Ok(Some((span, defaults)))
+}
+
+/// Generates `<name>_default(...)`, which omits the defaulted parameters and calls the full method with their default values.
+fn make_default_param_wrapper(
+    function: &venial::Function,
+    defaults: &[(Ident, TokenStream)],
+) -> venial::Function {
+    let name = &function.name;
+    let wrapper_name = format_ident!("{}_default", name);
+    let cfg_attrs: Vec<&venial::Attribute> = util::extract_cfg_attrs(&function.attributes)
+        .into_iter()
+        .collect();
+
+    let mut receiver = None;
+    let mut params = vec![];
+    let mut args = vec![];
+    for (param, _punct) in function.params.inner.iter() {
+        match param {
+            venial::FnParam::Receiver(param) => receiver = Some(param),
+            venial::FnParam::Typed(param) => {
+                let param_name = &param.name;
+                match defaults.iter().find(|(name, _)| name == param_name) {
+                    Some((_, default)) => args.push(quote! { #default }),
+                    None => {
+                        let ty = &param.ty;
+                        params.push(quote! { #param_name: #ty });
+                        args.push(quote! { #param_name });
+                    }
+                }
+            }
+        }
+    }
+
+    let defaulted_names = defaults
+        .iter()
+        .map(|(name, _)| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let doc = format!(" Calls `{name}()` with default values for {defaulted_names}.");
+
+    let (receiver_param, receiver_arg) = match receiver {
+        Some(receiver) => (quote! { #receiver, }, quote! { self, }),
+        None => (TokenStream::new(), TokenStream::new()),
+    };
+    let return_ty = function.return_ty.as_ref().map(|ty| quote! { -> #ty });
+    let vis_marker = &function.vis_marker;
+
+    let wrapper = quote! {
+        #(#cfg_attrs)*
+        #[doc = #doc]
+        #vis_marker fn #wrapper_name(#receiver_param #(#params),*) #return_ty {
+            Self::#name(#receiver_arg #(#args),*)
+        }
+    };
+
+    venial::parse_declaration(wrapper)
+        .unwrap()
+        .as_function()
+        .unwrap()
+        .clone()
+}
+
+/// Replaces all occurrences of identifier `from` with `to`, also inside groups.
+fn substitute_ident(tokens: &[TokenTree], from: &Ident, to: &Ident) -> Vec<TokenTree> {
+    tokens
+        .iter()
+        .map(|tt| match tt {
+            TokenTree::Ident(ident) if ident == from => TokenTree::Ident(to.clone()),
+            TokenTree::Group(group) => {
+                let inner: Vec<TokenTree> = group.stream().into_iter().collect();
+                let mut new_group = Group::new(
+                    group.delimiter(),
+                    substitute_ident(&inner, from, to).into_iter().collect(),
+                );
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group)
+            }
+            other => other.clone(),
+        })
+        .collect()
+}
+
 fn add_virtual_script_call(
     virtual_functions: &mut Vec<venial::Function>,
     function: &mut venial::Function,
@@ -459,6 +1191,57 @@ fn add_virtual_script_call(
     virtual_functions.push(early_bound_function);
 }
 
+/// Handles `#[profile]` and `#[profile(name = "...")]` on methods, by timing the method body and pushing the result
+/// to a `Performance` custom monitor.
+///
+/// The default monitor name is `ClassName/method_name`.
+fn process_profile_attributes(
+    class_name: &Ident,
+    impl_block: &mut venial::Impl,
+) -> ParseResult<()> {
+    for item in impl_block.body_items.iter_mut() {
+        let venial::ImplMember::Method(function) = item else {
+            continue;
+        };
+
+        let Some(index) = function.attributes.iter().position(|attr| {
+            attr.get_single_path_segment()
+                .map_or(false, |name| name == "profile")
+        }) else {
+            continue;
+        };
+
+        let mut parser = KvParser::parse(&function.attributes, "profile")?.unwrap();
+        require_api_version!("4.2", parser.span(), "#[profile]")?;
+
+        // #[profile(name = "MyClass/my_method")]
+        let monitor_name = match parser.handle_expr("name")? {
+            Some(name) => name,
+            None => {
+                let name = format!("{}/{}", class_name, function.name);
+                quote! { #name }
+            }
+        };
+        parser.finish()?;
+
+        function.attributes.remove(index);
+
+        let Some(body) = &function.body else {
+            return bail!(&function.name, "#[profile] requires a function body");
+        };
+
+        let body_stream = body.stream();
+        let profiled_body = quote! {
+            let __profile_scope = ::godot::private::ProfileScope::new(#monitor_name);
+            #body_stream
+        };
+
+        function.body = Some(Group::new(Delimiter::Brace, profiled_body));
+    }
+
+    Ok(())
+}
+
 fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<venial::Constant>> {
     let mut constant_signatures = vec![];
 
@@ -525,6 +1308,28 @@ where
                 // #[func(gd_self)]
                 let has_gd_self = parser.handle_alone("gd_self")?;
 
+                // #[func(try_mut)]
+                let is_try_mut = parser.handle_alone("try_mut")?;
+
+                // #[func(instantiate = [i64, f64])]
+                let instantiate = if let Some(mut list) = parser.handle_array("instantiate")? {
+                    let mut type_args = vec![];
+                    while let Some(type_arg) = list.next_ident()? {
+                        type_args.push(type_arg);
+                    }
+                    list.finish()?;
+
+                    if type_args.is_empty() {
+                        return bail!(
+                            &error_scope,
+                            "#[func(instantiate)] requires at least one type"
+                        );
+                    }
+                    Some(type_args)
+                } else {
+                    None
+                };
+
                 parser.finish()?;
 
                 ItemAttr {
@@ -534,6 +1339,8 @@ where
                         rename,
                         is_virtual,
                         has_gd_self,
+                        is_try_mut,
+                        instantiate,
                     },
                 }
             }
@@ -600,9 +1407,40 @@ fn convert_to_match_expression_or_none(tokens: Option<TokenStream>) -> TokenStre
     }
 }
 
+/// Warns if a `get_property()`/`set_property()` override may shadow `#[export]` fields, unless it forwards to `dispatch_fn`.
+///
+/// The warning is the deprecation of `__GODOT_SHADOWED_EXPORTS`, which `#[derive(GodotClass)]` only applies when there are
+/// exported fields. The body is only checked for a mention of `dispatch_fn`, not for how it is called.
+fn make_shadowed_exports_check(
+    class_name: &Ident,
+    method: &venial::Function,
+    dispatch_fn: &str,
+) -> TokenStream {
+    fn mentions(tokens: TokenStream, name: &str) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => ident == name,
+            TokenTree::Group(group) => mentions(group.stream(), name),
+            _ => false,
+        })
+    }
+
+    let forwards = method
+        .body
+        .as_ref()
+        .map_or(false, |body| mentions(body.stream(), dispatch_fn));
+    if forwards {
+        return TokenStream::new();
+    }
+
+    quote_spanned! { method.name.span()=>
+        const _: () = #class_name::__GODOT_SHADOWED_EXPORTS;
+    }
+}
+
 /// Codegen for `#[godot_api] impl GodotExt for MyType`
-fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream> {
+fn transform_trait_impl(mut original_impl: venial::Impl) -> ParseResult<TokenStream> {
     let (class_name, trait_path) = util::validate_trait_impl_virtual(&original_impl, "godot_api")?;
+    process_profile_attributes(&class_name, &mut original_impl)?;
     let class_name_obj = util::class_name_obj(&class_name);
 
     let mut godot_init_impl = TokenStream::new();
@@ -611,6 +1449,7 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
     let mut on_notification_impl = TokenStream::new();
     let mut get_property_impl = TokenStream::new();
     let mut set_property_impl = TokenStream::new();
+    let mut validate_property_impl = TokenStream::new();
 
     let mut register_fn = None;
     let mut create_fn = None;
@@ -619,10 +1458,12 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
     let mut on_notification_fn = None;
     let mut get_property_fn = None;
     let mut set_property_fn = None;
+    let mut validate_property_fn = None;
 
     let mut virtual_methods = vec![];
     let mut virtual_method_cfg_attrs = vec![];
     let mut virtual_method_names = vec![];
+    let mut child_tree_hooks = vec![];
 
     let prv = quote! { ::godot::private };
 
@@ -748,6 +1589,8 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
             }
 
             "get_property" => {
+                let shadowed_exports_check =
+                    make_shadowed_exports_check(&class_name, method, "get_exported_property");
                 get_property_impl = quote! {
                     #(#cfg_attrs)*
                     impl ::godot::obj::cap::GodotGet for #class_name {
@@ -762,6 +1605,9 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
                             <Self as #trait_path>::get_property(self, property)
                         }
                     }
+
+                    #(#cfg_attrs)*
+                    #shadowed_exports_check
                 };
 
                 get_property_fn = Some(quote! {
@@ -771,6 +1617,8 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
             }
 
             "set_property" => {
+                let shadowed_exports_check =
+                    make_shadowed_exports_check(&class_name, method, "set_exported_property");
                 set_property_impl = quote! {
                     #(#cfg_attrs)*
                     impl ::godot::obj::cap::GodotSet for #class_name {
@@ -785,6 +1633,9 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
                             <Self as #trait_path>::set_property(self, property, value)
                         }
                     }
+
+                    #(#cfg_attrs)*
+                    #shadowed_exports_check
                 };
 
                 set_property_fn = Some(quote! {
@@ -793,6 +1644,43 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
                 });
             }
 
+            "validate_property" => {
+                validate_property_impl = quote! {
+                    #(#cfg_attrs)*
+                    impl ::godot::obj::cap::GodotUserValidateProperty for #class_name {
+                        fn __godot_user_validate_property(&self, property: &mut ::godot::builtin::meta::PropertyInfo) {
+                            use ::godot::obj::UserClass as _;
+
+                            #[cfg(before_api = "4.3")]
+                            if ::godot::private::is_class_inactive(Self::__config().is_tool) {
+                                return;
+                            }
+
+                            <Self as #trait_path>::validate_property(self, property)
+                        }
+                    }
+                };
+
+                validate_property_fn = Some(quote! {
+                    #(#cfg_attrs)*
+                    () => Some(#prv::callbacks::validate_property_user::<#class_name>),
+                });
+            }
+
+            // Not Godot virtuals, but connected to the node's own signals in ready().
+            "on_child_entered_tree" | "on_child_exiting_tree" => {
+                let method_ident = &method.name;
+                let signal_name = method_name.trim_start_matches("on_");
+                child_tree_hooks.push(quote! {
+                    #(#cfg_attrs)*
+                    #prv::connect_child_tree_hook::<#class_name>(
+                        #prv::Storage::get_gd(storage),
+                        #signal_name,
+                        <#class_name as #trait_path>::#method_ident,
+                    );
+                });
+            }
+
             // Other virtual methods, like ready, process etc.
             _ => {
                 let method = util::reduce_to_signature(method);
@@ -847,7 +1735,13 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
     let virtual_method_callbacks: Vec<TokenStream> = virtual_methods
         .into_iter()
         .map(|(signature_info, before_kind)| {
-            make_virtual_callback(&class_name, signature_info, before_kind)
+            let prelude = if signature_info.method_name == "ready" {
+                quote! { #( #child_tree_hooks )* }
+            } else {
+                TokenStream::new()
+            };
+
+            make_virtual_callback(&class_name, signature_info, before_kind, prelude)
         })
         .collect();
 
@@ -863,6 +1757,7 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
     let on_notification_fn = convert_to_match_expression_or_none(on_notification_fn);
     let get_property_fn = convert_to_match_expression_or_none(get_property_fn);
     let set_property_fn = convert_to_match_expression_or_none(set_property_fn);
+    let validate_property_fn = convert_to_match_expression_or_none(validate_property_fn);
 
     let result = quote! {
         #original_impl
@@ -872,6 +1767,7 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
         #register_class_impl
         #get_property_impl
         #set_property_impl
+        #validate_property_impl
 
         impl ::godot::private::You_forgot_the_attribute__godot_api for #class_name {}
 
@@ -901,6 +1797,7 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
                 user_on_notification_fn: #on_notification_fn,
                 user_set_fn: #set_property_fn,
                 user_get_fn: #get_property_fn,
+                user_validate_property_fn: #validate_property_fn,
                 get_virtual_fn: #prv::callbacks::get_virtual::<#class_name>,
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
@@ -6,6 +6,7 @@
  */
 
 mod derive_godot_class;
+mod derive_state_machine;
 mod godot_api;
 mod data_models {
     pub mod field;
@@ -21,4 +22,5 @@ pub(crate) use data_models::field_var::*;
 pub(crate) use data_models::func::*;
 pub(crate) use data_models::property::*;
 pub(crate) use derive_godot_class::*;
+pub(crate) use derive_state_machine::*;
 pub(crate) use godot_api::*;
@@ -113,7 +113,7 @@ impl CStyleEnumerator {
             _ => {
                 return bail!(
                     &enum_variant.contents,
-                    "GodotConvert only supports c-style enums"
+                    "GodotConvert only supports C-style enums; enums with data variants cannot be directly exported, implement GodotConvert manually"
                 )
             }
         }
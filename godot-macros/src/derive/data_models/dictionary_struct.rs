@@ -0,0 +1,68 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::Ident;
+use venial::TyExpr;
+
+use crate::util::bail;
+use crate::ParseResult;
+
+use super::godot_attribute::RenameRule;
+
+/// Stores info from a struct with named fields, converted to and from a `Dictionary` when deriving `GodotConvert`.
+pub struct DictionaryStruct {
+    /// The fields of the struct, in declaration order.
+    pub fields: Vec<DictionaryField>,
+}
+
+/// A single field of a [`DictionaryStruct`].
+pub struct DictionaryField {
+    /// The name of the field in Rust.
+    pub name: Ident,
+    /// The type of the field.
+    pub ty: TyExpr,
+    /// The key under which the field is stored in the dictionary.
+    pub key: String,
+}
+
+impl DictionaryStruct {
+    /// Parses a struct with named fields.
+    ///
+    /// Dictionary keys are the field names, transformed by `rename_all` if present.
+    pub fn parse_struct(
+        struct_: &venial::Struct,
+        rename_all: Option<RenameRule>,
+    ) -> ParseResult<DictionaryStruct> {
+        let venial::StructFields::Named(named_fields) = &struct_.fields else {
+            return bail!(
+                &struct_.fields,
+                "`#[godot(dictionary)]` expects a struct with named fields"
+            );
+        };
+
+        let fields = named_fields
+            .fields
+            .inner
+            .iter()
+            .map(|(field, _punct)| {
+                let name = field.name.clone();
+                let key = match rename_all {
+                    Some(rule) => rule.apply(&name.to_string()),
+                    None => name.to_string(),
+                };
+
+                DictionaryField {
+                    name,
+                    ty: field.ty.clone(),
+                    key,
+                }
+            })
+            .collect();
+
+        Ok(DictionaryStruct { fields })
+    }
+}
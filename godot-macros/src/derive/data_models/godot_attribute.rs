@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::ToTokens;
 use venial::Declaration;
 
@@ -18,6 +18,11 @@ pub enum GodotAttribute {
     Transparent { span: Span },
     /// `#[godot(via = via_type)]`
     Via { span: Span, via_type: ViaType },
+    /// `#[godot(dictionary)]` or `#[godot(dictionary, rename_all = "...")]`
+    Dictionary {
+        span: Span,
+        rename_all: Option<RenameRule>,
+    },
 }
 
 impl GodotAttribute {
@@ -43,9 +48,18 @@ impl GodotAttribute {
             });
         }
 
+        if parser.handle_alone("dictionary")? {
+            let rename_all = parser
+                .handle_expr("rename_all")?
+                .map(RenameRule::parse_expr)
+                .transpose()?;
+
+            return Ok(Self::Dictionary { span, rename_all });
+        }
+
         bail!(
             span,
-            "expected either `#[godot(transparent)]` or `#[godot(via = <via_type>)]`"
+            "expected one of `#[godot(transparent)]`, `#[godot(via = <via_type>)]` or `#[godot(dictionary)]`"
         )
     }
 
@@ -56,6 +70,7 @@ impl GodotAttribute {
         match self {
             GodotAttribute::Transparent { span } => *span,
             GodotAttribute::Via { span, .. } => *span,
+            GodotAttribute::Dictionary { span, .. } => *span,
         }
     }
 }
@@ -88,3 +103,65 @@ impl ToTokens for ViaType {
         }
     }
 }
+
+/// The naming convention from a `#[godot(dictionary, rename_all = "...")]` attribute.
+///
+/// Field names are assumed to be `snake_case`, as is idiomatic in Rust.
+#[derive(Copy, Clone)]
+pub enum RenameRule {
+    /// `"camelCase"`
+    CamelCase,
+    /// `"PascalCase"`
+    PascalCase,
+    /// `"snake_case"`
+    SnakeCase,
+    /// `"SCREAMING_SNAKE_CASE"`
+    ScreamingSnakeCase,
+}
+
+impl RenameRule {
+    fn parse_expr(expr: TokenStream) -> ParseResult<Self> {
+        let mut tokens = expr.clone().into_iter();
+        let (Some(TokenTree::Literal(literal)), None) = (tokens.next(), tokens.next()) else {
+            return bail!(
+                expr,
+                "expected `rename_all` to be followed by a string literal"
+            );
+        };
+
+        let rule = match literal.to_string().trim_matches('"') {
+            "camelCase" => RenameRule::CamelCase,
+            "PascalCase" => RenameRule::PascalCase,
+            "snake_case" => RenameRule::SnakeCase,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnakeCase,
+            other => return bail!(literal, "Rename rule `{other}` is not supported, expected one of: \"camelCase\", \"PascalCase\", \"snake_case\", \"SCREAMING_SNAKE_CASE\""),
+        };
+
+        Ok(rule)
+    }
+
+    /// Applies the rule to a `snake_case` field name.
+    pub fn apply(self, field_name: &str) -> String {
+        match self {
+            RenameRule::SnakeCase => field_name.to_string(),
+            RenameRule::ScreamingSnakeCase => field_name.to_ascii_uppercase(),
+            RenameRule::PascalCase | RenameRule::CamelCase => {
+                let mut result = String::with_capacity(field_name.len());
+                let mut capitalize_next = matches!(self, RenameRule::PascalCase);
+
+                for c in field_name.chars() {
+                    if c == '_' {
+                        capitalize_next = true;
+                    } else if capitalize_next {
+                        result.push(c.to_ascii_uppercase());
+                        capitalize_next = false;
+                    } else {
+                        result.push(c);
+                    }
+                }
+
+                result
+            }
+        }
+    }
+}
@@ -6,13 +6,14 @@
  */
 
 use proc_macro2::{Ident, TokenStream};
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 use venial::Declaration;
 
 use crate::util::bail;
 use crate::ParseResult;
 
 use super::c_style_enum::CStyleEnum;
+use super::dictionary_struct::DictionaryStruct;
 use super::godot_attribute::{GodotAttribute, ViaType};
 use super::newtype::NewtypeStruct;
 
@@ -71,6 +72,8 @@ pub enum ConvertType {
     NewType { field: NewtypeStruct },
     /// Deriving for an enum.
     Enum { variants: CStyleEnum, via: ViaType },
+    /// Deriving for a struct with named fields, converted to a `Dictionary`.
+    Dictionary { fields: DictionaryStruct },
 }
 
 impl ConvertType {
@@ -78,15 +81,18 @@ impl ConvertType {
         let attribute = GodotAttribute::parse_attribute(&declaration)?;
 
         match &declaration {
-            Declaration::Struct(struct_) => {
-                let GodotAttribute::Transparent { .. } = attribute else {
-                    return bail!(attribute.span(), "`GodotConvert` on structs only works with `#[godot(transparent)]` currently");
-                };
-
-                Ok(Self::NewType {
+            Declaration::Struct(struct_) => match attribute {
+                GodotAttribute::Transparent { .. } => Ok(Self::NewType {
                     field: NewtypeStruct::parse_struct(struct_)?,
-                })
-            }
+                }),
+                GodotAttribute::Dictionary { rename_all, .. } => Ok(Self::Dictionary {
+                    fields: DictionaryStruct::parse_struct(struct_, rename_all)?,
+                }),
+                GodotAttribute::Via { span, .. } => bail!(
+                    span,
+                    "`GodotConvert` on structs only works with `#[godot(transparent)]` or `#[godot(dictionary)]` currently"
+                ),
+            },
             Declaration::Enum(enum_) => {
                 let GodotAttribute::Via { via_type, .. } = attribute else {
                     return bail!(
@@ -112,6 +118,7 @@ impl ConvertType {
         match self {
             ConvertType::NewType { field } => field.ty.to_token_stream(),
             ConvertType::Enum { via, .. } => via.to_token_stream(),
+            ConvertType::Dictionary { .. } => quote! { ::godot::builtin::Dictionary },
         }
     }
 }
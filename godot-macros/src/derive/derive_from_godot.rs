@@ -8,7 +8,9 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, ConvertType, DictionaryStruct, GodotConvert, NewtypeStruct, ViaType,
+};
 
 /// Creates a `FromGodot` impl for the given `GodotConvert`.
 ///
@@ -29,6 +31,7 @@ pub fn make_fromgodot(convert: &GodotConvert) -> TokenStream {
             variants,
             via: ViaType::Int { int_ident },
         } => make_fromgodot_for_int_enum(name, variants, int_ident),
+        ConvertType::Dictionary { fields } => make_fromgodot_for_dictionary_struct(name, fields),
     }
 }
 
@@ -87,3 +90,33 @@ fn make_fromgodot_for_gstring_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStr
         }
     }
 }
+
+/// Derives `FromGodot` for structs converted from a `Dictionary`.
+///
+/// Fails if a key is missing or a value cannot be converted to the field's type. Extra keys are ignored.
+fn make_fromgodot_for_dictionary_struct(name: &Ident, struct_: &DictionaryStruct) -> TokenStream {
+    let field_names = struct_.fields.iter().map(|field| &field.name);
+    let field_types = struct_.fields.iter().map(|field| &field.ty);
+    let keys = struct_.fields.iter().map(|field| &field.key);
+    let missing_key_errors = struct_
+        .fields
+        .iter()
+        .map(|field| format!("missing key `{}` in dictionary for {name}", field.key));
+
+    quote! {
+        impl ::godot::builtin::meta::FromGodot for #name {
+            fn try_from_godot(via: ::godot::builtin::Dictionary) -> ::std::result::Result<Self, ::godot::builtin::meta::ConvertError> {
+                Ok(Self {
+                    #(
+                        #field_names: {
+                            let value = via.get(#keys).ok_or_else(|| {
+                                ::godot::builtin::meta::ConvertError::with_cause_value(#missing_key_errors, &via)
+                            })?;
+                            <#field_types as ::godot::builtin::meta::FromGodot>::try_from_variant(&value)?
+                        },
+                    )*
+                })
+            }
+        }
+    }
+}
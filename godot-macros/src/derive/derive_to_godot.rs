@@ -8,7 +8,9 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, ConvertType, DictionaryStruct, GodotConvert, NewtypeStruct, ViaType,
+};
 
 /// Creates a `ToGodot` impl for the given `GodotConvert`.
 ///
@@ -29,6 +31,7 @@ pub fn make_togodot(convert: &GodotConvert) -> TokenStream {
             variants,
             via: ViaType::Int { int_ident },
         } => make_togodot_for_int_enum(name, variants, int_ident),
+        ConvertType::Dictionary { fields } => make_togodot_for_dictionary_struct(name, fields),
     }
 }
 
@@ -85,3 +88,23 @@ fn make_togodot_for_string_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStream
         }
     }
 }
+
+/// Derives `ToGodot` for structs converted to a `Dictionary`.
+///
+/// Each field is converted to a `Variant`, so nested structs deriving `GodotConvert` become nested dictionaries.
+fn make_togodot_for_dictionary_struct(name: &Ident, struct_: &DictionaryStruct) -> TokenStream {
+    let field_names = struct_.fields.iter().map(|field| &field.name);
+    let keys = struct_.fields.iter().map(|field| &field.key);
+
+    quote! {
+        impl ::godot::builtin::meta::ToGodot for #name {
+            fn to_godot(&self) -> ::godot::builtin::Dictionary {
+                let mut dict = ::godot::builtin::Dictionary::new();
+                #(
+                    dict.set(#keys, ::godot::builtin::meta::ToGodot::to_variant(&self.#field_names));
+                )*
+                dict
+            }
+        }
+    }
+}
@@ -43,6 +43,7 @@ pub fn derive_var(declaration: Declaration) -> ParseResult<TokenStream> {
 /// Make an appropriate property hint implementation.
 ///
 /// For newtype structs we just defer to the wrapped type. For enums we use `PropertyHint::ENUM` with an appropriate hint string.
+/// For dictionary structs we defer to `Dictionary`.
 fn create_property_hint_impl(convert: &GodotConvert) -> TokenStream {
     use super::data_models::ConvertType as Data;
     use super::data_models::ViaType;
@@ -67,5 +68,8 @@ fn create_property_hint_impl(convert: &GodotConvert) -> TokenStream {
                 }
             }
         }
+        Data::Dictionary { .. } => quote! {
+            <::godot::builtin::Dictionary as ::godot::register::property::Var>::property_hint()
+        },
     }
 }
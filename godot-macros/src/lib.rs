@@ -20,7 +20,7 @@ use venial::Declaration;
 use crate::util::ident;
 
 // Below intra-doc link to the trait only works as HTML, not as symbol link.
-/// Derive macro for [`GodotClass`](../obj/trait.GodotClass.html) on structs.
+/// Derive macro for [`GodotClass`](../obj/trait.GodotClass.html) on structs and enums.
 ///
 /// You should use this macro; manual implementations of the `GodotClass` trait are not encouraged.
 ///
@@ -43,6 +43,7 @@ use crate::util::ident;
 ///    - [Class hiding](#class-hiding)
 /// - [Further field customization](#further-field-customization)
 ///    - [Fine-grained inference hints](#fine-grained-inference-hints)
+/// - [Enums as state machines](#enums-as-state-machines)
 ///
 ///
 /// # Construction
@@ -91,6 +92,29 @@ use crate::util::ident;
 /// # }
 /// ```
 ///
+/// Default expressions are evaluated in the order the fields are declared, with the `Base<T>` field taking its declared position
+/// as well. Side effects of these expressions thus happen in a well-defined sequence.
+///
+/// With `#[class(resettable)]`, a method `reset_to_defaults(&mut self)` is generated, which assigns every field except the base
+/// its value from the generated `init` again -- i.e. the `#[init(default)]` expression or `Default::default()`. This is useful for
+/// "reset" buttons or object pooling. The `after_init` method is not re-run. Fields of type `OnReady<T>` and `OnEditor<T>` are skipped,
+/// since they are not initialized in `init`.
+///
+/// ```
+/// # use godot_macros::GodotClass;
+/// #[derive(GodotClass)]
+/// #[class(init, resettable)]
+/// struct Bullet {
+///     #[init(default = 10)]
+///     damage: i64,
+///     bounces: u32,
+/// }
+///
+/// fn recycle(bullet: &mut Bullet) {
+///     bullet.reset_to_defaults(); // damage = 10, bounces = 0
+/// }
+/// ```
+///
 /// You can also _disable_ construction from GDScript. This needs to be explicit via `#[class(no_init)]`.
 /// Simply omitting the `init`/`no_init` keys and not overriding your own constructor will cause a compile error.
 ///
@@ -122,6 +146,15 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// The base must be a class in the `godot::engine` module. Other names are reported at the `base` key:
+///
+/// ```compile_fail,E0412
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=NonExistentNode)] // error: cannot find type `NonExistentNode`
+/// struct MyStruct {}
+/// ```
+///
 /// If you need a reference to the base class, you can add a field of type `Base<T>`. The derive macro will pick this up and wire
 /// your object accordingly. You can access it through `self.base()` and `self.base_mut()` methods.
 ///
@@ -281,6 +314,10 @@ use crate::util::ident;
 ///
 /// ```
 ///
+/// Enum variants can also be listed as string literals in square brackets, e.g. `#[export(enum = ["Fire Ball", "Ice Spear"])]`.
+/// This allows names that are not valid Rust identifiers; the values are numbered from 0. Since procedural macros cannot read
+/// the values of constants, the strings must be written inline.
+///
 /// Most values in expressions like `key = value`, can be an arbitrary expression that evaluates to the
 /// right value. Meaning you can use constants or variables, as well as any other rust syntax you'd like in
 /// the export attributes.
@@ -300,6 +337,391 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// For tooling that deals with raw engine handles, integer fields can be exported with the advanced hints
+/// `INT_IS_OBJECTID` and `INT_IS_POINTER`. These have no GDScript annotation; they are only accepted on fields that
+/// Godot sees as integers.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[export(hint = INT_IS_OBJECTID)]
+///     watched_object: i64,
+///
+///     #[export(hint = INT_IS_POINTER)]
+///     native_handle: i64,
+/// }
+/// ```
+///
+/// String fields holding secrets, such as API keys in editor plugins, can use `#[export(secret)]`. This sets the `PASSWORD` hint:
+/// in the inspector, the value is edited in a line edit that shows every character as a bullet. This behaves the same in all Godot
+/// versions supported by gdext, since the hint exists as of Godot 4.0.
+/// Only the display is obscured -- the value is still saved in plain text in scene and resource files. The field must be passed to
+/// Godot as a string (e.g. `GString`); other types cause a compile error.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[export(secret)]
+///     api_key: GString,
+/// }
+/// ```
+///
+/// As an escape hatch for hints without a dedicated key, `#[export(custom_type_hint = HINT, hint_string = expr)]` passes any
+/// [`PropertyHint`](../engine/global/struct.PropertyHint.html) and hint string through to Godot verbatim, bypassing type inference.
+/// The hint may be spelled with or without the `PROPERTY_HINT_` prefix; `hint_string` is optional. Nothing is validated, so the
+/// hint string must follow the format Godot expects for that hint.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[export(custom_type_hint = PROPERTY_HINT_NODE_PATH_VALID_TYPES, hint_string = "Node2D,CollisionShape2D")]
+///     target: NodePath,
+///
+///     #[export(custom_type_hint = EXPRESSION)]
+///     formula: GString,
+/// }
+/// ```
+///
+/// Plain `#[export]` on resource fields registers the `RESOURCE_TYPE` hint with the field's class, so the inspector only accepts
+/// matching resources. For textures like `Gd<Texture2D>`, it also shows a thumbnail preview. Prefer `Option<Gd<T>>`, since the
+/// inspector can clear the field; clearing a non-`Option` field panics.
+///
+/// Resource fields (`Gd<T>` or `Option<Gd<T>>` with `T` inheriting `Resource`) accept `#[export(new_resource)]`. Besides the usual
+/// `RESOURCE_TYPE` hint, which enables the inspector's "New ..." dropdown, this adds the usage flag `EDITOR_INSTANTIATE_OBJECT`:
+/// when the owning node is created in the editor, a new instance of the resource is assigned automatically. Using the key on other
+/// field types is a compile error.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init, base=Node)]
+/// struct MyStruct {
+///     #[export(new_resource)]
+///     stats: Option<Gd<Resource>>,
+/// }
+/// ```
+///
+/// To restrict the node picker of a `NodePath` field to certain node types, list them with `#[export(node_path, types = [...])]`.
+/// This corresponds to GDScript's `@export_node_path`. The types must be Godot classes (engine or user-defined); their class names
+/// are used in the hint string.
+///
+/// ```
+/// # use godot::prelude::*;
+/// # use godot::engine::{Area3D, CharacterBody3D};
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[export(node_path, types = [CharacterBody3D, Area3D])]
+///     target: NodePath,
+/// }
+/// ```
+///
+/// An untyped `Array` field can still be restricted to one element type in the editor, with `#[export(element = Type)]`. This sets the
+/// `ARRAY_TYPE` hint, as for a typed `Array<Type>`. The field keeps holding a `VariantArray`, so Rust code can still store other
+/// elements. The element type must be convertible to `Variant`, and the field must be an untyped array; anything else is a compile
+/// error. Since the editor assigns typed arrays, the generated setter takes a `Variant` and copies any array into an untyped one.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[export(element = i64)]
+///     scores: VariantArray,
+///
+///     #[export(element = Gd<Node>)]
+///     targets: VariantArray,
+/// }
+/// ```
+///
+/// Since Godot 4.4, dictionaries can be typed. `#[export(dict = "Key:Value")]` on a `Dictionary` field sets the `DICTIONARY_TYPE` hint,
+/// with Godot type names for key and value (e.g. `int`, `String`, or a class name). The inspector then only accepts entries of these
+/// types. On older Godot versions, the key is a compile error; using it on other field types is a compile error as well.
+///
+/// ```
+/// # #[cfg(since_api = "4.4")]
+/// # mod conditional {
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[export(dict = "String:int")]
+///     scores: Dictionary,
+/// }
+/// # }
+/// ```
+///
+/// For a value bounded by another property, `#[export(range_max_from = "field")]` uses the current value of `field` as the maximum
+/// of the range slider shown in the editor. The referenced field must have a numeric type. It can be combined with
+/// `#[export(range = (...))]` to specify the minimum, step and further options; the static maximum is then only used as a fallback.
+/// Without `range`, the minimum is 0. Note that Godot does not clamp the value itself, only the editor's slider is adjusted.
+///
+/// ```
+/// # #[cfg(since_api = "4.2")]
+/// # mod conditional {
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[export]
+///     max_health: f64,
+///
+///     // Slider from 0 to the current `max_health`.
+///     #[export(range = (0.0, 100.0, 0.5), range_max_from = "max_health")]
+///     health: f64,
+/// }
+/// # }
+/// ```
+///
+/// This requires at least Godot 4.2.
+///
+/// Computed or status values that designers should see but not change can be marked `#[export(read_only)]`. The property is shown in
+/// the inspector, but cannot be edited there; code can still assign it. The key can be combined with any hint, including
+/// `range_max_from`. Like the latter, it is applied in the generated `_validate_property`, before a user-defined
+/// `validate_property()` runs. This requires at least Godot 4.2.
+///
+/// ```
+/// # #[cfg(since_api = "4.2")]
+/// # mod conditional {
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[export(read_only)]
+///     kills: i32,
+/// }
+/// # }
+/// ```
+///
+/// In classes that inherit `Resource` and have a `Base<T>` field, setting an `#[export]` field through Godot (e.g. the inspector or
+/// `Object::set()`) emits the resource's `changed` signal after assignment, so that objects using the resource can update.
+/// Fields marked `#[export(no_emit_changed)]` skip this, as do custom setters from `#[var(set = ...)]`.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Resource)]
+/// struct WeaponStats {
+///     #[export]
+///     damage: i32, // emits `changed` when set
+///
+///     #[export(no_emit_changed)]
+///     notes: GString,
+///
+///     base: Base<Resource>,
+/// }
+/// ```
+///
+/// `#[export(default = expr)]` declares the value that the inspector's "Reset to default" button restores. The property is then
+/// reported as revertible (Godot's `_property_can_revert` and `_property_get_revert`). Unless the field also has `#[init(default = ...)]`,
+/// the generated constructor uses the same value. The key can be combined with any of the hints above.
+///
+/// Exported fields without `#[export(default)]` but with `#[init(default = ...)]` are revertible as well, using the init value. Since
+/// the revert value is recomputed whenever the editor queries it, this init value must be a literal or constant, such as `42`, `-1.5`
+/// or `Vector2::ZERO`. Other expressions, e.g. `GString::from("name")`, are a compile error; declare them with `#[export(default)]`,
+/// which is evaluated for every query as well.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct MyStruct {
+///     // Starts at 3 and can be reset to 3 in the editor.
+///     #[export(default = 3)]
+///     lives: i32,
+///
+///     #[export(range = (0.0, 10.0), default = 1.5)]
+///     speed: f64,
+///
+///     #[export(default = Vector2::new(1.0, 2.0))]
+///     offset: Vector2,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct MyStruct {
+///     #[export]
+///     #[init(default = Vector2::new(1.0, 2.0))] // error: use #[export(default = ...)]
+///     offset: Vector2,
+/// }
+/// ```
+///
+/// The inspector lists properties in declaration order. `#[export(order = N)]` places a property at position `N` instead, where
+/// properties without `order` keep their index among the `#[var]` and `#[export]` fields (starting at 0); other fields are not
+/// counted. Ties are resolved by declaration order.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     // Shown last.
+///     #[export(order = 5)]
+///     notes: GString,
+///
+///     #[export]
+///     name: GString,
+///
+///     #[export]
+///     id: i64,
+/// }
+/// ```
+///
+/// `#[export(tooltip = "...")]` sets the text shown when hovering the property in the inspector. Godot takes inspector tooltips
+/// from the class documentation, so the tooltip is registered as the property's description in the editor help, in place of the
+/// field's `///` doc comment (which still documents the field in Rust). The key can be combined with any of the hints above.
+///
+/// This requires at least Godot 4.3, like [editor documentation](attr.godot_api.html#editor-documentation). On earlier versions,
+/// the key is a compile error, as there is no other way to register a tooltip.
+///
+/// ```
+/// # #[cfg(since_api = "4.3")]
+/// # mod conditional {
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     /// Speed in meters per second, before modifiers are applied.
+///     #[export(range = (0.0, 10.0), tooltip = "Controls movement speed")]
+///     speed: f64,
+/// }
+/// # }
+/// ```
+///
+/// Object references that must be assigned in the inspector can be declared as [`OnEditor<T>`](../obj/struct.OnEditor.html), e.g.
+/// `OnEditor<Gd<Node>>`. They are exported like `Option<T>`, but used like `T`; before `ready()`, an unassigned field is reported as
+/// a Godot error. `OnEditor<T>` fields require `#[export]`.
+///
+/// For pure data classes, `#[class(export_all)]` exports every field as if it had a plain `#[export]` attribute, with the property
+/// hint inferred from the field's type. Fields can still use `#[export(...)]` to customize the hint, or opt out with `#[no_export]`.
+/// The base field and `OnReady<T>` fields are never exported.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, export_all)]
+/// struct EnemyStats {
+///     // @export
+///     speed: f64,
+///
+///     // @export_range(0, 100)
+///     #[export(range = (0.0, 100.0))]
+///     health: f64,
+///
+///     // Not exported.
+///     #[no_export]
+///     cached_path_length: f64,
+/// }
+/// ```
+///
+/// ## Multiplayer replication
+///
+/// Properties of a node can be replicated over the network by annotating them with `#[remote_sync]`, in addition to `#[var]` or
+/// `#[export]`. When the node becomes ready, a `MultiplayerSynchronizer` child named `RemoteSynchronizer` is added (deferred, at the
+/// end of the frame), whose replication config contains all such properties. This requires Godot 4.2 and a `Base<T>` field.
+///
+/// `replication_mode` is one of `always` (the default), `on_change` or `never`, corresponding to `SceneReplicationConfig`'s
+/// replication modes. Properties are also sent when the node is spawned, unless the key `no_spawn` is given. Godot's replication
+/// has no interpolation, so `interpolated` is rejected.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base = Node3D)]
+/// struct Player {
+///     #[var]
+///     #[remote_sync]
+///     health: i32,
+///
+///     #[export]
+///     #[remote_sync(replication_mode = on_change, no_spawn)]
+///     nickname: GString,
+///
+///     base: Base<Node3D>,
+/// }
+/// ```
+///
+/// ## Typed property access
+///
+/// With `#[class(typed_accessors)]`, associated functions `property_get_<field>(&Gd<Self>)` and
+/// `property_set_<field>(&mut Gd<Self>, value)` are generated for every `#[export]` field. They go through Godot's `Object::get()`/`Object::set()`, so registered getters and setters are invoked, but with static
+/// types instead of `Variant` and string names. Values use the field's Godot representation, i.e. [`GodotConvert::Via`](../builtin/meta/trait.GodotConvert.html#associatedtype.Via).
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, typed_accessors)]
+/// struct Enemy {
+///     #[export]
+///     health: i64,
+/// }
+///
+/// fn heal(mut enemy: Gd<Enemy>) {
+///     let health = Enemy::property_get_health(&enemy);
+///     Enemy::property_set_health(&mut enemy, health + 10);
+/// }
+/// ```
+///
+/// Like every call into Godot, these functions bind the object internally. Prefer direct field access through
+/// [`Gd::bind()`](../obj/struct.Gd.html#method.bind) or `bind_mut()` when you already hold a `Gd` and no borrow is active.
+/// The typed accessors are useful when you want Godot's view of the property, e.g. to run custom `#[var(get, set)]` functions,
+/// or when code is written against the property interface rather than the Rust struct.
+///
+/// ## Dynamic property access
+///
+/// Godot calls the `get_property()` and `set_property()` virtual methods (`_get` and `_set`) before the getters and setters of
+/// exported fields, so an override of these may shadow `#[export]` fields. With `#[class(export_dispatch)]`, the methods
+/// `get_exported_property(&self, &StringName) -> Option<Variant>` and `set_exported_property(&mut self, &StringName, &Variant) -> bool`
+/// are generated, which access the exported field of the given name directly. Overrides should forward properties they don't handle
+/// to them; in a class with `#[export]` fields, an override that doesn't is reported with a deprecation warning.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, export_dispatch)]
+/// struct Vehicle {
+///     #[export]
+///     speed: f64,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Vehicle {
+///     fn get_property(&self, property: StringName) -> Option<Variant> {
+///         match property.to_string().as_str() {
+///             "speed_kmh" => Some((self.speed * 3.6).to_variant()),
+///             _ => self.get_exported_property(&property),
+///         }
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # #![deny(deprecated)]
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Vehicle {
+///     #[export]
+///     speed: f64,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Vehicle {
+///     fn get_property(&self, property: StringName) -> Option<Variant> { // warning: may shadow #[export] fields
+///         None
+///     }
+/// }
+/// ```
+///
 /// You can specify custom property hints, hint strings, and usage flags in a `#[var]` attribute using the
 /// `hint`, `hint_string`, and `usage_flags` keys in the attribute. These are constants in the `PropertyHint`
 /// and `PropertyUsageFlags` enums, respectively.
@@ -321,6 +743,30 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// ## Property introspection
+///
+/// With `#[class(introspect)]`, an associated function `exported_property_infos() -> Vec<PropertyInfo>` is generated. It
+/// returns name, type, hint and usage of every `#[export]` property, in registration order. This lets Rust code, e.g. generic
+/// serializers or editor tooling, inspect the properties without a round-trip through `ClassDB`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, introspect)]
+/// struct Enemy {
+///     #[export(range = (0.0, 100.0))]
+///     health: f64,
+///     #[export]
+///     name: GString,
+/// }
+///
+/// fn print_properties() {
+///     for info in Enemy::exported_property_infos() {
+///         godot_print!("{}: {:?}", info.property_name, info.variant_type);
+///     }
+/// }
+/// ```
+///
 /// # Signals
 ///
 /// The `#[signal]` attribute is quite limited at the moment. The functions it decorates (the signals) can accept parameters.
@@ -342,6 +788,45 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// For each signal, an `emit_<signal>_deferred()` method with the same parameters is generated, e.g.
+/// `emit_some_signal_with_parameters_deferred(node)`. It queues the emission through `call_deferred("emit_signal", ...)`, so
+/// connected handlers run at the next idle time (the end of the current frame), not during the call. Argument types are checked
+/// by the Rust compiler. The class needs a `Base<T>` field for this; otherwise, the method panics.
+///
+/// Additionally, `disconnect_all_signals(&mut self)` disconnects every connection of the signals declared in the impl block, which
+/// is handy for cleanup in `exit_tree()`. Signals without connections are skipped. It also requires a `Base<T>` field.
+///
+/// Since Godot 4.2, a `connect_<signal>()` method is generated as well, which connects a Rust closure taking the signal's parameters
+/// with their declared types. No named `#[func]` is needed for the handler. The closure may mutate its captured state, and it must be
+/// `Send + Sync + 'static`, as Godot may invoke it from other threads. It returns a [`SignalHandle`](../obj/struct.SignalHandle.html),
+/// which disconnects the closure when dropped. Call `forget()` on the handle to keep the connection for as long as the object lives.
+/// The closure is released together with the connection, at the latest when the object is freed. Arguments of unexpected types are
+/// reported as errors and do not invoke the closure.
+///
+/// ```no_run
+/// # #[cfg(since_api = "4.2")]
+/// # mod conditional {
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Door {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Door {
+///     #[signal]
+///     fn opened(by: GString);
+/// }
+///
+/// fn watch(door: &Gd<Door>) {
+///     door.bind()
+///         .connect_opened(|by| godot_print!("opened by {by}"))
+///         .forget();
+/// }
+/// # }
+/// ```
+///
 /// # Further class customization
 ///
 /// ## Running code in the editor
@@ -392,19 +877,161 @@ use crate::util::ident;
 ///
 /// These classes will appear in the Godot editor and GDScript as "AnimalToad" or "NpcToad".
 ///
+/// The same applies to names of Godot's own classes: a class registered as e.g. `Node` or `Timer` would collide with the engine class.
+/// For common engine classes, this is a compile error; `rename` resolves it.
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Timer {} // error: 'Timer' is a built-in Godot class name
+/// ```
+///
 /// ## Class hiding
 ///
 /// If you want to register a class with Godot, but not have it show up in the editor then you can use `#[class(hidden)]`.
 ///
-/// ```
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(base=Node, init, hidden)]
+/// pub struct Foo {}
+/// ```
+///
+/// Even though this class is a `Node` and it has an init function, it still won't show up in the editor as a node you can add to a scene
+/// because we have added a `hide` key to the class. This will also prevent it from showing up in documentation.
+///
+/// ## Safe `queue_free`
+///
+/// For `Node`-derived classes with a `Base<T>` field, `#[class(queue_free_safe)]` generates an inherent method
+/// `queue_free_safe(&mut self)`. It calls [`Node::queue_free()`](../engine/struct.Node.html#method.queue_free) only if:
+/// - the node is inside the scene tree (`is_inside_tree()`), and
+/// - the node has not already been queued for deletion (`is_queued_for_deletion()`).
+///
+/// Otherwise, it does nothing. This avoids redundant calls in cleanup code, e.g. when several code paths may free the same node.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, queue_free_safe)]
+/// struct Projectile {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Projectile {
+///     #[func]
+///     fn on_hit(&mut self) {
+///         // Fine to call even if another hit already queued this node.
+///         self.queue_free_safe();
+///     }
+/// }
+/// ```
+///
+/// Using this key on a class with base `RefCounted` or `Object` is a compile error, as those cannot be queued for deletion.
+///
+/// ## Scene-bound classes
+///
+/// If a class is the root node of a specific scene, `#[class(scene = "res://path.tscn")]` generates the constant `SCENE_PATH` and an
+/// associated function `instantiate() -> Gd<Self>`, which loads the scene and instantiates it.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D, scene = "res://enemies/goblin.tscn")]
+/// struct Goblin {
+///     base: Base<Node2D>,
+/// }
+///
+/// fn spawn(parent: &mut Gd<Node>) {
+///     let goblin = Goblin::instantiate();
+///     parent.add_child(goblin.upcast());
+/// }
+/// ```
+///
+/// The macro only checks that the path starts with `res://` and names a `.tscn` or `.scn` file, since the Godot project is not known at
+/// compile time. `instantiate()` panics if the scene cannot be loaded, or if its root node does not have the class `Self`.
+///
+/// ## Engine singletons
+///
+/// `#[class(singleton)]` registers an instance of the class as an engine singleton, under the class name. Use
+/// `#[class(singleton = "Name")]` to choose a different name. The singleton is then accessible from GDScript by its name, from
+/// `Engine::get_singleton()`, and in Rust through the generated `singleton() -> Gd<Self>` function.
+///
+/// The instance is created with the class's `init` once all classes of the same init level are registered, and freed when the
+/// extension is unloaded. Since the library owns the instance, the class must be manually managed (base `Object` or `Node`-derived,
+/// not `RefCounted`).
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Object, singleton = "Scores")]
+/// struct ScoreManager {
+///     #[var]
+///     high_score: i64,
+///     base: Base<Object>,
+/// }
+///
+/// fn submit(score: i64) {
+///     let mut scores = ScoreManager::singleton();
+///     let mut scores = scores.bind_mut();
+///     scores.high_score = scores.high_score.max(score);
+/// }
+/// ```
+///
+/// ```gdscript
+/// print(Scores.high_score)
+/// ```
+///
+/// Reference-counted singletons are rejected at compile time:
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=RefCounted, singleton)]
+/// struct Counter {}
+/// ```
+///
+/// ## Save and load hooks
+///
+/// Resources sometimes store their data in a different form than they use at runtime, e.g. compressed or encrypted.
+/// `#[class(before_save_fn = method)]` and `#[class(after_load_fn = method)]` hook into the property access Godot uses for
+/// saving and loading (`Object::get()` and `Object::set()`), for the class's own `#[var]` and `#[export]` fields:
+///
+/// - `fn method(&self, property: StringName, value: Variant) -> Variant` is called whenever Godot reads a property. It receives
+///   the value returned by the property's getter and returns the value Godot sees, and thus saves.
+/// - `fn method(&mut self, property: StringName)` is called after Godot has assigned a property through its setter, e.g. while
+///   loading a resource. It can convert the stored form back.
+///
+/// Godot does not distinguish saving from other property access, so the hooks also run for the inspector and for `get()`/`set()`
+/// calls from GDScript. If the class overrides `get_property()` or `set_property()` in its `I*` trait impl, those take precedence
+/// and the corresponding hook is not called.
+///
+/// ```no_run
 /// # use godot::prelude::*;
 /// #[derive(GodotClass)]
-/// #[class(base=Node, init, hidden)]
-/// pub struct Foo {}
-/// ```
+/// #[class(init, base=Resource, before_save_fn = prepare, after_load_fn = restore)]
+/// struct Level {
+///     #[export]
+///     layout: GString,
+///     base: Base<Resource>,
+/// }
 ///
-/// Even though this class is a `Node` and it has an init function, it still won't show up in the editor as a node you can add to a scene
-/// because we have added a `hide` key to the class. This will also prevent it from showing up in documentation.
+/// impl Level {
+///     fn prepare(&self, property: StringName, value: Variant) -> Variant {
+///         if property == StringName::from("layout") {
+///             // compress `value`...
+///         }
+///         value
+///     }
+///
+///     fn restore(&mut self, property: StringName) {
+///         if property == StringName::from("layout") {
+///             // decompress `self.layout`...
+///         }
+///     }
+/// }
+/// ```
 ///
 /// # Further field customization
 ///
@@ -440,7 +1067,81 @@ use crate::util::ident;
 /// #     fn init(base: godot::obj::Base<Self::Base>) -> Self { todo!() }
 /// # }
 /// ```
-#[proc_macro_derive(GodotClass, attributes(class, base, hint, var, export, init, signal))]
+///
+/// ## Unused fields
+///
+/// The derive macro never rewrites the struct, so attributes on fields are kept as-is. Fields that are only read by the generated
+/// `init` or by `#[var]`/`#[export]` glue do not trigger dead-code warnings. Without generated `init` (e.g. with `#[class(no_init)]`
+/// or a user-defined `init`), a field that is never read in Rust is genuinely unused, and rustc warns about it. Silence this with the
+/// usual `#[allow(dead_code)]` on the field:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(no_init)]
+/// struct Handle {
+///     // Only kept alive for its Drop impl.
+///     #[allow(dead_code)]
+///     guard: std::sync::Arc<()>,
+/// }
+/// ```
+///
+/// # Enums as state machines
+///
+/// `#[derive(GodotClass)]` also works on enums, whose variants are either unit variants or tuple variants with a single field.
+/// The class is based on `Resource` unless `#[class(base = ...)]` says otherwise, and supports the `init`, `no_init`, `rename`,
+/// `tool` and `hidden` keys. With `init`, objects start out in the first variant, with default-constructed data.
+///
+/// The enum is exposed to Godot as a state machine:
+/// - The `state` property holds the index of the current variant, in declaration order. It is shown as a drop-down in the inspector.
+///   Assigning a different index switches to that variant, with default-constructed data.
+/// - The data of the current variant is listed as an additional property, named after the variant in snake_case. It is only present
+///   while that variant is active, so saved resources only contain the data of their current state.
+/// - For each variant, a transition method `to_<variant>()` switches to it, taking the variant's data as parameter (if any).
+///   It is available both in Rust and GDScript. `state_index()` returns the current index in Rust.
+///
+/// Variant data must implement `ToGodot`, `FromGodot` and `Default`. Variants named `State` are not allowed, as they would collide
+/// with the `state` property.
+///
+/// The transition methods take the place of a `#[godot_api]` impl block, so the enum cannot have one of its own. Additional Rust
+/// methods can still be declared in a regular `impl` block.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// enum DoorState {
+///     Closed,
+///     Open,
+///     Locked(GString), // key name
+/// }
+///
+/// fn lock(door: &mut DoorState) {
+///     door.to_locked("cellar".into());
+///     assert_eq!(door.state_index(), 2);
+/// }
+/// ```
+///
+/// ```gdscript
+/// var door = DoorState.new()
+/// door.to_locked("cellar")
+/// print(door.state, " ", door.locked) # 2 cellar
+/// ```
+#[proc_macro_derive(
+    GodotClass,
+    attributes(
+        class,
+        base,
+        hint,
+        var,
+        export,
+        no_export,
+        init,
+        signal,
+        mixin,
+        remote_sync
+    )
+)]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
 }
@@ -469,6 +1170,7 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// * lifecycle methods like `ready` or `process`
 /// * `on_notification` method
 /// * `to_string` method
+/// * `validate_property` method, to adjust property hints and usage at runtime (Godot 4.2+)
 ///
 /// Neither of the two `#[godot_api]` blocks is required. For small data bundles inheriting `RefCounted`, you may be fine with
 /// accessing properties directly from GDScript.
@@ -523,6 +1225,47 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// For light setup that goes beyond field defaults, `#[class(init, after_init = method)]` calls `fn method(&mut self)` on the
+/// freshly initialized object, before it is handed to Godot. At that point, all fields already hold their default values, so the
+/// method can derive or override some of them.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, after_init = configure)]
+/// pub struct MyNode {
+///     base: Base<Node>,
+///
+///     #[init(default = 3)]
+///     lives: i64,
+///     max_lives: i64,
+/// }
+///
+/// impl MyNode {
+///     fn configure(&mut self) {
+///         self.max_lives = self.lives * 2;
+///     }
+/// }
+/// ```
+///
+/// Data classes without a `Base<T>` field can instead take their values from the struct's own `Default` impl, using
+/// `#[class(init, from_default)]`. The generated constructor is then `Self { overridden: expr, ..Default::default() }`, where only
+/// fields with `#[init(default = ...)]` are listed. The struct must implement `Default`, e.g. through `#[derive(Default)]`.
+/// For classes with a base field, which cannot implement `Default`, the key has no effect and each field is initialized as above.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass, Default)]
+/// #[class(init, from_default)]
+/// pub struct Settings {
+///     volume: f32,
+///     fullscreen: bool,
+///
+///     #[init(default = 60)]
+///     max_fps: i32,
+/// }
+/// ```
+///
 ///
 /// # Lifecycle functions
 ///
@@ -543,6 +1286,10 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// The string representation used by `str(obj)` in GDScript or `Display` in Rust is customized with the trait's `to_string` method.
+/// Alternatively, an inherent `#[godot_api]` impl can declare `fn _to_string(&self) -> GString`, mirroring GDScript's `_to_string()`.
+/// Only one of the two can be used per class.
+///
 ///
 /// # User-defined functions
 ///
@@ -554,7 +1301,8 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// `Gd::bind_mut()` in case of `&mut self`. To avoid that, use `#[func(gd_self)]`, which requires an explicit first argument of type `Gd<T>`.
 ///
 /// Functions without a receiver become static functions in Godot. They can be called from GDScript using `MyStruct.static_function()`.
-/// If they return `Gd<Self>`, they are effectively constructors that allow taking arguments.
+/// If they return `Gd<Self>`, they are effectively constructors that allow taking arguments. Constructors that can fail may
+/// return `Option<Gd<Self>>` instead; `None` arrives in GDScript as `null`.
 ///
 /// ```no_run
 /// # use godot::prelude::*;
@@ -579,6 +1327,15 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///         })
 ///     }
 ///
+///     #[func]
+///     pub fn try_static_function(constructor_arg: i64) -> Option<Gd<Self>> {
+///         if constructor_arg < 0 {
+///             return None;
+///         }
+///
+///         Some(Self::static_function(constructor_arg))
+///     }
+///
 ///     #[func(gd_self)]
 ///     pub fn explicit_receiver(mut this: Gd<Self>, other_arg: bool) {
 ///         // Only bind Gd pointer if needed.
@@ -589,6 +1346,213 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Method chaining
+///
+/// A `#[func(gd_self)]` method can return `Gd<Self>` to enable builder-style chaining, e.g. `obj.with_x(1).with_y(2)` in GDScript.
+/// Returning `this` hands the same object back to the caller, which may also be a newly created instance.
+///
+/// For `RefCounted` classes, returning `this` moves the reference that was passed in, so no reference is gained or lost by the call.
+/// This keeps an intermediate object alive across the chain, even if it is not stored anywhere, e.g. `MyStruct.new().with_x(1)`.
+/// Manually managed classes have no reference count; the same pointer is returned, and the caller remains responsible for freeing it.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base = RefCounted)]
+/// struct Builder {
+///     x: i64,
+/// }
+///
+/// #[godot_api]
+/// impl Builder {
+///     #[func(gd_self)]
+///     fn with_x(mut this: Gd<Self>, x: i64) -> Gd<Self> {
+///         this.bind_mut().x = x;
+///         this
+///     }
+/// }
+/// ```
+///
+/// ## Optional parameters
+///
+/// Trailing parameters of type `Option<T>` are registered as optional arguments with a `null` default, so GDScript callers may
+/// omit them. An omitted or `null` argument arrives as `None`. `Option` parameters that are followed by a non-`Option` one remain
+/// required, but still accept `null`.
+///
+/// For `T` other than `Gd<...>`, such parameters are registered with type `Variant`, as Godot has no nullable form of these types.
+///
+/// An `Option<Gd<T>>` parameter maps `null` to `None` and objects of class `T` (or a subclass) to `Some`. An object of any other class
+/// is not silently turned into `None`, but fails the call with a conversion error, like a plain `Gd<T>` parameter would.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     // Callable from GDScript as `spawn(3)`, `spawn(3, 10)` or `spawn(3, null, "ghost")`.
+///     #[func]
+///     fn spawn(&self, count: i64, health: Option<i64>, name: Option<GString>) {
+///         let health = health.unwrap_or(100);
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// ## Default parameters
+///
+/// `#[default_param(name = value, ...)]` on a `#[func]` additionally registers a `<name>_default` method, which leaves out the listed
+/// parameters and calls the full method with the given values. Both methods are callable from GDScript; the wrapper is also available
+/// in Rust. With `rename`, the wrapper is exposed as `<rename>_default`. This cannot be combined with `virtual`, `gd_self` or
+/// `instantiate`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     // Callable from GDScript as `move_to(target, 2.0)` or `move_to_default(target)`.
+///     #[func]
+///     #[default_param(speed = 1.0)]
+///     fn move_to(&mut self, target: Vector3, speed: f32) {
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// ## Mutable object parameters
+///
+/// A parameter may be declared as `&mut Gd<T>`. The argument is received like a `Gd<T>` -- so its class is checked on entry -- and the
+/// method gets a mutable reference to it. Note that this does not make the parameter an out-parameter in the GDScript sense: the
+/// caller's reference cannot be rebound, but any changes made _through_ it (e.g. setting properties) mutate the shared object and are
+/// visible to the caller.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     fn hide_node(&self, node: &mut Gd<Node2D>) {
+///         node.set_visible(false);
+///     }
+/// }
+/// ```
+///
+/// ## Borrowed string parameters
+///
+/// Parameters of type `&GString` and `&StringName` borrow a string that is kept alive for the duration of the call, instead of moving an
+/// owned value into the method. Godot strings are reference-counted and copy-on-write, so receiving the argument never copies its
+/// characters. The reference is only valid inside the method; if you need to keep the string, clone it.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     fn count_vowels(&self, text: &GString) -> i64 {
+///         text.chars_checked().iter().filter(|c| "aeiou".contains(**c)).count() as i64
+///     }
+/// }
+/// ```
+///
+/// ## Error results
+///
+/// A method may return `Result<T, GString>` or `Result<T, String>`; Godot sees it as returning `T`. On `Ok`, the value is returned
+/// as usual. On `Err`, the message is pushed as a Godot error (visible in the editor's debugger), and the method returns `T::default()`.
+/// `T` must therefore implement `Default`; otherwise, compilation fails at the return type.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     fn parse_level(&self, text: GString) -> Result<i64, GString> {
+///         text.to_string()
+///             .parse()
+///             .map_err(|_| GString::from(format!("not a level: '{text}'")))
+///     }
+/// }
+/// ```
+///
+/// ## Re-entrant `&mut self` calls
+///
+/// A `&mut self` method needs exclusive access to the object. If it is called while the object is already bound -- typically
+/// re-entrantly, e.g. by an engine callback while a `&self` method runs -- the call panics with a message naming the method.
+/// With `#[func(try_mut)]`, such a call is instead reported as a Godot error and returns `Default::default()`, without running the
+/// method. This requires a `&mut self` receiver and a return type implementing `Default`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct { hits: i64 }
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func(try_mut)]
+///     fn register_hit(&mut self) -> i64 {
+///         self.hits += 1;
+///         self.hits
+///     }
+/// }
+/// ```
+///
+/// ## Generic methods
+///
+/// A method with a single type parameter can be registered once per type, by listing the types in `#[func(instantiate = [...])]`.
+/// Each instance is exposed to Godot as `<name>_<type>`, where `<name>` is the method name or its `rename`. The
+/// [`GodotNumeric`](../builtin/meta/trait.GodotNumeric.html) bound covers Godot's integer and float types. `instantiate` cannot be
+/// combined with `virtual` or `gd_self`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     // Registered as `larger_i64` and `larger_f64`.
+///     #[func(instantiate = [i64, f64])]
+///     fn larger<T: GodotNumeric>(&self, a: T, b: T) -> T {
+///         if a > b { a } else { b }
+///     }
+/// }
+/// ```
+///
+/// ## Internal methods
+///
+/// Adding `#[hide_in_editor]` to a `#[func]` registers the method without the `MethodFlags::NORMAL` flag and leaves it out of the
+/// editor documentation. This is meant for methods that exist only to be called dynamically, e.g. through `Callable` or `call()`,
+/// and should not be presented as part of the class's API. They remain callable like any other method.
+///
+/// Godot has no flag that hides a method everywhere in the editor: `MethodFlags::EDITOR` is never set for extension methods, and
+/// GDScript autocompletion does not filter on `NORMAL`. So apart from the class reference, the change is limited to the flags
+/// reported by `ClassDB.class_get_method_list()`, which tools can inspect.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     #[hide_in_editor]
+///     fn on_timer_internal(&mut self) {}
+/// }
+/// ```
+///
 /// ## Virtual methods
 ///
 /// Functions with the `#[func(virtual)]` attribute are virtual functions, meaning attached scripts can override them.
@@ -626,12 +1590,111 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///
 /// Make sure you understand the limitations in the [tutorial](https://godot-rust.github.io/book/register/virtual-functions.html).
 ///
+/// ## Profiling
+///
+/// Methods annotated with `#[profile]` measure how long their body takes to execute, using `Time::get_ticks_usec()`. After each call,
+/// the elapsed time in microseconds is available as a custom monitor in Godot's `Performance` singleton, which shows up in the
+/// editor's debugger panel. The monitor is named `ClassName/method_name` by default; use `#[profile(name = "...")]` to choose a
+/// different one. This works on methods of both inherent and trait impls, and can be combined with `#[func]`.
+///
+/// ```no_run
+/// # #[cfg(since_api = "4.2")]
+/// # mod conditional {
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct MyStruct {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     #[profile(name = "MyGame/pathfinding")]
+///     fn find_path(&self) {
+///         // expensive computation...
+///     }
+/// }
+///
+/// #[godot_api]
+/// impl INode for MyStruct {
+///     // Shows up as "MyStruct/process".
+///     #[profile]
+///     fn process(&mut self, _delta: f64) {}
+/// }
+/// # }
+/// ```
+///
+/// This requires at least Godot 4.2.
+///
+/// ## Editor documentation
+///
+/// `///` doc comments on `#[func]` methods and `#[signal]` declarations are shown in the editor's help for the class, along with
+/// the parameter and return types Godot knows for them. Doc comments are taken as plain text; Markdown is not translated to
+/// Godot's BBCode.
+///
+/// Doc comments on the `#[derive(GodotClass)]` struct itself and on its `#[var]` and `#[export]` fields are shown as well; the first
+/// paragraph of the struct's comment becomes the brief description. No build step is involved: the documentation is loaded into the
+/// editor when the extension is initialized.
+///
+/// This requires at least Godot 4.3. On earlier versions, doc comments are ignored.
+///
+/// # Mixins
+///
+/// Godot has no multiple inheritance, but behavior can be shared between classes through mixins. A mixin is a plain Rust type (not
+/// a `GodotClass`) whose impl block is annotated with `#[godot_api(mixin)]`. Each class storing the mixin in a `#[mixin]` field gets
+/// the mixin's `#[func]` methods: they are registered with Godot as if declared on the class, and forward to the field.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(Default)]
+/// struct MovementMixin {
+///     speed: f64,
+/// }
+///
+/// #[godot_api(mixin)]
+/// impl MovementMixin {
+///     #[func]
+///     fn set_speed(&mut self, speed: f64) {
+///         self.speed = speed;
+///     }
+///
+///     #[func]
+///     fn get_speed(&self) -> f64 {
+///         self.speed
+///     }
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D)]
+/// struct Player {
+///     #[mixin]
+///     movement: MovementMixin,
+/// }
+///
+/// // `Player` now has `set_speed()` and `get_speed()`, in Rust and in GDScript.
+/// # fn main() {}
+/// ```
+///
+/// The mixin must be declared in the same crate, and its methods must be visible from the class's module. `#[signal]`, `#[constant]`
+/// and the `#[func]` keys `virtual`, `gd_self` and `instantiate` are not supported in mixins. If two mixins of a class, or a mixin and
+/// the class itself, declare methods with the same name, compilation fails with a "duplicate definitions" error.
+///
 /// # Constants and signals
 ///
 /// Please refer to [the book](https://godot-rust.github.io/book/register/constants.html).
 #[proc_macro_attribute]
-pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
-    translate(input, class::attribute_godot_api)
+pub fn godot_api(meta: TokenStream, input: TokenStream) -> TokenStream {
+    if meta.is_empty() {
+        translate(input, class::attribute_godot_api)
+    } else {
+        translate_meta(
+            "godot_api",
+            meta,
+            input,
+            class::attribute_godot_api_with_meta,
+        )
+    }
 }
 
 /// Derive macro for [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) on structs.
@@ -712,6 +1775,19 @@ pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
 ///
 /// When using one of the integer types, each variant of the enum will be converted into its discriminant.
 ///
+/// Enums with data variants are rejected with a compile error at the variant. Such enums cannot be used as `#[var]` or `#[export]`
+/// fields directly; implement `GodotConvert`, `ToGodot`, `FromGodot` and `Var`/`Export` manually for them.
+/// ```compile_fail
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert, Var, Export)]
+/// #[godot(via = i64)]
+/// enum Shape {
+///     Circle(f32),
+///     Square,
+/// }
+/// ```
+///
 /// ### Examples
 ///
 /// ```no_run
@@ -760,6 +1836,39 @@ pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// assert_eq!(MyEnum::B.to_godot(), 10);
 /// assert_eq!(MyEnum::C.to_godot(), 11);
 /// ```
+///
+/// ## `dictionary`
+///
+/// Structs with named fields can use `#[godot(dictionary)]` to be converted into a `Dictionary`, with the field names as keys.
+/// This is useful to return structured data from `#[func]` methods to GDScript, without defining a separate Godot class.
+///
+/// Each field is converted through its own `ToGodot`/`FromGodot` implementation. Fields whose types also derive
+/// `GodotConvert` with `#[godot(dictionary)]` thus become nested dictionaries.
+///
+/// Keys can be renamed with `rename_all`, which accepts `"camelCase"`, `"PascalCase"`, `"snake_case"` and `"SCREAMING_SNAKE_CASE"`.
+/// Converting back from a dictionary fails if a key is missing or has a value of the wrong type; additional keys are ignored.
+///
+/// ### Example
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(dictionary, rename_all = "camelCase")]
+/// struct PlayerStats {
+///     max_health: i64,
+///     display_name: GString,
+/// }
+///
+/// let stats = PlayerStats {
+///     max_health: 100,
+///     display_name: "Ferris".into(),
+/// };
+///
+/// let dict = stats.to_godot();
+/// assert_eq!(dict.get("maxHealth"), Some(100.to_variant()));
+/// assert_eq!(dict.get("displayName"), Some("Ferris".to_variant()));
+/// ```
 #[proc_macro_derive(GodotConvert, attributes(godot))]
 pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
@@ -133,29 +133,32 @@ impl KvParser {
     }
 
     pub fn handle_usize(&mut self, key: &str) -> ParseResult<Option<usize>> {
-        let Some(expr) = self.handle_expr(key)? else {
+        let Some((key, value)) = self.handle_any_entry(key) else {
             return Ok(None);
         };
 
-        let mut tokens = expr.into_iter();
-        let Some(TokenTree::Literal(lit)) = tokens.next() else {
+        // Errors point at the offending value tokens; the key is only used if there is no value at all.
+        let Some(value) = value else {
             return bail!(
                 key,
                 "missing value for '{key}' (must be unsigned integer literal)"
             );
         };
 
-        if let Some(surplus) = tokens.next() {
-            return bail!(
-                key,
-                "value for '{key}' must be unsigned integer literal; found extra {surplus:?}"
-            );
-        }
+        let lit = match value.single()? {
+            TokenTree::Literal(lit) => lit,
+            other => {
+                return bail!(
+                    other,
+                    "value for '{key}' must be unsigned integer literal; found `{other}`"
+                )
+            }
+        };
 
         let Ok(int) = lit.to_string().parse() else {
             return bail!(
-                key,
-                "value for '{key}' must be unsigned integer literal; found {lit:?}"
+                lit,
+                "value for '{key}' must be unsigned integer literal; found `{lit}`"
             );
         };
 
@@ -226,8 +229,18 @@ impl KvValue {
     }
 
     pub fn ident(self) -> ParseResult<Ident> {
+        if self.tokens.len() > 1 {
+            return bail!(&self.tokens[1], "expected a single identifier");
+        }
+
         match self.single()? {
             TokenTree::Ident(ident) => Ok(ident),
+            TokenTree::Literal(lit) if lit.to_string().starts_with('"') => {
+                bail!(
+                    lit,
+                    "expected identifier, found string literal {lit}; remove the quotes"
+                )
+            }
             tt => {
                 bail!(tt, "expected identifier")
             }
@@ -462,6 +475,32 @@ mod tests {
         assert!(parsed.is_empty(), "Remaining entries in map");
     }
 
+    fn first_value(input_tokens: TokenStream) -> KvValue {
+        parse(input_tokens)
+            .into_values()
+            .next()
+            .flatten()
+            .expect("value")
+    }
+
+    #[test]
+    fn test_kv_value_ident() {
+        let value = first_value(quote! { #[attr(key = Name)] });
+        assert_eq!(value.ident().expect("ident").to_string(), "Name");
+
+        let value = first_value(quote! { #[attr(key = "Name")] });
+        assert!(
+            value.ident().is_err(),
+            "string literal is not an identifier"
+        );
+
+        let value = first_value(quote! { #[attr(key = invalid base)] });
+        assert!(
+            value.ident().is_err(),
+            "multiple tokens are not an identifier"
+        );
+    }
+
     #[test]
     fn test_parse_kv_just_key() {
         expect_parsed(
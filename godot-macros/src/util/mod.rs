@@ -16,7 +16,7 @@ use venial::{Error, Function, Impl, TyExpr};
 mod kv_parser;
 mod list_parser;
 
-pub(crate) use kv_parser::KvParser;
+pub(crate) use kv_parser::{KvParser, KvValue};
 pub(crate) use list_parser::ListParser;
 
 pub fn ident(s: &str) -> Ident {
@@ -261,6 +261,53 @@ pub(crate) fn extract_cfg_attrs(
     })
 }
 
+/// Returns the string literals of `#[doc = "..."]` attributes, i.e. one per line of a `///` comment.
+pub(crate) fn extract_doc_literals(attrs: &[venial::Attribute]) -> Vec<TokenTree> {
+    attrs
+        .iter()
+        .filter(|attr| {
+            attr.get_single_path_segment()
+                .map_or(false, |name| name == "doc")
+        })
+        .filter_map(|attr| match &attr.value {
+            venial::AttributeValue::Equals(_, tokens) => match tokens.as_slice() {
+                [literal @ TokenTree::Literal(_)] => Some(literal.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Commonly used Godot engine classes, which user classes must not be named after.
+///
+/// Not exhaustive; a collision with any other engine class is still reported by Godot when the extension is loaded.
+#[rustfmt::skip]
+const ENGINE_CLASS_NAMES: &[&str] = &[
+    "AnimatedSprite2D", "AnimatedSprite3D", "AnimationPlayer", "AnimationTree", "Area2D", "Area3D", "ArrayMesh",
+    "AudioStreamPlayer", "AudioStreamPlayer2D", "AudioStreamPlayer3D", "BoxContainer", "Button", "Camera2D",
+    "Camera3D", "CanvasItem", "CanvasLayer", "CharacterBody2D", "CharacterBody3D", "ClassDB", "CollisionObject2D",
+    "CollisionObject3D", "CollisionShape2D", "CollisionShape3D", "ColorRect", "Container", "Control", "Curve",
+    "DirAccess", "DirectionalLight3D", "EditorPlugin", "Engine", "Environment", "Expression", "FileAccess", "Font",
+    "GDScript", "Gradient", "GridContainer", "GridMap", "HBoxContainer", "HTTPRequest", "Image", "ImageTexture",
+    "Input", "InputEvent", "ItemList", "JSON", "Label", "Light2D", "Light3D", "LineEdit", "MainLoop",
+    "MarginContainer", "Marker2D", "Marker3D", "Material", "Mesh", "MeshInstance2D", "MeshInstance3D", "Mutex",
+    "NavigationAgent2D", "NavigationAgent3D", "Node", "Node2D", "Node3D", "OS", "Object", "OmniLight3D", "PackedScene",
+    "Panel", "Path2D", "Path3D", "PathFollow2D", "PathFollow3D", "PhysicsBody2D", "PhysicsBody3D", "ProgressBar",
+    "RandomNumberGenerator", "RayCast2D", "RayCast3D", "RefCounted", "Resource", "ResourceLoader", "ResourceSaver",
+    "RichTextLabel", "RigidBody2D", "RigidBody3D", "SceneTree", "Script", "ScrollContainer", "Semaphore", "Shader",
+    "ShaderMaterial", "Skeleton2D", "Skeleton3D", "Slider", "SpotLight3D", "Sprite2D", "Sprite3D", "StaticBody2D",
+    "StaticBody3D", "SubViewport", "TextEdit", "Texture", "Texture2D", "TextureRect", "Theme", "Thread", "TileMap",
+    "Time", "Timer", "Translation", "Tree", "Tween", "VBoxContainer", "Viewport", "Window", "World2D", "World3D",
+];
+
+/// Whether `godot_name`, the name under which a class is registered, collides with one of the common engine classes.
+pub(crate) fn is_engine_class_name(godot_name: &str) -> bool {
+    ENGINE_CLASS_NAMES.binary_search(&godot_name).is_ok()
+}
+
 #[cfg(before_api = "4.3")]
 pub fn make_virtual_tool_check() -> TokenStream {
     quote! {
@@ -12,7 +12,7 @@ pub use super::register::{godot_api, Export, GodotClass, GodotConvert, Var};
 
 pub use super::builtin::__prelude_reexport::*;
 pub use super::builtin::math::FloatExt as _;
-pub use super::builtin::meta::{FromGodot, ToGodot};
+pub use super::builtin::meta::{FromGodot, GodotNumeric, ToGodot};
 
 pub use super::engine::{
     load, try_load, utilities, AudioStreamPlayer, Camera2D, Camera3D, GFile, IAudioStreamPlayer,
@@ -22,7 +22,7 @@ pub use super::engine::{
 };
 pub use super::init::{gdextension, ExtensionLibrary, InitLevel};
 pub use super::log::*;
-pub use super::obj::{Base, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId, OnReady};
+pub use super::obj::{Base, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId, OnEditor, OnReady};
 
 // Make trait methods available.
 pub use super::engine::NodeExt as _;
@@ -142,11 +142,46 @@ func test_refcounted_as_object_return_from_user_func_ptrcall():
 	var obj: MockRefCountedRust = obj_test.return_refcounted_as_object() 
 	assert_eq(obj.i, 42)
 
+func test_inherent_to_string():
+	var obj := InherentToString.new()
+	assert_eq(str(obj), "inherent=42")
+
 func test_custom_constructor():
 	var obj = CustomConstructor.construct_object(42)
 	assert_eq(obj.val, 42)
 	obj.free()
 
+func test_option_static_factory_varcall():
+	var some: Variant = OptionFfiTest.try_create(true)
+	assert_that(some is OptionFfiTest, "some is OptionFfiTest")
+
+	var none: Variant = OptionFfiTest.try_create(false)
+	assert_eq(none, null, "none == null")
+
+func test_option_static_factory_ptrcall():
+	var some: OptionFfiTest = OptionFfiTest.try_create(true)
+	assert_that(some != null, "some != null")
+
+	var none: OptionFfiTest = OptionFfiTest.try_create(false)
+	assert_eq(none, null, "none == null")
+
+func test_option_trailing_args_varcall():
+	var ffi: Variant = OptionFfiTest.new()
+
+	assert_eq(ffi.describe_optional_args(1), "1 (none) (none)")
+	assert_eq(ffi.describe_optional_args(1, 2), "1 2 (none)")
+	assert_eq(ffi.describe_optional_args(1, null, "x"), "1 (none) x")
+	assert_eq(ffi.describe_nullable_arg(null, "!"), "(none)!")
+	assert_eq(ffi.describe_nullable_arg(5, "!"), "5!")
+
+func test_option_trailing_args_ptrcall():
+	var ffi := OptionFfiTest.new()
+
+	assert_eq(ffi.describe_optional_args(1), "1 (none) (none)")
+	assert_eq(ffi.describe_optional_args(1, 2), "1 2 (none)")
+	assert_eq(ffi.describe_optional_args(1, 2, "x"), "1 2 x")
+	assert_eq(ffi.describe_nullable_arg(null, "!"), "(none)!")
+
 func test_option_refcounted_none_varcall():
 	var ffi := OptionFfiTest.new()
 
@@ -327,6 +362,18 @@ func test_gd_self_obj_succeeds():
 
 	assert_eq(gd_self_obj.succeed_at_updating_internal_value(10), 10)
 
+func test_gd_self_obj_chaining():
+	var obj := GdSelfObj.new()
+	var chained = obj.with_internal_value(1).add_to_internal_value(2)
+
+	assert_that(is_same(chained, obj), "Chained calls return the same instance")
+	assert_eq(obj.get_internal_value(), 3)
+
+	# The temporary is kept alive by the returned reference.
+	var temporary = GdSelfObj.new().with_internal_value(5).add_to_internal_value(1)
+	assert_eq(temporary.get_internal_value(), 6)
+	assert_eq(temporary.get_reference_count(), 1)
+
 func sample_func():
 	pass
 
@@ -361,3 +408,34 @@ func test_get_set():
 	assert(obj.is_set_called())
 	assert(obj.is_get_called())
 
+func test_scalar_getters_ptrcall():
+	# Statically typed, so the getters are called through ptrcall.
+	var obj: ScalarGetterObj = ScalarGetterObj.new()
+	var count: int = obj.get_count()
+	var ratio: float = obj.get_ratio()
+	var negative: bool = obj.is_negative()
+	assert_eq(count, -7)
+	assert_eq(ratio, -3.5)
+	assert_eq(negative, true)
+	assert_eq(obj.small_count(), 7)
+
+func test_string_name_params_ptrcall():
+	# Statically typed, so the StringName is passed through ptrcall without conversion.
+	var obj: FuncObj = FuncObj.new()
+	var name: StringName = &"interned_key"
+	var result: StringName = obj.echo_string_name(name)
+	assert_eq(result, &"interned_key")
+	assert_eq(typeof(obj.echo_string_name(name)), TYPE_STRING_NAME)
+	assert_eq(typeof(obj.echo_gstring("plain")), TYPE_STRING)
+
+func test_typed_array_return():
+	var obj: FuncObj = FuncObj.new()
+	var ints: Array[int] = obj.return_typed_ints()
+	assert_that(ints.is_typed(), "returned Array[int] is typed")
+	assert_eq(ints.get_typed_builtin(), TYPE_INT)
+	assert_eq(ints, [1, 2, 3])
+
+	var nodes: Array[Node] = obj.return_typed_nodes()
+	assert_that(nodes.is_typed(), "returned Array[Node] is typed")
+	assert_eq(nodes.get_typed_builtin(), TYPE_OBJECT)
+	assert_eq(nodes.get_typed_class_name(), &"Node")
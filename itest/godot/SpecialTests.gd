@@ -53,3 +53,79 @@ func test_collision_object_2d_input_event():
 
 	window.queue_free()
 
+
+# Test that `emit_<signal>_deferred()` only invokes handlers once the engine is idle.
+#
+# Deferred calls are flushed at the end of the frame, so this cannot be observed within a standard integration test.
+func test_signal_emit_deferred():
+	var emitter := DeferredEmitter.new()
+	var received := []
+	emitter.value_changed.connect(func(value): received.append(value))
+
+	emitter.change_value_deferred(42)
+	assert_eq(received, [], "Deferred signal should not be emitted immediately")
+
+	await Engine.get_main_loop().process_frame
+
+	assert_eq(received, [42], "Deferred signal should be emitted at idle time")
+
+
+# Test that the deferred NodeExt helpers modify the tree once the frame ends.
+#
+# The Rust test `node_deferred_tree_changes` can only check that nothing happens immediately.
+func test_node_deferred_tree_changes():
+	var root: Node = Engine.get_main_loop().root
+
+	var parent := Node.new()
+	var new_parent := Node.new()
+	root.add_child(parent)
+	root.add_child(new_parent)
+
+	var added := Node.new()
+	var removed := Node.new()
+	var reparented := Node.new()
+	parent.add_child(removed)
+	parent.add_child(reparented)
+
+	DeferredTreeChanger.add_child_deferred(parent, added)
+	DeferredTreeChanger.remove_child_deferred(parent, removed)
+	DeferredTreeChanger.queue_reparent(reparented, new_parent)
+
+	await Engine.get_main_loop().process_frame
+
+	assert_eq(added.get_parent(), parent, "Deferred add_child should take effect")
+	assert_eq(removed.get_parent(), null, "Deferred remove_child should take effect")
+	assert_eq(reparented.get_parent(), new_parent, "Deferred reparent should take effect")
+
+	removed.free()
+	parent.queue_free()
+	new_parent.queue_free()
+
+
+# Test that `#[remote_sync]` fields are replicated by a synchronizer child, which is added at the end of the frame.
+#
+# The Rust test `node_remote_sync_deferred` can only check that the child is not added immediately.
+func test_node_remote_sync():
+	# Godot 4.1 compat: `#[remote_sync]` requires Godot 4.2.
+	if not ClassDB.class_exists("RemoteSyncNode"):
+		return
+
+	var root: Node = Engine.get_main_loop().root
+	var node: Node = ClassDB.instantiate("RemoteSyncNode")
+	root.add_child(node)
+
+	await Engine.get_main_loop().process_frame
+
+	var synchronizer := node.get_node("RemoteSynchronizer") as MultiplayerSynchronizer
+	if not assert_that(synchronizer != null, "Synchronizer should be added"):
+		node.queue_free()
+		return
+
+	var config := synchronizer.replication_config
+	assert_eq(config.get_properties(), [NodePath(".:health"), NodePath(".:nickname")], "Synchronized properties")
+	assert_eq(config.property_get_replication_mode(NodePath(".:health")), SceneReplicationConfig.REPLICATION_MODE_ALWAYS, "Default replication mode")
+	assert_eq(config.property_get_replication_mode(NodePath(".:nickname")), SceneReplicationConfig.REPLICATION_MODE_ON_CHANGE, "Custom replication mode")
+	assert_that(config.property_get_spawn(NodePath(".:health")), "Property should be sent on spawn")
+	assert_that(not config.property_get_spawn(NodePath(".:nickname")), "Property should not be sent on spawn")
+
+	node.queue_free()
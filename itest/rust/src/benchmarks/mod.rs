@@ -10,10 +10,12 @@
 use std::hint::black_box;
 
 use godot::builtin::inner::InnerRect2i;
+use godot::builtin::meta::ToGodot;
 use godot::builtin::{GString, Rect2i, StringName, Vector2i};
+use godot::engine::Object;
 use godot::engine::{Node3D, Os, RefCounted};
 use godot::obj::{Gd, InstanceId, NewAlloc, NewGd};
-use godot::register::GodotClass;
+use godot::register::{godot_api, GodotClass};
 
 use crate::framework::bench;
 
@@ -67,6 +69,36 @@ fn class_user_refc_life() -> Gd<MyBenchType> {
     Gd::default()
 }
 
+#[bench(repeat = 25)]
+fn class_user_scalar_getters() -> i64 {
+    let mut obj = black_box(MyBenchGetters::new_gd().upcast::<Object>());
+
+    let mut sum = 0;
+    for _ in 0..100 {
+        sum += obj.call("get_health".into(), &[]).to::<i64>();
+        sum += obj.call("get_armor".into(), &[]).to::<i64>();
+        sum += obj.call("is_alive".into(), &[]).to::<bool>() as i64;
+        sum += obj.call("get_speed".into(), &[]).to::<f64>() as i64;
+    }
+    sum
+}
+
+// Same calls with one argument, which go through the general trampoline; for comparison with the above.
+#[bench(repeat = 25)]
+fn class_user_scalar_methods() -> i64 {
+    let mut obj = black_box(MyBenchGetters::new_gd().upcast::<Object>());
+    let args = [0.to_variant()];
+
+    let mut sum = 0;
+    for _ in 0..100 {
+        sum += obj.call("get_health_plus".into(), &args).to::<i64>();
+        sum += obj.call("get_armor_plus".into(), &args).to::<i64>();
+        sum += obj.call("is_alive_plus".into(), &args).to::<bool>() as i64;
+        sum += obj.call("get_speed_plus".into(), &args).to::<f64>() as i64;
+    }
+    sum
+}
+
 #[bench]
 fn class_singleton_access() -> Gd<Os> {
     Os::singleton()
@@ -99,3 +131,57 @@ fn utilities_ffi_call() -> f64 {
 #[derive(GodotClass)]
 #[class(init)]
 struct MyBenchType {}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct MyBenchGetters {
+    #[init(default = 100)]
+    health: i64,
+    #[init(default = 20)]
+    armor: i64,
+    #[init(default = 4.5)]
+    speed: f64,
+}
+
+#[godot_api]
+impl MyBenchGetters {
+    #[func]
+    fn get_health(&self) -> i64 {
+        self.health
+    }
+
+    #[func]
+    fn get_armor(&self) -> i64 {
+        self.armor
+    }
+
+    #[func]
+    fn is_alive(&self) -> bool {
+        self.health > 0
+    }
+
+    #[func]
+    fn get_speed(&self) -> f64 {
+        self.speed
+    }
+
+    #[func]
+    fn get_health_plus(&self, offset: i64) -> i64 {
+        self.health + offset
+    }
+
+    #[func]
+    fn get_armor_plus(&self, offset: i64) -> i64 {
+        self.armor + offset
+    }
+
+    #[func]
+    fn is_alive_plus(&self, offset: i64) -> bool {
+        self.health + offset > 0
+    }
+
+    #[func]
+    fn get_speed_plus(&self, offset: i64) -> f64 {
+        self.speed + offset as f64
+    }
+}
@@ -53,6 +53,34 @@ fn dictionary_from() {
     assert_eq!(dictionary.get(2), Some("bar".to_variant()), "key = \"bar\"");
 }
 
+#[itest]
+fn dictionary_from_pairs() {
+    let dictionary = Dictionary::from_pairs([("foo", 1), ("bar", 2), ("foo", 3)]);
+
+    assert_eq!(dictionary.len(), 2);
+    assert_eq!(dictionary.get("foo"), Some(3.to_variant()), "key = \"foo\"");
+    assert_eq!(dictionary.get("bar"), Some(2.to_variant()), "key = \"bar\"");
+}
+
+#[itest]
+fn dictionary_from_json() {
+    let dictionary =
+        Dictionary::from_json(r#"{"name": "Godot", "version": 4, "tags": ["engine"]}"#)
+            .expect("valid JSON object");
+
+    assert_eq!(dictionary.get("name"), Some("Godot".to_variant()));
+    // Godot parses all JSON numbers as floats.
+    assert_eq!(dictionary.get("version"), Some(4.0.to_variant()));
+    assert_eq!(dictionary.get("tags"), Some(varray!["engine"].to_variant()));
+
+    let err = Dictionary::from_json("{\n\"name\": }").expect_err("invalid JSON");
+    assert_eq!(err.line(), Some(2));
+    assert!(!err.message().is_empty());
+
+    let err = Dictionary::from_json("[1, 2]").expect_err("JSON array instead of object");
+    assert_eq!(err.line(), None);
+}
+
 #[itest]
 fn dictionary_macro() {
     let dictionary = dict! {
@@ -6,7 +6,10 @@
  */
 
 use crate::framework::{expect_panic, itest};
-use godot::builtin::{PackedByteArray, PackedFloat32Array, PackedStringArray};
+use godot::builtin::{
+    PackedByteArray, PackedFloat32Array, PackedStringArray, PackedVector2Array, Vector2,
+};
+use godot::private::class_macros::assert_eq_approx;
 
 #[itest]
 fn packed_array_default() {
@@ -233,3 +236,61 @@ fn packed_array_format() {
     let a = PackedByteArray::new();
     assert_eq!(format!("{a}"), "[]");
 }
+
+#[itest]
+fn packed_array_polygon_area() {
+    let square = PackedVector2Array::from(&[
+        Vector2::new(0.0, 0.0),
+        Vector2::new(2.0, 0.0),
+        Vector2::new(2.0, 2.0),
+        Vector2::new(0.0, 2.0),
+    ]);
+    assert_eq_approx!(square.to_polygon_area(), 4.0);
+
+    // Reversed winding order flips the sign.
+    let mut reversed = square.clone();
+    reversed.reverse();
+    assert_eq_approx!(reversed.to_polygon_area(), -4.0);
+
+    let triangle = PackedVector2Array::from(&[
+        Vector2::new(0.0, 0.0),
+        Vector2::new(4.0, 0.0),
+        Vector2::new(0.0, 3.0),
+    ]);
+    assert_eq_approx!(triangle.to_polygon_area(), 6.0);
+
+    // Degenerate cases.
+    assert_eq!(PackedVector2Array::new().to_polygon_area(), 0.0);
+    let line = PackedVector2Array::from(&[Vector2::ZERO, Vector2::new(1.0, 1.0)]);
+    assert_eq!(line.to_polygon_area(), 0.0);
+}
+
+#[itest]
+fn packed_array_polygon_centroid() {
+    let square = PackedVector2Array::from(&[
+        Vector2::new(1.0, 1.0),
+        Vector2::new(3.0, 1.0),
+        Vector2::new(3.0, 3.0),
+        Vector2::new(1.0, 3.0),
+    ]);
+    assert_eq_approx!(square.to_polygon_centroid(), Vector2::new(2.0, 2.0));
+
+    let triangle = PackedVector2Array::from(&[
+        Vector2::new(0.0, 0.0),
+        Vector2::new(3.0, 0.0),
+        Vector2::new(0.0, 3.0),
+    ]);
+    assert_eq_approx!(triangle.to_polygon_centroid(), Vector2::new(1.0, 1.0));
+
+    // Degenerate cases.
+    assert_eq!(
+        PackedVector2Array::new().to_polygon_centroid(),
+        Vector2::ZERO
+    );
+    let collinear = PackedVector2Array::from(&[
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(2.0, 2.0),
+    ]);
+    assert_eq!(collinear.to_polygon_centroid(), Vector2::ZERO);
+}
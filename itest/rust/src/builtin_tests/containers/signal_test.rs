@@ -15,7 +15,7 @@ use godot::engine::{Object, RefCounted};
 use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField};
 use godot::sys;
 
-use crate::framework::itest;
+use crate::framework::{expect_panic, itest};
 
 #[derive(GodotClass)]
 #[class(init, base=Object)]
@@ -62,6 +62,42 @@ impl Receiver {
     }
 }
 
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct DeferredEmitter {
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl DeferredEmitter {
+    #[signal]
+    fn value_changed(value: i64);
+
+    // Used by SpecialTests.gd, which can wait for the deferred emission.
+    #[func]
+    fn change_value_deferred(&self, value: i64) {
+        self.emit_value_changed_deferred(value);
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct MultiSignalEmitter {
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl MultiSignalEmitter {
+    #[signal]
+    fn started();
+
+    #[signal]
+    fn progressed(percent: i64);
+
+    #[signal]
+    fn never_connected();
+}
+
 const SIGNAL_ARG_STRING: &str = "Signal string arg";
 
 #[itest]
@@ -149,3 +185,183 @@ fn connect_signal() {
 
     receiver.free();
 }
+
+#[itest]
+fn emit_signal_deferred_not_immediate() {
+    let mut emitter = DeferredEmitter::new_gd();
+    let receiver = Receiver::new_alloc();
+
+    emitter.connect("value_changed".into(), receiver.callable("receive_1_arg"));
+    emitter.bind().emit_value_changed_deferred(987);
+
+    // Handlers only run once the engine is idle; see SpecialTests.gd for the emission itself.
+    assert!(!receiver.bind().used[1].get());
+
+    receiver.free();
+}
+
+#[itest]
+fn emit_signal_deferred_requires_base() {
+    let emitter = Emitter::new_alloc();
+
+    expect_panic("emit_*_deferred() without Base<T> field", || {
+        emitter.bind().emit_signal_1_arg_deferred(987);
+    });
+
+    emitter.free();
+}
+
+#[itest]
+fn disconnect_all_signals() {
+    let mut emitter = MultiSignalEmitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+    let other_receiver = Receiver::new_alloc();
+
+    emitter.connect("started".into(), receiver.callable("receive_0_arg"));
+    emitter.connect("progressed".into(), receiver.callable("receive_1_arg"));
+    emitter.connect(
+        "progressed".into(),
+        other_receiver.callable("receive_1_arg"),
+    );
+
+    let connection_count = |emitter: &Gd<MultiSignalEmitter>, name: &str| {
+        Signal::from_object_signal(emitter, name)
+            .connections()
+            .len()
+    };
+    assert_eq!(connection_count(&emitter, "started"), 1);
+    assert_eq!(connection_count(&emitter, "progressed"), 2);
+
+    // Signals without connections are skipped.
+    emitter.bind_mut().disconnect_all_signals();
+
+    assert_eq!(connection_count(&emitter, "started"), 0);
+    assert_eq!(connection_count(&emitter, "progressed"), 0);
+    assert_eq!(connection_count(&emitter, "never_connected"), 0);
+
+    emitter.emit_signal("started".into(), &[]);
+    emitter.emit_signal("progressed".into(), &[987.to_variant()]);
+    assert!(!receiver.bind().used[0].get());
+    assert!(!receiver.bind().used[1].get());
+    assert!(!other_receiver.bind().used[1].get());
+
+    // Calling it again is a no-op.
+    emitter.bind_mut().disconnect_all_signals();
+
+    other_receiver.free();
+    receiver.free();
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn subscribe_disconnects_on_drop() {
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use std::sync::Arc;
+
+    let mut emitter = MultiSignalEmitter::new_alloc();
+    let total = Arc::new(AtomicI64::new(0));
+
+    let handle = emitter.subscribe("progressed", {
+        let total = total.clone();
+        move |args| {
+            total.fetch_add(args[0].to::<i64>(), Ordering::SeqCst);
+        }
+    });
+    assert!(handle.is_connected());
+    assert_eq!(handle.signal(), &StringName::from("progressed"));
+
+    emitter.emit_signal("progressed".into(), &[5.to_variant()]);
+    emitter.emit_signal("progressed".into(), &[7.to_variant()]);
+    assert_eq!(total.load(Ordering::SeqCst), 12);
+
+    drop(handle);
+    emitter.emit_signal("progressed".into(), &[100.to_variant()]);
+    assert_eq!(total.load(Ordering::SeqCst), 12);
+    assert_eq!(
+        Signal::from_object_signal(&emitter, "progressed")
+            .connections()
+            .len(),
+        0
+    );
+
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn subscribe_handle_outlives_emitter() {
+    use std::sync::Arc;
+
+    let mut emitter = MultiSignalEmitter::new_alloc();
+
+    // The forgotten handle keeps the connection, but not the closure: it is released together with the emitter.
+    let captured = Arc::new(());
+    let kept = emitter.subscribe("started", {
+        let captured = captured.clone();
+        move |_| {
+            let _ = &captured;
+        }
+    });
+    kept.forget();
+
+    let handle = emitter.subscribe("started", |_| {});
+    assert_eq!(
+        Signal::from_object_signal(&emitter, "started")
+            .connections()
+            .len(),
+        2
+    );
+
+    emitter.free();
+    assert_eq!(Arc::strong_count(&captured), 1);
+
+    // Dropping after the emitter is gone is a no-op.
+    assert!(!handle.is_connected());
+    drop(handle);
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn subscribe_unknown_signal() {
+    let mut emitter = MultiSignalEmitter::new_alloc();
+
+    expect_panic("subscribe() to non-existent signal", || {
+        let _handle = emitter.subscribe("does_not_exist", |_| {});
+    });
+
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn connect_signal_closure() {
+    use std::sync::{Arc, Mutex};
+
+    let mut emitter = MultiSignalEmitter::new_alloc();
+    let received = Arc::new(Mutex::new(Vec::new()));
+
+    let handle = emitter.bind().connect_progressed({
+        let received = received.clone();
+        let mut calls = 0;
+        move |percent| {
+            calls += 1;
+            received.lock().unwrap().push((calls, percent));
+        }
+    });
+    assert_eq!(handle.signal(), &StringName::from("progressed"));
+
+    emitter.emit_signal("progressed".into(), &[30.to_variant()]);
+    emitter.emit_signal("progressed".into(), &[80.to_variant()]);
+    assert_eq!(*received.lock().unwrap(), [(1, 30), (2, 80)]);
+
+    // Arguments of the wrong type don't reach the closure.
+    emitter.emit_signal("progressed".into(), &["text".to_variant()]);
+    assert_eq!(received.lock().unwrap().len(), 2);
+
+    drop(handle);
+    emitter.emit_signal("progressed".into(), &[100.to_variant()]);
+    assert_eq!(received.lock().unwrap().len(), 2);
+
+    emitter.free();
+}
@@ -114,6 +114,32 @@ fn basis_euler_angles_same() {
     }
 }
 
+#[itest]
+fn basis_constructors_same() {
+    let scale = Vector3::new(2.0, -0.5, 4.0);
+    assert_eq_approx!(Basis::from_scale(scale), InnerBasis::from_scale(scale));
+
+    let euler = Vector3::new(0.3, -0.4, 1.2);
+    assert_eq_approx!(
+        Basis::from_euler(EulerOrder::YXZ, euler).to_quat(),
+        InnerBasis::from_euler(euler, EulerOrder::YXZ as i64).get_rotation_quaternion(),
+    );
+}
+
+#[cfg(since_api = "4.1")]
+#[itest]
+fn basis_looking_at_roundtrip() {
+    let target = Vector3::new(1.0, 2.0, -3.0);
+    let basis = Basis::new_looking_at(target, Vector3::UP, false);
+
+    assert_eq_approx!(basis * Vector3::FORWARD, target.normalized());
+    assert_eq_approx!(basis.inverse(), basis.transposed());
+    assert_eq_approx!(basis.inverse() * target.normalized(), Vector3::FORWARD);
+
+    let model_basis = Basis::new_looking_at(target, Vector3::UP, true);
+    assert_eq_approx!(model_basis * Vector3::BACK, target.normalized());
+}
+
 #[itest]
 fn basis_equiv() {
     let inner = InnerBasis::from_outer(&TEST_BASIS);
@@ -7,7 +7,7 @@
 
 use crate::framework::{expect_panic, itest};
 use godot::builtin::math::assert_eq_approx;
-use godot::builtin::{Quaternion, Vector3};
+use godot::builtin::{EulerOrder, Quaternion, Vector3};
 
 #[itest]
 fn quaternion_default() {
@@ -50,6 +50,49 @@ fn quaternion_from_axis_angle() {
     });
 }
 
+#[itest]
+fn quaternion_to_axis_angle() {
+    let axis = Vector3::new(1.0, 2.0, -2.0).normalized();
+    let quat = Quaternion::from_axis_angle(axis, 1.2);
+
+    let (actual_axis, actual_angle) = quat.to_axis_angle();
+    assert_eq_approx!(actual_axis, axis);
+    assert_eq_approx!(actual_angle, 1.2);
+}
+
+#[itest]
+fn quaternion_euler_roundtrip() {
+    let euler = Vector3::new(0.3, -1.1, 0.7);
+    let quat = Quaternion::from_euler(euler);
+
+    assert!(quat.is_normalized());
+    assert_eq_approx!(quat.to_euler(EulerOrder::YXZ), euler);
+}
+
+#[itest]
+fn quaternion_angle_to() {
+    let from = Quaternion::from_axis_angle(Vector3::UP, 0.25);
+    let to = Quaternion::from_axis_angle(Vector3::UP, 1.0);
+
+    assert_eq_approx!(from.angle_to(to), 0.75);
+    assert_eq_approx!(to.angle_to(from), 0.75);
+    assert_eq_approx!(from.angle_to(from), 0.0);
+}
+
+#[itest]
+fn quaternion_rotate_toward() {
+    let from = Quaternion::default();
+    let to = Quaternion::from_axis_angle(Vector3::UP, 1.0);
+
+    let step = from.rotate_toward(to, 0.25);
+    assert_eq_approx!(step, Quaternion::from_axis_angle(Vector3::UP, 0.25));
+    assert_eq_approx!(step.angle_to(to), 0.75);
+
+    // Does not overshoot.
+    assert_eq_approx!(from.rotate_toward(to, 2.0), to);
+    assert_eq_approx!(to.rotate_toward(to, 0.5), to);
+}
+
 #[itest]
 fn quaternion_normalization() {
     // 1. Should panic on quaternions with length 0.
@@ -82,3 +82,39 @@ fn transform3d_xform_equiv() {
         "operator: Transform3D * Plane"
     );
 }
+
+#[itest]
+fn transform3d_interpolation() {
+    let axis = Vector3::new(0.0, 1.0, 0.0);
+    let from = Transform3D::IDENTITY;
+    let to = Transform3D::IDENTITY
+        .rotated(axis, 1.0)
+        .translated(Vector3::new(2.0, 4.0, 6.0));
+
+    // End points are reproduced.
+    assert_eq_approx!(from.lerp(to, 0.0), from);
+    assert_eq_approx!(from.lerp(to, 1.0), to);
+    assert_eq_approx!(from.slerp(to, 0.0), from);
+    assert_eq_approx!(from.slerp(to, 1.0), to);
+
+    // Halfway rotates by half the angle and moves half the distance.
+    let expected = Transform3D::IDENTITY
+        .rotated(axis, 0.5)
+        .translated(Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq_approx!(from.lerp(to, 0.5), expected, "function: lerp\n");
+    assert_eq_approx!(from.slerp(to, 0.5), expected, "function: slerp\n");
+    assert_eq_approx!(
+        from.interpolate_with(to, 0.5),
+        expected,
+        "function: interpolate_with\n"
+    );
+
+    // Unlike interpolate_with(), slerp() discards scale.
+    let scaled = to.scaled_local(Vector3::new(2.0, 2.0, 2.0));
+    assert_eq_approx!(
+        from.slerp(scaled, 0.5),
+        expected,
+        "function: slerp (scaled)\n"
+    );
+}
@@ -115,6 +115,10 @@ fn string_name_length() {
 
     let empty = StringName::default();
     assert_eq!(empty.len(), 0);
+
+    // Length counts characters, not UTF-8 bytes.
+    let unicode = StringName::from("héllo ✓");
+    assert_eq!(unicode.len(), 7);
 }
 
 #[itest]
@@ -123,6 +127,8 @@ fn string_name_is_empty() {
     assert!(!name.is_empty());
     let empty = StringName::default();
     assert!(empty.is_empty());
+    let from_empty = StringName::from("");
+    assert!(from_empty.is_empty());
 }
 
 #[itest]
@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Callable, Signal};
+use godot::engine::{AudioStreamPlayer, AudioStreamPlayerExt, SceneTreeTimer};
+use godot::obj::{Gd, NewAlloc};
+
+use crate::framework::{expect_panic, itest, TestContext};
+
+#[itest]
+fn audio_play_after_cancel(ctx: &TestContext) {
+    let mut player = AudioStreamPlayer::new_alloc();
+    ctx.scene_tree.clone().add_child(player.clone().upcast());
+
+    player.play_after(100.0);
+
+    let timer = player
+        .get_meta("_gdext_delayed_play_timer".into())
+        .to::<Gd<SceneTreeTimer>>();
+    let timeout = Signal::from_object_signal(&timer, "timeout");
+    let play = Callable::from_object_method(&player, "play");
+    assert!(timeout.is_connected(play.clone()));
+
+    // Rescheduling replaces the pending timer.
+    player.play_after(100.0);
+    assert!(!timeout.is_connected(play.clone()));
+
+    player.cancel_delayed_play();
+    assert!(!player.has_meta("_gdext_delayed_play_timer".into()));
+    assert!(!player.is_playing());
+
+    // Cancelling without pending playback is a no-op.
+    player.cancel_delayed_play();
+
+    player.free();
+}
+
+#[itest]
+fn audio_play_after_outside_tree() {
+    let mut player = AudioStreamPlayer::new_alloc();
+
+    expect_panic("play_after() outside scene tree", || {
+        player.play_after(1.0);
+    });
+
+    player.free();
+}
@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Color, PackedColorArray, PackedVector2Array, Transform2D, Vector2};
+use godot::engine::{PlaceholderTexture2D, RenderingServer, SpriteBatch2D};
+use godot::obj::NewGd;
+
+use crate::framework::{expect_panic, itest};
+
+fn make_batch() -> SpriteBatch2D {
+    let mut texture = PlaceholderTexture2D::new_gd();
+    texture.set_size(Vector2::new(16.0, 8.0));
+
+    SpriteBatch2D::new(texture.upcast())
+}
+
+#[itest]
+fn sprite_batch_accumulate() {
+    let mut batch = make_batch();
+    assert!(batch.is_empty());
+
+    batch.add_sprite(Transform2D::IDENTITY, Color::WHITE);
+
+    let positions = PackedVector2Array::from(&[Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)]);
+    let tints = PackedColorArray::from(&[Color::RED, Color::BLUE]);
+    batch.add_sprites(&positions, &tints);
+    assert_eq!(batch.len(), 3);
+
+    batch.clear();
+    assert!(batch.is_empty());
+    assert_eq!(batch.texture().get_size(), Vector2::new(16.0, 8.0));
+}
+
+#[itest]
+fn sprite_batch_mismatched_tints() {
+    let mut batch = make_batch();
+    let positions = PackedVector2Array::from(&[Vector2::ZERO, Vector2::ONE]);
+    let tints = PackedColorArray::from(&[Color::RED]);
+
+    expect_panic("one tint per position", || {
+        batch.add_sprites(&positions, &tints);
+    });
+}
+
+#[itest]
+fn sprite_batch_draw() {
+    let mut server = RenderingServer::singleton();
+    let canvas_item = server.canvas_item_create();
+
+    let mut batch = make_batch();
+    batch.draw(canvas_item); // empty: no-op
+
+    batch.add_sprite(
+        Transform2D::IDENTITY.translated(Vector2::new(5.0, 5.0)),
+        Color::WHITE,
+    );
+    batch.draw(canvas_item);
+
+    server.free_rid(canvas_item);
+}
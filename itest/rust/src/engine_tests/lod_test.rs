@@ -0,0 +1,66 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{PackedInt32Array, PackedVector3Array};
+use godot::engine::mesh::ArrayType;
+use godot::engine::{ArrayMesh, BoxMesh, LodMeshBuilder, Mesh};
+use godot::obj::{EngineEnum, Gd, NewGd};
+
+use crate::framework::{expect_panic, itest};
+
+fn make_box() -> Gd<Mesh> {
+    let mut mesh = BoxMesh::new_gd();
+    mesh.set_subdivide_width(8);
+    mesh.set_subdivide_height(8);
+    mesh.set_subdivide_depth(8);
+
+    mesh.upcast()
+}
+
+#[itest]
+fn lod_mesh_builder_build() {
+    let base = make_box();
+    let instance = LodMeshBuilder::new(base.clone())
+        .add_lod(10.0, 0.5)
+        .add_lod(40.0, 0.9)
+        .lod_bias(2.0)
+        .build();
+
+    assert_eq!(instance.get_lod_bias(), 2.0);
+
+    // Surfaces keep their vertices and indices; the LODs are stored alongside.
+    let mesh = instance
+        .get_mesh()
+        .expect("instance has a mesh")
+        .cast::<ArrayMesh>();
+    assert_eq!(mesh.get_surface_count(), base.get_surface_count());
+
+    let vertex = ArrayType::ARRAY_VERTEX.ord() as usize;
+    let index = ArrayType::ARRAY_INDEX.ord() as usize;
+    let (base_arrays, arrays) = (base.surface_get_arrays(0), mesh.surface_get_arrays(0));
+    assert_eq!(
+        arrays.get(vertex).to::<PackedVector3Array>(),
+        base_arrays.get(vertex).to::<PackedVector3Array>()
+    );
+    assert_eq!(
+        arrays.get(index).to::<PackedInt32Array>(),
+        base_arrays.get(index).to::<PackedInt32Array>()
+    );
+
+    instance.free();
+}
+
+#[itest]
+fn lod_mesh_builder_invalid_lod() {
+    expect_panic("reduction must be below 1", || {
+        let _ = LodMeshBuilder::new(make_box()).add_lod(10.0, 1.0);
+    });
+
+    expect_panic("distance must be positive", || {
+        let _ = LodMeshBuilder::new(make_box()).add_lod(0.0, 0.5);
+    });
+}
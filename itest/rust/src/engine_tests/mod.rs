@@ -5,11 +5,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod audio_test;
+mod canvas_test;
 mod codegen_enums_test;
 mod codegen_test;
 mod gfile_test;
+mod lod_test;
 mod native_structures_test;
 mod node_test;
+mod physics_test;
 mod save_load_test;
+mod tile_test;
 mod translate_test;
 mod utilities_test;
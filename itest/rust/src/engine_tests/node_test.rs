@@ -8,10 +8,13 @@
 use std::str::FromStr;
 
 use godot::builtin::{NodePath, Variant};
-use godot::engine::{global, Node, Node3D, NodeExt, PackedScene, SceneTree};
-use godot::obj::{NewAlloc, NewGd};
+use godot::engine::{
+    global, InstancePlaceholder, Node, Node2D, Node3D, NodeExt, PackedScene, SceneTree,
+};
+use godot::obj::{Base, Gd, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
 
-use crate::framework::{itest, TestContext};
+use crate::framework::{expect_panic, itest, TestContext};
 
 #[itest]
 fn node_get_node() {
@@ -50,6 +53,164 @@ fn node_get_node_fail() {
     child.free();
 }
 
+#[itest]
+fn node_get_node_placeholder() {
+    let mut placeholder = InstancePlaceholder::new_alloc();
+    placeholder.set_name("deferred".into());
+
+    let mut parent = Node::new_alloc();
+    parent.add_child(placeholder.clone().upcast());
+
+    // Placeholders are not silently cast to the expected type.
+    let found = parent.try_get_node_as::<Node3D>(NodePath::from("deferred"));
+    assert!(found.is_none());
+
+    let found = parent.try_get_node_as::<InstancePlaceholder>(NodePath::from("deferred"));
+    assert_eq!(found, Some(placeholder));
+
+    expect_panic("get_node_as() on a placeholder", || {
+        parent.get_node_as::<Node3D>(NodePath::from("deferred"));
+    });
+
+    parent.free();
+}
+
+#[itest]
+fn node_deferred_tree_changes(ctx: &TestContext) {
+    let mut parent = Node::new_alloc();
+    let mut new_parent = Node::new_alloc();
+    ctx.scene_tree.clone().add_child(parent.clone());
+    ctx.scene_tree.clone().add_child(new_parent.clone());
+
+    let added = Node::new_alloc();
+    let removed = Node::new_alloc();
+    let mut reparented = Node::new_alloc();
+    parent.add_child(removed.clone());
+    parent.add_child(reparented.clone());
+
+    // Tree changes only take effect at the end of the frame; SpecialTests.gd checks that they do.
+    parent.add_child_deferred(added.clone());
+    parent.remove_child_deferred(removed.clone());
+    reparented.queue_reparent(new_parent.clone());
+
+    assert_eq!(added.get_parent(), None);
+    assert_eq!(removed.get_parent(), Some(parent.clone()));
+    assert_eq!(reparented.get_parent(), Some(parent.clone()));
+
+    // Removed node is no longer owned by the tree; the others are freed along with their parents.
+    // Deferred calls are flushed before nodes queued for deletion are freed.
+    removed.clone().queue_free();
+    parent.queue_free();
+    new_parent.queue_free();
+}
+
+/// Exposes the deferred `NodeExt` helpers to SpecialTests.gd, which can wait for the end of the frame.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct DeferredTreeChanger;
+
+#[godot_api]
+impl DeferredTreeChanger {
+    #[func]
+    fn add_child_deferred(mut parent: Gd<Node>, child: Gd<Node>) {
+        parent.add_child_deferred(child);
+    }
+
+    #[func]
+    fn remove_child_deferred(mut parent: Gd<Node>, child: Gd<Node>) {
+        parent.remove_child_deferred(child);
+    }
+
+    #[func]
+    fn queue_reparent(mut node: Gd<Node>, new_parent: Gd<Node>) {
+        node.queue_reparent(new_parent);
+    }
+}
+
+#[itest]
+fn node_children_of_type() {
+    // root
+    // ├─ a: Node3D
+    // │  └─ a1: Node3D
+    // ├─ b: Node
+    // │  └─ b1: Node3D
+    // └─ c: Node2D
+    let mut root = Node::new_alloc();
+    let mut a = Node3D::new_alloc();
+    let a1 = Node3D::new_alloc();
+    let mut b = Node::new_alloc();
+    let b1 = Node3D::new_alloc();
+    let c = Node2D::new_alloc();
+
+    a.add_child(a1.clone().upcast());
+    b.add_child(b1.clone().upcast());
+    root.add_child(a.clone().upcast());
+    root.add_child(b.clone());
+    root.add_child(c.clone().upcast());
+
+    assert_eq!(root.children_of_type::<Node3D>(), vec![a.clone()]);
+    assert_eq!(root.children_of_type::<Node2D>(), vec![c]);
+    assert_eq!(root.children_of_type::<Node>().len(), 3);
+
+    // Non-matching `b` is still searched.
+    assert_eq!(root.descendants_of_type::<Node3D>(), vec![a, a1, b1]);
+    assert_eq!(root.descendants_of_type::<Node>().len(), 5);
+    assert!(b.descendants_of_type::<Node2D>().is_empty());
+
+    root.free();
+}
+
+#[itest]
+fn node_get_or_add_child() {
+    let mut root = Node::new_alloc();
+
+    let first = root.get_or_add_child::<Node3D>("Pivot");
+    assert_eq!(first.get_name(), "Pivot".into());
+    assert_eq!(root.get_child_count(), 1);
+
+    // Second call finds the existing child.
+    let second = root.get_or_add_child::<Node3D>("Pivot");
+    assert_eq!(second, first);
+    assert_eq!(root.get_child_count(), 1);
+
+    // Base classes match as well.
+    let as_node = root.get_or_add_child::<Node>("Pivot");
+    assert_eq!(as_node, first.upcast());
+
+    expect_panic("get_or_add_child() with mismatched type", || {
+        root.get_or_add_child::<Node2D>("Pivot");
+    });
+    assert_eq!(root.get_child_count(), 1);
+
+    root.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node, scene = "res://SceneBoundNode.tscn")]
+struct SceneBoundNode {
+    base: Base<Node>,
+}
+
+// Points to a scene whose root has a different class.
+#[derive(GodotClass)]
+#[class(init, base=Node, scene = "res://SceneBoundNode.tscn")]
+struct SceneBoundMismatch {
+    base: Base<Node>,
+}
+
+#[itest]
+fn node_scene_bound_instantiate() {
+    assert_eq!(SceneBoundNode::SCENE_PATH, "res://SceneBoundNode.tscn");
+
+    let node = SceneBoundNode::instantiate();
+    assert_eq!(node.get_name(), "SceneBoundNode".into());
+    node.free();
+
+    expect_panic("instantiate() with mismatched root class", || {
+        SceneBoundMismatch::instantiate();
+    });
+}
+
 #[itest]
 fn node_path_from_str(ctx: &TestContext) {
     let child = ctx.scene_tree.clone();
@@ -91,3 +252,66 @@ fn node_call_group(ctx: &TestContext) {
     node.add_to_group("group".into());
     tree.call_group("group".into(), "set_name".into(), &[Variant::from("name")]);
 }
+
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct RemoteSyncNode {
+    #[var]
+    #[remote_sync]
+    health: i32,
+
+    #[export]
+    #[remote_sync(replication_mode = on_change, no_spawn)]
+    nickname: godot::builtin::GString,
+
+    base: Base<Node>,
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn node_remote_sync_deferred(ctx: &TestContext) {
+    let mut node = RemoteSyncNode::new_alloc().upcast::<Node>();
+    ctx.scene_tree.clone().add_child(node.clone());
+
+    // The synchronizer is added at the end of the frame, so entering the tree (and thus ready) doesn't modify the children.
+    assert_eq!(node.get_child_count(), 0);
+    assert!(!node.has_node("RemoteSynchronizer".into()));
+
+    // The synchronizer, once added, is freed together with the node. Its config is checked after a frame in SpecialTests.gd.
+    node.queue_free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn node_remote_sync_config() {
+    use godot::engine::scene_replication_config::ReplicationMode;
+
+    let config = godot::private::make_remote_sync_config(&[
+        ("health", ReplicationMode::REPLICATION_MODE_ALWAYS, true),
+        (
+            "nickname",
+            ReplicationMode::REPLICATION_MODE_ON_CHANGE,
+            false,
+        ),
+    ]);
+
+    let health = NodePath::from(".:health");
+    let nickname = NodePath::from(".:nickname");
+
+    let properties = config.get_properties();
+    assert_eq!(properties.len(), 2);
+    assert_eq!(properties.get(0), health);
+    assert_eq!(properties.get(1), nickname);
+
+    assert_eq!(
+        config.property_get_replication_mode(health.clone()),
+        ReplicationMode::REPLICATION_MODE_ALWAYS
+    );
+    assert_eq!(
+        config.property_get_replication_mode(nickname.clone()),
+        ReplicationMode::REPLICATION_MODE_ON_CHANGE
+    );
+    assert!(config.property_get_spawn(health));
+    assert!(!config.property_get_spawn(nickname));
+}
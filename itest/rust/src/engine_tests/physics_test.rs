@@ -0,0 +1,83 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::meta::ToGodot;
+use godot::builtin::Vector3;
+use godot::engine::physics_server_3d::BodyMode;
+use godot::engine::{
+    CharacterBody3D, CharacterBody3DExt, PhysicsDirectSpaceState3DExt, PhysicsRayQueryParameters3D,
+    PhysicsServer3D,
+};
+use godot::obj::NewAlloc;
+
+use crate::framework::{itest, TestContext};
+
+#[itest]
+fn physics_intersect_ray_typed() {
+    let mut server = PhysicsServer3D::singleton();
+
+    // Box with half extents 1, centered at the origin. Created through the server, so there is no collider node.
+    let space = server.space_create();
+    let shape = server.box_shape_create();
+    server.shape_set_data(shape, Vector3::new(1.0, 1.0, 1.0).to_variant());
+
+    let body = server.body_create();
+    server.body_set_mode(body, BodyMode::STATIC);
+    server.body_add_shape(body, shape);
+    server.body_set_space(body, space);
+
+    let mut state = server
+        .space_get_direct_state(space)
+        .expect("direct space state available");
+
+    let query = PhysicsRayQueryParameters3D::create(Vector3::new(0.0, 0.0, 5.0), Vector3::ZERO);
+    let hit = state.intersect_ray_typed(query).expect("ray hits the box");
+    assert_eq_approx!(hit.position, Vector3::new(0.0, 0.0, 1.0));
+    assert_eq_approx!(hit.normal, Vector3::new(0.0, 0.0, 1.0));
+    assert_eq!(hit.collider, None);
+    assert_eq!(hit.rid, body);
+    assert_eq!(hit.shape_idx, 0);
+
+    let query = PhysicsRayQueryParameters3D::create(
+        Vector3::new(5.0, 0.0, 5.0),
+        Vector3::new(5.0, 0.0, -5.0),
+    );
+    assert_eq!(state.intersect_ray_typed(query), None);
+
+    server.free_rid(body);
+    server.free_rid(shape);
+    server.free_rid(space);
+}
+
+#[itest]
+fn physics_move_and_slide_ex(ctx: &TestContext) {
+    let mut body = CharacterBody3D::new_alloc();
+    ctx.scene_tree.clone().add_child(body.clone().upcast());
+
+    let previous_max_angle = body.get_floor_max_angle();
+
+    // Nothing to collide with.
+    let collided = body
+        .move_and_slide_ex()
+        .velocity(Vector3::new(2.0, 0.0, 0.0))
+        .up_direction(Vector3::BACK)
+        .floor_stop_on_slope(false)
+        .max_slides(2)
+        .apply();
+    assert!(!collided);
+
+    assert_eq!(body.get_velocity(), Vector3::new(2.0, 0.0, 0.0));
+    assert_eq!(body.get_up_direction(), Vector3::BACK);
+    assert!(!body.is_floor_stop_on_slope_enabled());
+    assert_eq!(body.get_max_slides(), 2);
+
+    // Unspecified properties are left unchanged.
+    assert_eq!(body.get_floor_max_angle(), previous_max_angle);
+
+    body.queue_free();
+}
@@ -0,0 +1,44 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Vector2i, Vector3i};
+use godot::engine::{GridMap, GridMapExt, TileMap, TileMapExt};
+use godot::obj::NewAlloc;
+
+use crate::framework::itest;
+
+#[itest]
+fn tile_map_iter_used_cells() {
+    let mut map = TileMap::new_alloc();
+    assert_eq!(map.iter_used_cells(0).len(), 0);
+
+    for coords in [Vector2i::new(1, 2), Vector2i::new(-3, 4)] {
+        map.set_cell_ex(0, coords)
+            .source_id(0)
+            .atlas_coords(Vector2i::ZERO)
+            .done();
+    }
+
+    let mut cells: Vec<Vector2i> = map.iter_used_cells(0).collect();
+    cells.sort_by_key(|cell| (cell.x, cell.y));
+    assert_eq!(cells, vec![Vector2i::new(-3, 4), Vector2i::new(1, 2)]);
+
+    map.free();
+}
+
+#[itest]
+fn grid_map_iter_used_cells() {
+    let mut map = GridMap::new_alloc();
+    assert_eq!(map.iter_used_cells().len(), 0);
+
+    map.set_cell_item(Vector3i::new(1, 2, 3), 0);
+
+    let cells: Vec<Vector3i> = map.iter_used_cells().collect();
+    assert_eq!(cells, vec![Vector3i::new(1, 2, 3)]);
+
+    map.free();
+}
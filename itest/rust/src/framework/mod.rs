@@ -123,6 +123,33 @@ pub fn expect_panic(context: &str, code: impl FnOnce()) {
     );
 }
 
+/// Like [`expect_panic`], but also checks that the panic message contains `expected_message`.
+pub fn expect_panic_with_message(context: &str, expected_message: &str, code: impl FnOnce()) {
+    use std::panic;
+
+    let prev_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_panic_info| {}));
+
+    let code = panic::AssertUnwindSafe(code);
+    let panic = panic::catch_unwind(code);
+    panic::set_hook(prev_hook);
+
+    let Err(payload) = panic else {
+        panic!("code should have panicked but did not: {context}");
+    };
+
+    let message = payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap_or("(non-string panic payload)");
+
+    assert!(
+        message.contains(expected_message),
+        "panic message should contain `{expected_message}`: {context}\n  actual: {message}",
+    );
+}
+
 /// Disable printing errors from Godot. Ideally we should catch and handle errors, ensuring they happen when
 /// expected. But that isn't possible, so for now we can just disable printing the error to avoid spamming
 /// the terminal when tests should error.
@@ -132,6 +159,30 @@ pub fn suppress_godot_print(mut f: impl FnMut()) {
     Engine::singleton().set_print_error_messages(true);
 }
 
+/// Runs `f` and returns the error messages that `#[func]` methods returning `Err` or failing to bind for `#[func(try_mut)]` logged in
+/// the meantime, in order.
+///
+/// The messages are still pushed to Godot; combine with [`suppress_godot_print`] to keep the terminal clean.
+pub fn capture_func_errors(f: impl FnOnce()) -> Vec<String> {
+    let errors = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let errors_in_hook = errors.clone();
+    godot::private::set_func_error_hook(Some(Box::new(move |message| {
+        errors_in_hook.lock().unwrap().push(message.to_string());
+    })));
+
+    // Remove the hook even if `f` panics, so that it doesn't leak into other tests.
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+    godot::private::set_func_error_hook(None);
+
+    if let Err(panic) = result {
+        std::panic::resume_unwind(panic);
+    }
+
+    let errors = errors.lock().unwrap();
+    errors.clone()
+}
+
 /// Some tests are disabled, as they rely on Godot checks which are only available in Debug builds.
 /// See https://github.com/godotengine/godot/issues/86264.
 pub fn runs_release() -> bool {
@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::framework::itest;
+use crate::framework::{itest, TestContext};
 use godot::prelude::*;
 
 #[itest(skip)]
@@ -134,8 +134,37 @@ impl Based {
     }
 }
 
+#[itest]
+fn base_queue_free_safe(ctx: &TestContext) {
+    let mut obj = QueueFreeSafe::new_alloc();
+
+    // Not inside tree: nothing happens.
+    obj.bind_mut().queue_free_safe();
+    assert!(!obj.is_queued_for_deletion());
+
+    let mut tree = ctx.scene_tree.clone();
+    tree.add_child(obj.clone().upcast());
+
+    obj.bind_mut().queue_free_safe();
+    assert!(obj.is_queued_for_deletion());
+
+    // Already queued: no second call.
+    obj.bind_mut().queue_free_safe();
+    assert!(obj.is_queued_for_deletion());
+
+    // Remove from tree and free directly, so the test doesn't depend on the next frame.
+    tree.remove_child(obj.clone().upcast());
+    obj.free();
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Node2D)]
 struct Baseless {
     // No need for fields, we just test if we can access this as Gd<Node2D>.
 }
+
+#[derive(GodotClass)]
+#[class(init, base=Node, queue_free_safe)]
+struct QueueFreeSafe {
+    base: Base<Node>,
+}
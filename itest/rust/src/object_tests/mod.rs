@@ -9,6 +9,7 @@ mod base_test;
 mod class_rename_test;
 mod object_swap_test;
 mod object_test;
+mod oneditor_test;
 mod onready_test;
 mod property_template_test;
 mod property_test;
@@ -12,10 +12,10 @@ use godot::builtin::meta::GodotType;
 use godot::builtin::meta::{FromGodot, ToGodot};
 use godot::builtin::{GString, StringName, Variant, Vector3};
 use godot::engine::{
-    file_access, Area2D, Camera3D, Engine, FileAccess, IRefCounted, Node, Node3D, Object,
-    RefCounted,
+    file_access, Area2D, Camera3D, Engine, FileAccess, GDScript, IRefCounted, Node, Node3D, Object,
+    RefCounted, Script, ScriptExtension,
 };
-use godot::obj::{Base, Gd, Inherits, InstanceId, NewAlloc, NewGd, RawGd};
+use godot::obj::{Base, Gd, Inherits, InstanceId, NewAlloc, NewGd, OnReady, RawGd};
 use godot::register::{godot_api, GodotClass};
 use godot::sys::{self, interface_fn, GodotFfi};
 
@@ -105,6 +105,29 @@ fn object_user_display() {
     assert_eq!(actual, expected);
 }
 
+#[itest]
+fn object_user_display_inherent() {
+    let obj = InherentToString::new_gd();
+
+    let actual = format!(".:{obj}:.");
+    let expected = ".:inherent=42:.".to_string();
+
+    assert_eq!(actual, expected);
+}
+
+#[itest]
+fn object_pointer_format() {
+    let obj = Node3D::new_alloc();
+    let copy = obj.clone();
+
+    let actual = format!("{obj:p}");
+    assert_eq!(actual, format!("{:p}", obj.obj_sys()));
+    assert_eq!(actual, format!("{copy:p}"));
+    assert!(actual.starts_with("0x"));
+
+    obj.free();
+}
+
 #[itest]
 fn object_engine_display() {
     let obj = Node3D::new_alloc();
@@ -162,6 +185,36 @@ fn object_from_invalid_instance_id() {
         .expect_err("invalid instance id should not return a valid object");
 }
 
+#[itest]
+fn object_weak_upgrade() {
+    let node: Gd<Node3D> = Node3D::new_alloc();
+    let weak = node.downgrade();
+    assert_eq!(weak.instance_id(), node.instance_id());
+
+    let upgraded = weak.upgrade().expect("object is alive");
+    assert_eq!(upgraded, node);
+
+    node.free();
+    assert!(weak.upgrade().is_none());
+}
+
+#[itest]
+fn object_weak_upgrade_refcounted() {
+    let obj = Gd::from_object(RefcPayload { value: 123 });
+    let weak = obj.downgrade();
+
+    // Weak reference does not contribute to the reference count.
+    assert_eq!(obj.get_reference_count(), 1);
+
+    let upgraded = weak.upgrade().expect("object is alive");
+    assert_eq!(upgraded.bind().value, 123);
+    assert_eq!(obj.get_reference_count(), 2);
+
+    drop(upgraded);
+    drop(obj);
+    assert!(weak.upgrade().is_none());
+}
+
 #[itest]
 fn object_from_instance_id_inherits_type() {
     let descr = GString::from("some very long description");
@@ -237,6 +290,41 @@ fn object_user_free_during_bind() {
     obj.free(); // now succeeds
 }
 
+#[itest]
+fn object_user_unchecked_borrows() {
+    let mut obj = Gd::from_object(RefcPayload { value: 1 });
+
+    // SAFETY: no guards or other references to the instance exist while these are used.
+    unsafe {
+        let instance = obj.as_mut_unchecked();
+        for _ in 0..10 {
+            instance.value += 1;
+        }
+
+        assert_eq!(obj.as_ref_unchecked().value, 11);
+    }
+
+    // No borrow is registered, so guards can be obtained right away.
+    assert_eq!(obj.bind().value, 11);
+    obj.bind_mut().value = 20;
+
+    // SAFETY: the guard above has been dropped.
+    let value = unsafe { obj.as_ref_unchecked().value };
+    assert_eq!(value, 20);
+}
+
+#[itest]
+fn object_user_unchecked_borrow_after_free() {
+    let obj = Gd::from_object(ObjPayload {});
+    let copy = obj.clone();
+    obj.free();
+
+    expect_panic("as_ref_unchecked() on dead user object", move || {
+        // SAFETY: panics before a reference is created.
+        let _ = unsafe { copy.as_ref_unchecked() };
+    });
+}
+
 #[itest]
 fn object_engine_freed_argument_passing(ctx: &TestContext) {
     let node: Gd<Node> = Node::new_alloc();
@@ -874,6 +962,26 @@ fn object_get_scene_tree(ctx: &TestContext) {
     assert_eq!(count, 1);
 } // implicitly tested: node does not leak
 
+#[itest]
+fn object_get_script_typed() {
+    let mut obj = Object::new_alloc();
+    assert!(obj.get_script_typed::<GDScript>().is_none());
+
+    let mut script = GDScript::new_gd();
+    script.set_source_code("extends Object".into());
+    script.reload();
+    obj.set_script(script.to_variant());
+
+    let typed = obj
+        .get_script_typed::<GDScript>()
+        .expect("GDScript attached");
+    assert_eq!(typed, script);
+    assert!(obj.get_script_typed::<Script>().is_some());
+    assert!(obj.get_script_typed::<ScriptExtension>().is_none());
+
+    obj.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(GodotClass)]
@@ -923,6 +1031,33 @@ impl IRefCounted for RefcPayload {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Overrides `to_string()` through `_to_string` in an inherent impl, instead of `IRefCounted::to_string`.
+#[derive(GodotClass)]
+#[class(init)]
+pub struct InherentToString {
+    #[init(default = 42)]
+    value: i32,
+}
+
+#[godot_api]
+impl InherentToString {
+    fn _to_string(&self) -> GString {
+        format!("inherent={}", self.value).into()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+// Compile-time test: user-provided #[allow] attributes on fields are honored, even if no generated code reads the field.
+#[derive(GodotClass)]
+#[class(no_init)]
+struct UnusedFieldAllowed {
+    #[allow(dead_code)]
+    unused: i32,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[derive(GodotClass, Eq, PartialEq, Debug)]
 #[class(no_init)]
 pub struct Tracker {
@@ -1033,6 +1168,140 @@ fn custom_constructor_works() {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+#[derive(GodotClass)]
+#[class(init, after_init = configure)]
+struct AfterInit {
+    #[init(default = 3)]
+    lives: i64,
+    max_lives: i64,
+}
+
+impl AfterInit {
+    fn configure(&mut self) {
+        // Fields already hold their defaults.
+        self.max_lives = self.lives * 2;
+        self.lives += 1;
+    }
+}
+
+#[itest]
+fn object_after_init() {
+    let obj = AfterInit::new_gd();
+    assert_eq!(obj.bind().lives, 4);
+    assert_eq!(obj.bind().max_lives, 6);
+}
+
+thread_local! {
+    static INIT_ORDER: std::cell::RefCell<Vec<&'static str>> = Default::default();
+}
+
+fn record_init(name: &'static str) -> i64 {
+    INIT_ORDER.with(|order| order.borrow_mut().push(name));
+    0
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct InitInDeclarationOrder {
+    #[init(default = record_init("first"))]
+    first: i64,
+    #[init(default = record_init("before_base"))]
+    before_base: i64,
+    base: Base<RefCounted>,
+    #[init(default = record_init("second"))]
+    second: i64,
+    #[init(default = record_init("third"))]
+    third: i64,
+}
+
+#[itest]
+fn object_init_declaration_order() {
+    INIT_ORDER.with(|order| order.borrow_mut().clear());
+
+    let obj = InitInDeclarationOrder::new_gd();
+    let order = INIT_ORDER.with(|order| order.take());
+    assert_eq!(order, ["first", "before_base", "second", "third"]);
+
+    let obj = obj.bind();
+    assert_eq!(
+        (obj.first, obj.before_base, obj.second, obj.third),
+        (0, 0, 0, 0)
+    );
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, resettable)]
+struct ResettableObj {
+    #[init(default = 3)]
+    lives: i64,
+    score: i64,
+    #[init(default = GString::from("Player"))]
+    name: GString,
+    #[init(default = OnReady::manual())]
+    level: OnReady<i64>,
+    base: Base<RefCounted>,
+}
+
+#[itest]
+fn object_reset_to_defaults() {
+    let mut obj = ResettableObj::new_gd();
+    {
+        let mut guard = obj.bind_mut();
+        guard.lives = 0;
+        guard.score = 1200;
+        guard.name = "Renamed".into();
+        guard.level.init(4);
+    }
+
+    obj.bind_mut().reset_to_defaults();
+
+    let guard = obj.bind();
+    assert_eq!(guard.lives, 3);
+    assert_eq!(guard.score, 0);
+    assert_eq!(guard.name, GString::from("Player"));
+
+    // OnReady<T> fields are not reset, as they are initialized outside init.
+    assert_eq!(*guard.level, 4);
+
+    // The base object is kept.
+    assert_eq!(guard.base().instance_id(), obj.instance_id());
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, from_default)]
+struct InitFromDefault {
+    name: GString,
+    volume: f32,
+
+    #[init(default = 60)]
+    max_fps: i32,
+}
+
+impl Default for InitFromDefault {
+    fn default() -> Self {
+        Self {
+            name: "settings".into(),
+            volume: 0.5,
+            max_fps: 30,
+        }
+    }
+}
+
+#[itest]
+fn object_init_from_default() {
+    let obj = InitFromDefault::new_gd();
+    let settings = obj.bind();
+
+    // Non-annotated fields come from the struct's `Default` impl, not from their types' defaults.
+    assert_eq!(settings.name, GString::from("settings"));
+    assert_eq!(settings.volume, 0.5);
+    assert_eq!(settings.max_fps, 60);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[derive(GodotClass)]
 #[class(init, base=Object)]
 struct DoubleUse {
@@ -0,0 +1,69 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::{expect_panic_with_message, itest, suppress_godot_print};
+use godot::engine::notify::NodeNotification;
+use godot::engine::Node;
+use godot::register::GodotClass;
+
+use godot::obj::{Gd, NewAlloc, OnEditor};
+use godot::prelude::ToGodot;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct OnEditorTarget {
+    #[export]
+    target: OnEditor<Gd<Node>>,
+}
+
+#[itest]
+fn oneditor_assigned() {
+    let mut obj = OnEditorTarget::new_alloc();
+    let target = Node::new_alloc();
+
+    // Like the inspector, assign through the property.
+    obj.set("target".into(), target.to_variant());
+    obj.notify(NodeNotification::Ready);
+
+    assert_eq!(*obj.bind().target, target);
+    assert_eq!(obj.get("target".into()), target.to_variant());
+
+    target.free();
+    obj.free();
+}
+
+#[itest]
+fn oneditor_unassigned() {
+    let mut obj = OnEditorTarget::new_alloc();
+
+    // Reads as null from Godot.
+    assert!(obj.get("target".into()).is_nil());
+
+    // The property is named even before ready().
+    let access = obj.clone();
+    expect_panic_with_message(
+        "access to unassigned OnEditor before ready() panics",
+        "property `OnEditorTarget.target` must be set in the inspector",
+        move || {
+            let _target = access.bind().target.clone();
+        },
+    );
+
+    // Validation before ready() reports a Godot error, but doesn't panic.
+    suppress_godot_print(|| obj.notify(NodeNotification::Ready));
+
+    let access = obj.clone();
+    expect_panic_with_message(
+        "access to unassigned OnEditor panics",
+        "property `OnEditorTarget.target` must be set in the inspector",
+        move || {
+            let _target = access.bind().target.clone();
+        },
+    );
+
+    obj.free();
+}
@@ -5,12 +5,14 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::framework::{expect_panic, itest};
+use std::cell::RefCell;
+
+use crate::framework::{expect_panic, itest, TestContext};
 use godot::engine::notify::NodeNotification;
-use godot::engine::INode;
+use godot::engine::{INode, Node};
 use godot::register::{godot_api, GodotClass};
 
-use godot::obj::{Gd, OnReady};
+use godot::obj::{Base, Gd, NewAlloc, OnReady};
 use godot::prelude::ToGodot;
 
 #[itest]
@@ -120,6 +122,27 @@ fn onready_lifecycle_with_impl_without_ready() {
     obj.free();
 }
 
+#[itest]
+fn onready_timing(ctx: &TestContext) {
+    TIMING_LOG.with(|log| log.borrow_mut().clear());
+
+    let mut scene_tree = ctx.scene_tree.clone();
+    let mut obj = OnReadyTiming::new_alloc();
+    scene_tree.add_child(obj.clone().upcast());
+
+    // Initialized after enter_tree(), right before ready().
+    assert_eq!(take_timing_log(), ["enter_tree", "init", "ready"]);
+
+    // A second ready() keeps the value and does not run the initializer again.
+    obj.clone().upcast::<Node>().request_ready();
+    scene_tree.remove_child(obj.clone().upcast());
+    scene_tree.add_child(obj.clone().upcast());
+    assert_eq!(take_timing_log(), ["enter_tree", "ready"]);
+    assert_eq!(*obj.bind().auto, 7);
+
+    obj.free();
+}
+
 #[itest]
 fn onready_property_access() {
     let mut obj = OnReadyWithImpl::create(true);
@@ -234,3 +257,46 @@ impl INode for OnReadyWithoutImpl {
     // Declare another function to ensure virtual getter must be provided.
     fn process(&mut self, _delta: f64) {}
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+thread_local! {
+    static TIMING_LOG: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+fn log_timing(event: &'static str) {
+    TIMING_LOG.with(|log| log.borrow_mut().push(event));
+}
+
+fn take_timing_log() -> Vec<&'static str> {
+    TIMING_LOG.with(|log| std::mem::take(&mut *log.borrow_mut()))
+}
+
+#[derive(GodotClass)]
+#[class(base=Node)]
+struct OnReadyTiming {
+    auto: OnReady<i32>,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for OnReadyTiming {
+    fn init(base: Base<Node>) -> Self {
+        Self {
+            auto: OnReady::new(|| {
+                log_timing("init");
+                7
+            }),
+            base,
+        }
+    }
+
+    fn enter_tree(&mut self) {
+        log_timing("enter_tree");
+    }
+
+    fn ready(&mut self) {
+        assert_eq!(*self.auto, 7);
+        log_timing("ready");
+    }
+}
@@ -6,10 +6,17 @@
  */
 
 use godot::builtin::meta::{GodotConvert, ToGodot};
-use godot::builtin::{dict, Color, Dictionary, GString, Variant, VariantType};
+use godot::builtin::{
+    array, dict, varray, Array, Basis, Color, Dictionary, EulerOrder, GString, NodePath,
+    PackedColorArray, PackedFloat64Array, PackedInt64Array, PackedVector2Array, PackedVector3Array,
+    StringName, Transform2D, Transform3D, Variant, VariantArray, VariantType, Vector2, Vector3,
+};
 use godot::engine::global::{PropertyHint, PropertyUsageFlags};
-use godot::engine::{INode, IRefCounted, Node, Object, RefCounted, Texture};
-use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, NewAlloc, NewGd};
+use godot::engine::{
+    Area3D, CharacterBody3D, INode, IRefCounted, Node, Object, PlaceholderTexture2D, RefCounted,
+    Resource, Texture, Texture2D,
+};
+use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, Inherits, NewAlloc, NewGd};
 use godot::register::property::{Export, PropertyHintInfo, Var};
 use godot::register::{godot_api, Export, GodotClass, GodotConvert, Var};
 use godot::test::itest;
@@ -301,6 +308,12 @@ struct CheckAllExports {
 
     #[export(color_no_alpha)]
     color_no_alpha: Color,
+
+    #[export(hint = INT_IS_OBJECTID)]
+    int_is_objectid: i64,
+
+    #[export(hint = INT_IS_POINTER)]
+    int_is_pointer: i64,
 }
 
 #[derive(GodotConvert, Var, Export, Eq, PartialEq, Debug)]
@@ -350,11 +363,7 @@ impl IRefCounted for DeriveExport {
 fn derive_export() {
     let class = DeriveExport::new_gd();
 
-    let property = class
-        .get_property_list()
-        .iter_shared()
-        .find(|c| c.get_or_nil("name") == "foo".to_variant())
-        .unwrap();
+    let property = find_property(&class, "foo");
     // `class_name` should be empty for non-Object variants.
     check_property(&property, "class_name", "");
     check_property(&property, "type", VariantType::Int as i32);
@@ -380,17 +389,17 @@ pub struct ExportResource {
 
     #[export]
     pub bar: Option<Gd<RenamedCustomResource>>,
+
+    // Same flags as `foo`, without spelling them out.
+    #[export(new_resource)]
+    pub baz: Option<Gd<CustomResource>>,
 }
 
 #[itest]
 fn export_resource() {
     let class = ExportResource::new_alloc();
 
-    let property = class
-        .get_property_list()
-        .iter_shared()
-        .find(|c| c.get_or_nil("name") == "foo".to_variant())
-        .unwrap();
+    let property = find_property(&class, "foo");
     check_property(&property, "class_name", "CustomResource");
     check_property(&property, "type", VariantType::Object as i32);
     check_property(&property, "hint", PropertyHint::RESOURCE_TYPE.ord());
@@ -401,20 +410,991 @@ fn export_resource() {
         PropertyUsageFlags::DEFAULT.ord() | PropertyUsageFlags::EDITOR_INSTANTIATE_OBJECT.ord(),
     );
 
-    let property = class
-        .get_property_list()
-        .iter_shared()
-        .find(|c| c.get_or_nil("name") == "bar".to_variant())
-        .unwrap();
+    let property = find_property(&class, "bar");
     check_property(&property, "class_name", "NewNameCustomResource");
     check_property(&property, "type", VariantType::Object as i32);
     check_property(&property, "hint", PropertyHint::RESOURCE_TYPE.ord());
     check_property(&property, "hint_string", "NewNameCustomResource");
     check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
 
+    let property = find_property(&class, "baz");
+    check_property(&property, "class_name", "CustomResource");
+    check_property(&property, "hint", PropertyHint::RESOURCE_TYPE.ord());
+    check_property(&property, "hint_string", "CustomResource");
+    check_property(
+        &property,
+        "usage",
+        PropertyUsageFlags::DEFAULT.ord() | PropertyUsageFlags::EDITOR_INSTANTIATE_OBJECT.ord(),
+    );
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportTexture {
+    #[export]
+    sprite: Option<Gd<Texture2D>>,
+
+    #[export]
+    any_texture: Option<Gd<Texture>>,
+}
+
+#[itest]
+fn export_texture() {
+    let mut node = ExportTexture::new_alloc();
+
+    // The RESOURCE_TYPE hint with a texture class is what enables the inspector's thumbnail preview.
+    for (name, class) in [("sprite", "Texture2D"), ("any_texture", "Texture")] {
+        let property = find_property(&node, name);
+        check_property(&property, "class_name", class);
+        check_property(&property, "type", VariantType::Object as i32);
+        check_property(&property, "hint", PropertyHint::RESOURCE_TYPE.ord());
+        check_property(&property, "hint_string", class);
+        check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
+    }
+
+    // Assigning and clearing, like the inspector does.
+    let texture = PlaceholderTexture2D::new_gd().upcast::<Texture2D>();
+    node.set("sprite".into(), texture.to_variant());
+    assert_eq!(node.bind().sprite, Some(texture.clone()));
+    assert_eq!(node.get("sprite".into()), texture.to_variant());
+
+    node.set("sprite".into(), Variant::nil());
+    assert_eq!(node.bind().sprite, None);
+    assert!(node.get("sprite".into()).is_nil());
+
+    node.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+struct ExportEmitChanged {
+    #[export]
+    damage: i32,
+
+    #[export(no_emit_changed)]
+    notes: GString,
+
+    base: Base<Resource>,
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn export_emit_changed() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let mut stats = ExportEmitChanged::new_gd();
+    let changed = Arc::new(AtomicUsize::new(0));
+    let _handle = stats.subscribe("changed", {
+        let changed = changed.clone();
+        move |_| {
+            changed.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    stats.set("damage".into(), 12.to_variant());
+    assert_eq!(stats.bind().damage, 12);
+    assert_eq!(changed.load(Ordering::SeqCst), 1);
+
+    stats.set("notes".into(), "sharp".to_variant());
+    assert_eq!(stats.bind().notes, GString::from("sharp"));
+    assert_eq!(changed.load(Ordering::SeqCst), 1);
+
+    // Direct field access bypasses the setter.
+    stats.bind_mut().damage = 20;
+    assert_eq!(changed.load(Ordering::SeqCst), 1);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node, export_all)]
+pub struct ExportAll {
+    pub int_val: i64,
+
+    #[export(range = (0.0, 10.0))]
+    pub range_val: f64,
+
+    #[var]
+    pub var_val: GString,
+
+    #[no_export]
+    pub hidden_val: i32,
+
+    pub base: Base<Node>,
+}
+
+#[itest]
+fn export_all() {
+    let class = ExportAll::new_alloc();
+
+    let property = find_property(&class, "int_val");
+    check_property(&property, "type", VariantType::Int as i32);
+    check_property(&property, "hint", PropertyHint::NONE.ord());
+    check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
+
+    let property = find_property(&class, "range_val");
+    check_property(&property, "hint", PropertyHint::RANGE.ord());
+    check_property(&property, "hint_string", "0,10");
+
+    let property = find_property(&class, "var_val");
+    check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
+
+    assert!(try_find_property(&class, "hidden_val").is_none());
+    assert!(try_find_property(&class, "base").is_none());
+
+    class.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+pub struct ExportRangeMaxFrom {
+    #[export]
+    #[init(default = 50)]
+    pub max_health: i32,
+
+    #[export(range = (0.0, 100.0, 0.5, or_less), range_max_from = "max_health")]
+    pub health: f64,
+
+    #[export(range_max_from = "max_health")]
+    pub shield: f32,
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn export_range_max_from() {
+    let mut class = ExportRangeMaxFrom::new_alloc();
+
+    let property = find_property(&class, "health");
+    check_property(&property, "hint", PropertyHint::RANGE.ord());
+    check_property(&property, "hint_string", "0,50,0.5,or_less");
+
+    let property = find_property(&class, "shield");
+    check_property(&property, "hint", PropertyHint::RANGE.ord());
+    check_property(&property, "hint_string", "0,50");
+
+    class.bind_mut().max_health = 75;
+    let property = find_property(&class, "health");
+    check_property(&property, "hint_string", "0,75,0.5,or_less");
+
+    // Unrelated properties are unchanged.
+    let property = find_property(&class, "max_health");
+    check_property(&property, "hint", PropertyHint::NONE.ord());
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportNodePath {
+    #[export(node_path, types = [CharacterBody3D, Area3D])]
+    target: NodePath,
+
+    #[export(node_path)]
+    any_node: NodePath,
+}
+
+#[itest]
+fn export_node_path() {
+    let class = ExportNodePath::new_alloc();
+
+    let property = find_property(&class, "target");
+    check_property(&property, "type", VariantType::NodePath as i32);
+    check_property(&property, "hint", PropertyHint::NODE_PATH_VALID_TYPES.ord());
+    check_property(&property, "hint_string", "CharacterBody3D,Area3D");
+
+    let property = find_property(&class, "any_node");
+    check_property(&property, "hint", PropertyHint::NODE_PATH_VALID_TYPES.ord());
+    check_property(&property, "hint_string", "");
+
     class.free();
 }
 
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportArrayElement {
+    #[export(element = i64)]
+    scores: VariantArray,
+
+    #[export(element = Gd<Node>)]
+    targets: VariantArray,
+}
+
+#[itest]
+fn export_array_element() {
+    let mut class = ExportArrayElement::new_alloc();
+
+    let property = find_property(&class, "scores");
+    check_property(&property, "type", VariantType::Array as i32);
+    check_property(&property, "hint", PropertyHint::ARRAY_TYPE.ord());
+    check_property(&property, "hint_string", "int");
+
+    let property = find_property(&class, "targets");
+    check_property(&property, "hint", PropertyHint::ARRAY_TYPE.ord());
+    check_property(&property, "hint_string", "Node");
+
+    // The field stays untyped.
+    let scores = varray![1, 2, 3];
+    class.set("scores".into(), scores.to_variant());
+    assert_eq!(class.bind().scores, scores);
+
+    // The editor assigns typed arrays, which are copied into an untyped array.
+    let typed_scores: Array<i64> = array![4, 5];
+    class.set("scores".into(), typed_scores.to_variant());
+    assert_eq!(class.bind().scores, varray![4, 5]);
+    // Only untyped arrays convert to VariantArray.
+    assert_eq!(
+        class.bind().scores.to_variant().to::<VariantArray>(),
+        varray![4, 5]
+    );
+
+    class.free();
+}
+
+#[cfg(since_api = "4.4")]
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportTypedDictionary {
+    #[export(dict = "String:int")]
+    scores: Dictionary,
+}
+
+#[cfg(since_api = "4.4")]
+#[itest]
+fn export_typed_dictionary() {
+    let mut class = ExportTypedDictionary::new_alloc();
+
+    let property = find_property(&class, "scores");
+    check_property(&property, "type", VariantType::Dictionary as i32);
+    check_property(&property, "hint", PropertyHint::DICTIONARY_TYPE.ord());
+    check_property(&property, "hint_string", "String;int");
+
+    let scores = dict! { "alice": 3, "bob": 5 };
+    class.set("scores".into(), scores.to_variant());
+    assert_eq!(class.get("scores".into()), scores.to_variant());
+    assert_eq!(class.bind().scores, scores);
+
+    class.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportReadOnly {
+    #[export]
+    #[init(default = 100)]
+    max_health: i32,
+
+    #[export(read_only)]
+    kills: i32,
+
+    #[export(read_only, range_max_from = "max_health")]
+    health: i32,
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn export_read_only() {
+    let mut class = ExportReadOnly::new_alloc();
+    let read_only = PropertyUsageFlags::DEFAULT | PropertyUsageFlags::READ_ONLY;
+
+    let property = find_property(&class, "kills");
+    check_property(&property, "usage", read_only.ord());
+    check_property(&property, "hint", PropertyHint::NONE.ord());
+
+    // Read-only and dynamic range are applied together.
+    let property = find_property(&class, "health");
+    check_property(&property, "usage", read_only.ord());
+    check_property(&property, "hint", PropertyHint::RANGE.ord());
+    check_property(&property, "hint_string", "0,100");
+
+    let property = find_property(&class, "max_health");
+    check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
+
+    // Only the editor is restricted; the property can still be set.
+    class.set("kills".into(), 3.to_variant());
+    assert_eq!(class.bind().kills, 3);
+
+    class.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ValidatePropertyUser {
+    #[export]
+    #[init(default = 10)]
+    max_ammo: i32,
+
+    #[export(range_max_from = "max_ammo")]
+    ammo: i32,
+
+    #[export]
+    infinite: bool,
+
+    #[export]
+    label: GString,
+}
+
+#[cfg(since_api = "4.2")]
+#[godot_api]
+impl INode for ValidatePropertyUser {
+    fn validate_property(&self, property: &mut godot::builtin::meta::PropertyInfo) {
+        if property.property_name.to_string() == "ammo" && self.infinite {
+            property.usage = PropertyUsageFlags::NO_EDITOR;
+        }
+
+        if property.property_name.to_string() == "label" {
+            property.hint = PropertyHint::PLACEHOLDER_TEXT;
+            property.hint_string = "Enter label".into();
+        }
+    }
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn validate_property_user() {
+    let mut class = ValidatePropertyUser::new_alloc();
+
+    // Hints of range_max_from are still applied.
+    let property = find_property(&class, "ammo");
+    check_property(&property, "hint", PropertyHint::RANGE.ord());
+    check_property(&property, "hint_string", "0,10");
+    check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
+
+    let property = find_property(&class, "label");
+    check_property(&property, "type", VariantType::String as i32);
+    check_property(&property, "hint", PropertyHint::PLACEHOLDER_TEXT.ord());
+    check_property(&property, "hint_string", "Enter label");
+
+    class.bind_mut().infinite = true;
+    let property = find_property(&class, "ammo");
+    check_property(&property, "hint_string", "0,10");
+    check_property(&property, "usage", PropertyUsageFlags::NO_EDITOR.ord());
+
+    class.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn validate_property_unknown_hint() {
+    use godot::sys;
+
+    let class = ValidatePropertyUser::new_alloc();
+
+    // Like a hint added in a newer Godot version, which this API version cannot represent.
+    const UNKNOWN_HINT: u32 = 9999;
+    let property = godot::builtin::meta::PropertyInfo {
+        variant_type: VariantType::String,
+        class_name: godot::builtin::meta::ClassName::none(),
+        property_name: "label".into(),
+        hint: PropertyHint::NONE,
+        hint_string: GString::new(),
+        usage: PropertyUsageFlags::DEFAULT,
+    };
+    let mut info = property.property_sys();
+    info.hint = UNKNOWN_HINT;
+
+    // Invoke the callback directly, since Godot itself only passes known hints.
+    let is_changed = unsafe {
+        let instance = sys::interface_fn!(object_get_instance_binding)(
+            class.obj_sys(),
+            sys::get_library(),
+            std::ptr::null(),
+        );
+        godot::private::callbacks::validate_property_user::<ValidatePropertyUser>(
+            instance as sys::GDExtensionClassInstancePtr,
+            std::ptr::addr_of_mut!(info),
+        )
+    };
+
+    // The user's validate_property() is skipped, instead of panicking; the info is left untouched.
+    assert_eq!(is_changed, false as sys::GDExtensionBool);
+    assert_eq!(info.hint, UNKNOWN_HINT);
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportSecret {
+    #[export(secret)]
+    api_key: GString,
+}
+
+#[itest]
+fn export_secret() {
+    let class = ExportSecret::new_alloc();
+    let property = find_property(&class, "api_key");
+
+    check_property(&property, "type", VariantType::String as i32);
+    check_property(&property, "hint", PropertyHint::PASSWORD.ord());
+    check_property(&property, "hint_string", "");
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportPackedArrays {
+    #[export]
+    vector2s: PackedVector2Array,
+
+    #[export]
+    vector3s: PackedVector3Array,
+
+    #[export]
+    colors: PackedColorArray,
+
+    #[export]
+    float64s: PackedFloat64Array,
+
+    #[export]
+    int64s: PackedInt64Array,
+}
+
+#[itest]
+fn export_packed_arrays() {
+    let mut class = ExportPackedArrays::new_alloc();
+
+    let cases = [
+        (
+            "vector2s",
+            VariantType::PackedVector2Array,
+            PackedVector2Array::from(&[Vector2::new(1.5, -2.0), Vector2::ZERO]).to_variant(),
+        ),
+        (
+            "vector3s",
+            VariantType::PackedVector3Array,
+            PackedVector3Array::from(&[Vector3::new(1.0, 2.5, -3.0), Vector3::UP]).to_variant(),
+        ),
+        (
+            "colors",
+            VariantType::PackedColorArray,
+            PackedColorArray::from(&[Color::from_rgba(0.1, 0.2, 0.3, 0.4), Color::WHITE])
+                .to_variant(),
+        ),
+        (
+            "float64s",
+            VariantType::PackedFloat64Array,
+            PackedFloat64Array::from(&[f64::MIN_POSITIVE, -127.83156478, 1e300]).to_variant(),
+        ),
+        (
+            "int64s",
+            VariantType::PackedInt64Array,
+            PackedInt64Array::from(&[i64::MIN, -1, i64::MAX]).to_variant(),
+        ),
+    ];
+
+    for (name, variant_type, value) in cases {
+        let property = find_property(&class, name);
+
+        check_property(&property, "type", variant_type as i32);
+        check_property(&property, "hint", PropertyHint::NONE.ord());
+
+        class.set(name.into(), value.clone());
+        assert_eq!(
+            class.get(name.into()),
+            value,
+            "round-trip of property `{name}`"
+        );
+    }
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node, typed_accessors)]
+struct TypedPropertyAccess {
+    #[export]
+    health: i64,
+
+    #[export]
+    #[var(get = get_name_upper)]
+    name: GString,
+}
+
+#[godot_api]
+impl TypedPropertyAccess {
+    #[func]
+    fn get_name_upper(&self) -> GString {
+        self.name.to_string().to_uppercase().into()
+    }
+}
+
+#[itest]
+fn export_typed_accessors() {
+    let mut class = TypedPropertyAccess::new_alloc();
+
+    TypedPropertyAccess::property_set_health(&mut class, 77);
+    assert_eq!(class.bind().health, 77);
+    assert_eq!(TypedPropertyAccess::property_get_health(&class), 77);
+
+    // Custom getters are invoked.
+    TypedPropertyAccess::property_set_name(&mut class, "enemy".into());
+    assert_eq!(class.bind().name, GString::from("enemy"));
+    assert_eq!(
+        TypedPropertyAccess::property_get_name(&class),
+        GString::from("ENEMY")
+    );
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportTransforms {
+    #[export]
+    transform_3d: Transform3D,
+
+    #[export]
+    transform_2d: Transform2D,
+
+    #[export]
+    basis: Basis,
+}
+
+// Godot's inspector has no decomposed (position/rotation/scale) widget for custom transform properties; it edits the raw matrix. Nodes
+// get a decomposed view only because they expose separate `position`, `rotation` and `scale` properties. So there is no special hint
+// to check for, just the variant type and an exact round-trip.
+#[itest]
+fn export_transforms() {
+    let mut class = ExportTransforms::new_alloc();
+
+    let basis = Basis::from_euler(EulerOrder::YXZ, Vector3::new(0.3, -1.2, 2.5))
+        .scaled(Vector3::new(2.0, 0.5, 1.5));
+    let cases = [
+        (
+            "transform_3d",
+            VariantType::Transform3D,
+            Transform3D::new(basis, Vector3::new(1.5, -2.25, 8.0)).to_variant(),
+        ),
+        (
+            "transform_2d",
+            VariantType::Transform2D,
+            Transform2D::from_angle_scale_skew_origin(
+                0.7,
+                Vector2::new(2.0, 3.0),
+                0.1,
+                Vector2::new(-4.0, 5.5),
+            )
+            .to_variant(),
+        ),
+        ("basis", VariantType::Basis, basis.to_variant()),
+    ];
+
+    for (name, variant_type, value) in cases {
+        let property = find_property(&class, name);
+
+        check_property(&property, "type", variant_type as i32);
+        check_property(&property, "hint", PropertyHint::NONE.ord());
+
+        // Values are stored as-is, without decomposing and recomposing, so the round-trip is exact (not just approximate).
+        class.set(name.into(), value.clone());
+        assert_eq!(
+            class.get(name.into()),
+            value,
+            "round-trip of property `{name}`"
+        );
+    }
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportDefaults {
+    #[export(default = 3)]
+    lives: i32,
+
+    #[export(range = (0.0, 10.0), default = 1.5)]
+    speed: f64,
+
+    #[export(default = GString::from("hero"))]
+    #[init(default = GString::from("villain"))]
+    title: GString,
+
+    #[export]
+    #[init(default = 42)]
+    init_default: i32,
+
+    #[export]
+    #[init(default = i32::MAX)]
+    init_default_const: i32,
+
+    // Not a constant, so the revert value must be declared in #[export].
+    #[export(default = GString::from("computed"))]
+    export_default_computed: GString,
+
+    #[export(default = Vector2::new(1.0, 2.0))]
+    export_default_vector: Vector2,
+
+    #[var]
+    #[init(default = 5)]
+    var_only: i32,
+
+    #[export]
+    no_default: i32,
+}
+
+#[itest]
+fn export_default_revert() {
+    let mut class = ExportDefaults::new_alloc();
+
+    // Generated init uses the export default, unless #[init(default)] overrides it.
+    assert_eq!(class.bind().lives, 3);
+    assert_eq!(class.bind().speed, 1.5);
+    assert_eq!(class.bind().title, GString::from("villain"));
+    assert_eq!(class.bind().no_default, 0);
+
+    class.bind_mut().lives = 7;
+
+    let cases = [
+        ("lives", 3.to_variant()),
+        ("speed", 1.5.to_variant()),
+        ("title", "hero".to_variant()),
+    ];
+    for (name, revert) in cases {
+        assert!(
+            class.property_can_revert(name.into()),
+            "can revert `{name}`"
+        );
+        assert_eq!(class.property_get_revert(name.into()), revert);
+    }
+
+    assert!(!class.property_can_revert("no_default".into()));
+    assert!(!class.property_can_revert("var_only".into()));
+
+    class.free();
+}
+
+#[itest]
+fn export_init_default_revert() {
+    let mut class = ExportDefaults::new_alloc();
+    assert_eq!(class.bind().init_default, 42);
+    assert_eq!(
+        class.property_get_revert("init_default_const".into()),
+        i32::MAX.to_variant()
+    );
+
+    assert_eq!(
+        class.bind().export_default_computed,
+        GString::from("computed")
+    );
+    assert_eq!(
+        class.property_get_revert("export_default_computed".into()),
+        "computed".to_variant()
+    );
+    assert_eq!(class.bind().export_default_vector, Vector2::new(1.0, 2.0));
+    assert_eq!(
+        class.property_get_revert("export_default_vector".into()),
+        Vector2::new(1.0, 2.0).to_variant()
+    );
+
+    class.bind_mut().init_default = -1;
+    assert!(class.property_can_revert("init_default".into()));
+
+    // Reverting like the inspector does: assign the revert value.
+    let revert = class.property_get_revert("init_default".into());
+    assert_eq!(revert, 42.to_variant());
+    class.set("init_default".into(), revert);
+    assert_eq!(class.bind().init_default, 42);
+
+    class.free();
+}
+
+// Fields removed by #[cfg] are stripped by the compiler before the derive macro runs, so they are neither initialized nor registered.
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct CfgGatedExports {
+    #[cfg(all())]
+    #[export]
+    #[init(default = 7)]
+    enabled: i32,
+
+    #[cfg(any())]
+    #[export]
+    #[init(default = compile_error!("Removed by #[cfg]"))]
+    disabled: TypeThatDoesNotExist,
+}
+
+#[itest]
+fn export_cfg_gated_fields() {
+    let class = CfgGatedExports::new_alloc();
+    assert_eq!(class.bind().enabled, 7);
+
+    assert!(try_find_property(&class, "enabled").is_some());
+    assert!(try_find_property(&class, "disabled").is_none());
+
+    class.free();
+}
+
+// Classes referring to each other are registered before either registers its properties.
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+pub struct CircularFirst {
+    #[export]
+    second: Option<Gd<CircularSecond>>,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+pub struct CircularSecond {
+    #[export]
+    first: Option<Gd<CircularFirst>>,
+}
+
+#[itest]
+fn export_circular_class_references() {
+    let first = CircularFirst::new_gd();
+    let second = CircularSecond::new_gd();
+
+    let property = find_property(&first, "second");
+    check_property(&property, "class_name", "CircularSecond");
+    check_property(&property, "hint_string", "CircularSecond");
+
+    let property = find_property(&second, "first");
+    check_property(&property, "class_name", "CircularFirst");
+    check_property(&property, "hint_string", "CircularFirst");
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportCustomHint {
+    #[export(custom_type_hint = PROPERTY_HINT_NODE_PATH_VALID_TYPES, hint_string = "Node2D,CollisionShape2D")]
+    target: NodePath,
+
+    #[export(custom_type_hint = EXPRESSION)]
+    formula: GString,
+}
+
+#[itest]
+fn export_custom_type_hint() {
+    let class = ExportCustomHint::new_alloc();
+
+    let property = find_property(&class, "target");
+    check_property(&property, "hint", PropertyHint::NODE_PATH_VALID_TYPES.ord());
+    check_property(&property, "hint_string", "Node2D,CollisionShape2D");
+    check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
+
+    let property = find_property(&class, "formula");
+    check_property(&property, "hint", PropertyHint::EXPRESSION.ord());
+    check_property(&property, "hint_string", "");
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportEnumStrings {
+    #[export(enum = ["Fire Ball", "Ice Spear", "Heal"])]
+    spell: i64,
+}
+
+#[itest]
+fn export_enum_string_literals() {
+    let class = ExportEnumStrings::new_alloc();
+
+    let property = find_property(&class, "spell");
+    check_property(&property, "hint", PropertyHint::ENUM.ord());
+    check_property(&property, "hint_string", "Fire Ball,Ice Spear,Heal");
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportOrder {
+    #[export(order = 3)]
+    last: i32,
+
+    // Not a property, so it doesn't count towards the index of the following fields.
+    base: Base<Node>,
+
+    #[export]
+    first: i32,
+
+    #[export(order = 1)]
+    second: i32,
+
+    // Property index 3, tie with `last` which is declared earlier.
+    #[export]
+    fourth: i32,
+
+    #[export(order = 2)]
+    third: i32,
+}
+
+#[itest]
+fn export_order() {
+    let names = ["first", "second", "third", "last", "fourth"];
+
+    let class = ExportOrder::new_alloc();
+    let actual: Vec<String> = class
+        .get_property_list()
+        .iter_shared()
+        .map(|prop| prop.get_or_nil("name").to::<GString>().to_string())
+        .filter(|name| names.contains(&name.as_str()))
+        .collect();
+
+    assert_eq!(actual, names);
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Resource, before_save_fn = reverse_for_save, after_load_fn = reverse_after_load)]
+struct StorageHooks {
+    #[export]
+    layout: GString,
+
+    after_load_calls: i32,
+    base: Base<Resource>,
+}
+
+impl StorageHooks {
+    fn reverse_for_save(&self, property: StringName, value: Variant) -> Variant {
+        assert_eq!(property, StringName::from("layout"));
+        reversed(&value.to::<GString>()).to_variant()
+    }
+
+    fn reverse_after_load(&mut self, property: StringName) {
+        assert_eq!(property, StringName::from("layout"));
+        self.layout = reversed(&self.layout);
+        self.after_load_calls += 1;
+    }
+}
+
+fn reversed(string: &GString) -> GString {
+    string.to_string().chars().rev().collect::<String>().into()
+}
+
+#[itest]
+fn storage_hooks_get_set() {
+    let mut resource = StorageHooks::new_gd();
+    resource.bind_mut().layout = "abc".into();
+
+    // Godot reads (and would save) the processed form; the field itself is unchanged.
+    assert_eq!(resource.get("layout".into()), "cba".to_variant());
+    assert_eq!(resource.bind().layout, GString::from("abc"));
+
+    // Assigning from Godot runs the setter, then the after-load hook.
+    resource.set("layout".into(), "zyx".to_variant());
+    assert_eq!(resource.bind().layout, GString::from("xyz"));
+    assert_eq!(resource.bind().after_load_calls, 1);
+
+    // Other properties are not affected.
+    resource.set("resource_name".into(), "level".to_variant());
+    assert_eq!(resource.get("resource_name".into()), "level".to_variant());
+    assert_eq!(resource.bind().after_load_calls, 1);
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+enum DoorState {
+    Closed,
+    Open,
+    Locked(GString),
+}
+
+fn property_names(object: &Gd<DoorState>) -> Vec<String> {
+    object
+        .get_property_list()
+        .iter_shared()
+        .map(|prop| prop.get_or_nil("name").to::<GString>().to_string())
+        .filter(|name| name == "state" || name == "locked")
+        .collect()
+}
+
+#[itest]
+fn state_machine_properties() {
+    let mut door = DoorState::new_gd();
+    assert_eq!(door.bind().state_index(), 0);
+    assert_eq!(door.get("state".into()), 0.to_variant());
+    assert_eq!(property_names(&door), ["state"]);
+
+    let state = find_property(&door, "state");
+    check_property(&state, "type", VariantType::Int as i32);
+    check_property(&state, "hint", PropertyHint::ENUM.ord());
+    check_property(&state, "hint_string", "Closed,Open,Locked");
+
+    // Data of the active variant is listed as its own property.
+    door.call("to_locked".into(), &["cellar".to_variant()]);
+    assert!(matches!(&*door.bind(), DoorState::Locked(key) if key == &GString::from("cellar")));
+    assert_eq!(door.get("state".into()), 2.to_variant());
+    assert_eq!(door.get("locked".into()), "cellar".to_variant());
+    assert_eq!(property_names(&door), ["state", "locked"]);
+
+    door.set("locked".into(), "attic".to_variant());
+    assert_eq!(door.get("locked".into()), "attic".to_variant());
+
+    // Re-assigning the current state keeps the data; other states start with default data.
+    door.set("state".into(), 2.to_variant());
+    assert_eq!(door.get("locked".into()), "attic".to_variant());
+
+    door.set("state".into(), 1.to_variant());
+    assert_eq!(door.bind().state_index(), 1);
+    assert_eq!(door.get("locked".into()), Variant::nil());
+    assert_eq!(property_names(&door), ["state"]);
+
+    door.set("state".into(), 2.to_variant());
+    assert_eq!(door.get("locked".into()), GString::new().to_variant());
+
+    // Invalid indices are rejected.
+    door.set("state".into(), 7.to_variant());
+    assert_eq!(door.bind().state_index(), 2);
+
+    door.bind_mut().to_closed();
+    assert_eq!(door.get("state".into()), 0.to_variant());
+}
+
+#[derive(GodotClass)]
+#[class(init, introspect)]
+struct ExportIntrospect {
+    #[export(range = (0.0, 100.0))]
+    health: f64,
+
+    #[var]
+    not_exported: i32,
+
+    #[export]
+    texture: Option<Gd<Texture2D>>,
+}
+
+#[itest]
+fn export_introspect() {
+    let infos = ExportIntrospect::exported_property_infos();
+    let names: Vec<String> = infos
+        .iter()
+        .map(|info| info.property_name.to_string())
+        .collect();
+    assert_eq!(names, ["health", "texture"]);
+
+    let health = &infos[0];
+    assert_eq!(health.variant_type, VariantType::Float);
+    assert_eq!(health.hint, PropertyHint::RANGE);
+    assert_eq!(health.hint_string, GString::from("0,100"));
+    assert_eq!(health.usage, PropertyUsageFlags::DEFAULT);
+
+    let texture = &infos[1];
+    assert_eq!(texture.variant_type, VariantType::Object);
+    assert_eq!(texture.hint, PropertyHint::RESOURCE_TYPE);
+    assert_eq!(texture.hint_string, GString::from("Texture2D"));
+}
+
+/// Returns the entry of the property `name` in the object's property list.
+fn find_property<T: Inherits<Object>>(object: &Gd<T>, name: &str) -> Dictionary {
+    try_find_property(object, name).unwrap_or_else(|| panic!("property `{name}` not listed"))
+}
+
+fn try_find_property<T: Inherits<Object>>(object: &Gd<T>, name: &str) -> Option<Dictionary> {
+    object
+        .upcast_ref::<Object>()
+        .get_property_list()
+        .iter_shared()
+        .find(|property| property.get_or_nil("name") == name.to_variant())
+}
+
 fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
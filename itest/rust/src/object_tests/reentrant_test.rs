@@ -5,8 +5,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::framework::itest;
+use crate::framework::{
+    capture_func_errors, expect_panic, expect_panic_with_message, itest, suppress_godot_print,
+};
 use godot::prelude::*;
+use godot::sys;
 
 #[derive(GodotClass)]
 #[class(init, base = Object)]
@@ -41,6 +44,18 @@ impl ReentrantClass {
     fn second(&mut self) {
         self.second_called = true;
     }
+
+    // Calls back into the object while it is bound by `&self`, so `&mut self` methods cannot bind it.
+    #[func]
+    fn first_reads(&self, method: StringName) -> Variant {
+        self.to_gd().upcast::<Object>().call(method, &[])
+    }
+
+    #[func(try_mut)]
+    fn second_try(&mut self) -> i64 {
+        self.second_called = true;
+        7
+    }
 }
 
 #[itest]
@@ -79,3 +94,67 @@ fn reentrant_emit_succeeds() {
 
     class.free()
 }
+
+#[itest]
+fn reentrant_mut_call_while_bound() {
+    let mut class = ReentrantClass::new_alloc();
+
+    // Without `try_mut`, binding fails with a panic, which surfaces as a failed call.
+    suppress_godot_print(|| {
+        let mut class = class.clone();
+        expect_panic("&mut self method called while bound", move || {
+            class.call("first_reads".into(), &["second".to_variant()]);
+        });
+    });
+    assert!(!class.bind().second_called);
+
+    // With `try_mut`, an error naming the method is reported and the default value returned.
+    let errors = capture_func_errors(|| {
+        suppress_godot_print(|| {
+            let result = class.call("first_reads".into(), &["second_try".to_variant()]);
+            assert_eq!(result, 0.to_variant());
+        })
+    });
+    assert_eq!(errors.len(), 1);
+    assert!(
+        errors[0].starts_with("cannot call ReentrantClass::second_try(): it takes `&mut self`"),
+        "unexpected error: {}",
+        errors[0]
+    );
+    assert!(!class.bind().second_called);
+
+    // Unbound, the method runs normally.
+    let result = class.call("second_try".into(), &[]);
+    assert_eq!(result, 7.to_variant());
+    assert!(class.bind().second_called);
+
+    class.free()
+}
+
+#[itest]
+fn reentrant_mut_call_panic_message() {
+    let class = ReentrantClass::new_alloc();
+
+    // Godot reports panics in #[func] methods as failed calls, so invoke the binding of the generated code directly.
+    // SAFETY: the binding of a user object is its instance storage, which is alive until the object is freed below.
+    let storage = unsafe {
+        let instance = sys::interface_fn!(object_get_instance_binding)(
+            class.obj_sys(),
+            sys::get_library(),
+            std::ptr::null(),
+        );
+        godot::private::as_storage::<ReentrantClass>(instance as sys::GDExtensionClassInstancePtr)
+    };
+
+    let guard = class.bind();
+    expect_panic_with_message(
+        "&mut self method called while bound",
+        "cannot call ReentrantClass::second(): it takes `&mut self`, but the object is already bound",
+        || {
+            let _instance = godot::private::bind_mut_for_call(storage, "second");
+        },
+    );
+    drop(guard);
+
+    class.free()
+}
@@ -7,8 +7,9 @@
 
 use crate::framework::itest;
 use godot::builtin::GString;
-use godot::engine::{Input, Os};
-use godot::obj::Gd;
+use godot::engine::{Engine, Input, Object, Os};
+use godot::obj::{Base, Gd};
+use godot::register::{godot_api, GodotClass};
 
 #[itest]
 fn singleton_is_unique() {
@@ -43,3 +44,41 @@ fn singleton_is_operational() {
     let read_value = os.get_environment(key);
     assert_eq!(read_value, value);
 }
+
+#[derive(GodotClass)]
+#[class(init, base=Object, singleton = "ItestUserSingleton")]
+struct UserSingleton {
+    #[init(default = 7)]
+    counter: i64,
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl UserSingleton {
+    #[func]
+    fn increment(&mut self) -> i64 {
+        self.counter += 1;
+        self.counter
+    }
+}
+
+#[itest]
+fn singleton_user_registered() {
+    assert_eq!(UserSingleton::SINGLETON_NAME, "ItestUserSingleton");
+
+    let engine = Engine::singleton();
+    assert!(engine.has_singleton("ItestUserSingleton".into()));
+
+    let a = UserSingleton::singleton();
+    let b = engine
+        .get_singleton("ItestUserSingleton".into())
+        .expect("singleton registered")
+        .cast::<UserSingleton>();
+    assert_eq!(a.instance_id(), b.instance_id());
+
+    // State is shared across accesses; reset to leave the singleton as it was.
+    let before = a.bind().counter;
+    let after = UserSingleton::singleton().bind_mut().increment();
+    assert_eq!(after, before + 1);
+    UserSingleton::singleton().bind_mut().counter = before;
+}
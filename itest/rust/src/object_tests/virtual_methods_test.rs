@@ -15,12 +15,14 @@ use godot::builtin::{
     PackedInt32Array, PackedStringArray, PackedVector2Array, PackedVector3Array, RealConv,
     StringName, Variant, VariantArray, Vector2, Vector3,
 };
+use godot::engine::global::JoyButton;
 use godot::engine::notify::NodeNotification;
 use godot::engine::resource_loader::CacheMode;
 use godot::engine::{
-    BoxMesh, INode, INode2D, IPrimitiveMesh, IRefCounted, IResourceFormatLoader, IRigidBody2D,
-    InputEvent, InputEventAction, Node, Node2D, PrimitiveMesh, RefCounted, ResourceFormatLoader,
-    ResourceLoader, Viewport, Window,
+    BoxMesh, Control, IControl, INode, INode2D, IPrimitiveMesh, IRefCounted, IResourceFormatLoader,
+    IRigidBody2D, InputEvent, InputEventAction, InputEventJoypadButton, InputMap, Node, Node2D,
+    PrimitiveMesh, RefCounted, ResourceFormatLoader, ResourceLoader, ShortcutHandler,
+    VBoxContainer, Viewport, Window,
 };
 use godot::obj::{Base, Gd, NewAlloc, NewGd};
 use godot::private::class_macros::assert_eq_approx;
@@ -80,6 +82,28 @@ impl INode2D for VirtualReadyTest {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass, Debug)]
+#[class(init, base=Node)]
+struct VirtualChildTreeTest {
+    events: Vec<String>,
+    base: Base<Node>,
+}
+
+#[cfg(since_api = "4.2")]
+#[godot_api]
+impl INode for VirtualChildTreeTest {
+    fn on_child_entered_tree(&mut self, child: Gd<Node>) {
+        self.events.push(format!("entered {}", child.get_name()));
+    }
+
+    fn on_child_exiting_tree(&mut self, child: Gd<Node>) {
+        self.events.push(format!("exiting {}", child.get_name()));
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[derive(GodotClass, Debug)]
 #[class(base=Node2D)]
 struct VirtualTreeTest {
@@ -154,6 +178,29 @@ impl INode2D for VirtualInputTest {
     }
 }
 
+#[derive(GodotClass)]
+#[class(init, base=Control)]
+struct VirtualShortcutTest {
+    base: Base<Control>,
+    event: Option<Gd<InputEvent>>,
+    actions: Vec<StringName>,
+}
+
+#[godot_api]
+impl IControl for VirtualShortcutTest {
+    fn shortcut_input(&mut self, event: Gd<InputEvent>) {
+        self.event = Some(event.clone());
+        self.route_shortcut_input(event);
+    }
+}
+
+impl ShortcutHandler for VirtualShortcutTest {
+    fn on_shortcut_activated(&mut self, action: &StringName) -> bool {
+        self.actions.push(action.clone());
+        *action == StringName::from("gdext_test_shortcut")
+    }
+}
+
 #[derive(GodotClass, Debug)]
 #[class(init, base=ResourceFormatLoader)]
 struct FormatLoaderTest {
@@ -282,6 +329,21 @@ impl IRefCounted for SetTest {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+#[derive(GodotClass)]
+#[class(init, base=Control)]
+struct MinSizeControl {
+    min_size: Vector2,
+}
+
+#[godot_api]
+impl IControl for MinSizeControl {
+    fn get_minimum_size(&self) -> Vector2 {
+        self.min_size
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[itest]
 fn test_to_string() {
     let _obj = VirtualMethodTest::new_gd();
@@ -300,6 +362,64 @@ fn test_ready(test_context: &TestContext) {
     assert_eq!(obj.bind().implementation_value, 1);
 }
 
+#[cfg(since_api = "4.2")]
+#[itest]
+fn test_child_tree_hooks(test_context: &TestContext) {
+    let mut obj = VirtualChildTreeTest::new_alloc();
+
+    let mut test_node = test_context.scene_tree.clone();
+    test_node.add_child(obj.clone().upcast());
+
+    let mut child = Node::new_alloc();
+    child.set_name("Child".into());
+    obj.add_child(child.clone());
+    obj.remove_child(child.clone());
+
+    // Re-entering the tree must not connect the hooks a second time.
+    test_node.remove_child(obj.clone().upcast());
+    test_node.add_child(obj.clone().upcast());
+    obj.add_child(child.clone());
+
+    assert_eq!(
+        obj.bind().events,
+        ["entered Child", "exiting Child", "entered Child"]
+    );
+
+    obj.free();
+}
+
+#[itest]
+fn test_get_minimum_size(test_context: &TestContext) {
+    let mut control = MinSizeControl::new_alloc();
+    control.bind_mut().min_size = Vector2::new(40.0, 25.0);
+    assert_eq!(
+        control.get_combined_minimum_size(),
+        Vector2::new(40.0, 25.0)
+    );
+
+    // Layout containers must take the minimum size into account.
+    let mut container = VBoxContainer::new_alloc();
+    container.add_child(control.clone().upcast());
+
+    let mut test_node = test_context.scene_tree.clone();
+    test_node.add_child(container.clone().upcast());
+    assert_eq!(
+        container.get_combined_minimum_size(),
+        Vector2::new(40.0, 25.0)
+    );
+
+    // Changing the minimum size is picked up after invalidating the cache.
+    control.bind_mut().min_size = Vector2::new(60.0, 10.0);
+    control.update_minimum_size();
+    assert_eq!(
+        container.get_combined_minimum_size(),
+        Vector2::new(60.0, 10.0)
+    );
+
+    test_node.remove_child(container.clone().upcast());
+    container.free();
+}
+
 #[itest]
 fn test_ready_multiple_fires(test_context: &TestContext) {
     let obj = VirtualReadyTest::new_alloc();
@@ -454,6 +574,36 @@ fn test_input_event(test_context: &TestContext) {
     test_viewport.queue_free();
 }
 
+#[itest]
+fn test_shortcut_input(test_context: &TestContext) {
+    let obj = VirtualShortcutTest::new_alloc();
+    let mut test_viewport = Window::new_alloc();
+
+    test_context
+        .scene_tree
+        .clone()
+        .add_child(test_viewport.clone().upcast());
+    test_viewport.add_child(obj.clone().upcast());
+
+    let mut event = InputEventJoypadButton::new_gd();
+    event.set_button_index(JoyButton::MISC1);
+    event.set_pressed(true);
+
+    let action = StringName::from("gdext_test_shortcut");
+    let mut input_map = InputMap::singleton();
+    input_map.add_action(action.clone());
+    input_map.action_add_event(action.clone(), event.clone().upcast());
+
+    // Joypad buttons are shortcut events, so they reach _shortcut_input.
+    test_viewport.push_input(event.clone().upcast());
+
+    assert_eq!(obj.bind().event, Some(event.upcast::<InputEvent>()));
+    assert!(obj.bind().actions.contains(&action));
+
+    input_map.erase_action(action);
+    test_viewport.queue_free();
+}
+
 // We were incrementing/decrementing the refcount wrong. Which only showed up if you had multiple virtual
 // methods handle the same refcounted object. Related to https://github.com/godot-rust/gdext/issues/257.
 #[itest]
@@ -563,6 +713,57 @@ fn test_set_sets_correct() {
     assert_eq!(obj.bind().settable, 500);
 }
 
+#[derive(GodotClass)]
+#[class(init, export_dispatch)]
+struct ExportedGetSetTest {
+    #[export]
+    speed: f64,
+    #[export]
+    label: GString,
+}
+
+#[godot_api]
+impl IRefCounted for ExportedGetSetTest {
+    fn get_property(&self, property: StringName) -> Option<Variant> {
+        match String::from(property.clone()).as_str() {
+            "speed_kmh" => Some((self.speed * 3.6).to_variant()),
+            _ => self.get_exported_property(&property),
+        }
+    }
+
+    fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+        match String::from(property.clone()).as_str() {
+            "speed_kmh" => {
+                self.speed = value.to::<f64>() / 3.6;
+                true
+            }
+            _ => self.set_exported_property(&property, &value),
+        }
+    }
+}
+
+#[itest]
+fn test_get_set_forward_to_exports() {
+    let mut obj = ExportedGetSetTest::new_gd();
+
+    obj.set("speed".into(), 10.0.to_variant());
+    obj.set("label".into(), "fast".to_variant());
+    assert_eq!(obj.bind().speed, 10.0);
+    assert_eq!(obj.bind().label, GString::from("fast"));
+
+    assert_eq_approx!(obj.get("speed_kmh".into()).to::<f64>(), 36.0);
+    obj.set("speed_kmh".into(), 72.0.to_variant());
+    assert_eq_approx!(obj.get("speed".into()).to::<f64>(), 20.0);
+    assert_eq!(obj.get("label".into()), "fast".to_variant());
+
+    let mut guard = obj.bind_mut();
+    assert_eq!(guard.get_exported_property(&"unknown".into()), None);
+    assert!(!guard.set_exported_property(&"unknown".into(), &1.to_variant()));
+    assert!(!guard.set_exported_property(&"speed".into(), &"not a number".to_variant()));
+    assert!(guard.set_exported_property(&"label".into(), &"slow".to_variant()));
+    assert_eq!(guard.label, GString::from("slow"));
+}
+
 // Used in `test_collision_object_2d_input_event` in `SpecialTests.gd`.
 #[derive(GodotClass)]
 #[class(init, base = RigidBody2D)]
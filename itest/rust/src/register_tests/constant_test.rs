@@ -174,6 +174,8 @@ godot::sys::plugin_add!(
             register_methods_constants_fn: ::godot::private::ErasedRegisterFn {
                 raw: ::godot::private::callbacks::register_user_methods_constants::<HasOtherConstants>,
             },
+            user_to_string_fn: None,
+            docs: None,
         },
         init_level: HasOtherConstants::INIT_LEVEL,
     }
@@ -7,9 +7,11 @@
 
 use std::fmt::Debug;
 
-use godot::builtin::meta::ToGodot;
-use godot::builtin::{GString, Vector2};
-use godot::register::GodotConvert;
+use godot::builtin::meta::{FromGodot, ToGodot};
+use godot::builtin::{dict, Dictionary, GString, Variant, Vector2};
+use godot::engine::RefCounted;
+use godot::obj::NewGd;
+use godot::register::{godot_api, GodotClass, GodotConvert};
 
 use crate::common::roundtrip;
 use crate::framework::itest;
@@ -46,6 +48,22 @@ enum EnumInty {
     E,
 }
 
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(dictionary)]
+struct DictStruct {
+    name: GString,
+    level: i64,
+    position: Vector2,
+}
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(dictionary, rename_all = "camelCase")]
+struct DictNested {
+    max_health: i64,
+    inner_stats: DictStruct,
+    kind: EnumStringy,
+}
+
 #[itest]
 fn newtype_tuple_struct() {
     roundtrip(TupleNewtype("hello!".into()));
@@ -86,6 +104,110 @@ fn enum_inty() {
     assert_eq!(EnumInty::E.to_godot(), 2);
 }
 
+#[itest]
+fn dictionary_struct() {
+    let value = DictStruct {
+        name: "hello!".into(),
+        level: 7,
+        position: Vector2::new(10.0, 25.0),
+    };
+    roundtrip(value.clone());
+
+    let expected = dict! {
+        "name": "hello!",
+        "level": 7,
+        "position": Vector2::new(10.0, 25.0),
+    };
+    assert_eq!(value.to_godot(), expected);
+}
+
+#[itest]
+fn dictionary_struct_nested_renamed() {
+    let value = DictNested {
+        max_health: 100,
+        inner_stats: DictStruct {
+            name: "inner".into(),
+            level: 3,
+            position: Vector2::ZERO,
+        },
+        kind: EnumStringy::B,
+    };
+    roundtrip(value.clone());
+
+    let dict = value.to_godot();
+    assert_eq!(dict.len(), 3);
+    assert_eq!(dict.get("maxHealth"), Some(100.to_variant()));
+    assert_eq!(dict.get("kind"), Some("B".to_variant()));
+
+    let inner = dict.get("innerStats").unwrap().to::<Dictionary>();
+    assert_eq!(inner.get("name"), Some("inner".to_variant()));
+    assert_eq!(inner.get("level"), Some(3.to_variant()));
+}
+
+#[itest]
+fn dictionary_struct_errors() {
+    let missing_key = dict! { "name": "hello!", "level": 7 };
+    assert!(DictStruct::try_from_godot(missing_key).is_err());
+
+    let wrong_type = dict! {
+        "name": "hello!",
+        "level": "not an int",
+        "position": Vector2::ZERO,
+    };
+    assert!(DictStruct::try_from_godot(wrong_type).is_err());
+
+    // Additional keys are ignored.
+    let extra_key = dict! {
+        "name": "hello!",
+        "level": 7,
+        "position": Vector2::ZERO,
+        "unused": true,
+    };
+    assert_eq!(
+        DictStruct::from_godot(extra_key),
+        DictStruct {
+            name: "hello!".into(),
+            level: 7,
+            position: Vector2::ZERO,
+        }
+    );
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct DictReturner;
+
+#[godot_api]
+impl DictReturner {
+    #[func]
+    fn make_stats(&self, level: i64) -> DictStruct {
+        DictStruct {
+            name: "from func".into(),
+            level,
+            position: Vector2::new(1.0, 2.0),
+        }
+    }
+
+    #[func]
+    fn level_of(&self, stats: DictStruct) -> i64 {
+        stats.level
+    }
+}
+
+#[itest]
+fn dictionary_struct_func() {
+    let mut obj = DictReturner::new_gd();
+
+    let result = obj.call("make_stats".into(), &[5.to_variant()]);
+    let dict = result.to::<Dictionary>();
+    assert_eq!(dict.get("name"), Some("from func".to_variant()));
+    assert_eq!(dict.get("level"), Some(5.to_variant()));
+
+    let stats = DictStruct::from_variant(&result);
+    let level = obj.call("level_of".into(), &[stats.to_variant()]);
+    assert_eq!(level, Variant::from(5));
+}
+
 macro_rules! test_inty {
     ($T:ident, $test_name:ident, $class_name:ident) => {
         #[derive(GodotConvert, Clone, PartialEq, Debug)]
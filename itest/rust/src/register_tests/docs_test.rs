@@ -0,0 +1,106 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+/// Brief description of the documented class.
+///
+/// Further details, which appear only on the class page.
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct DocumentedClass {
+    /// Documented with `<` and `&`, which need escaping.
+    #[var]
+    documented: i64,
+
+    /// This doc comment is replaced by the tooltip.
+    #[export(tooltip = "Controls movement speed")]
+    speed: f64,
+
+    #[export]
+    undocumented: i64,
+}
+
+#[godot_api]
+impl DocumentedClass {
+    /// Doubles the given amount.
+    #[func]
+    fn double(&self, amount: i64) -> i64 {
+        amount * 2
+    }
+
+    #[func]
+    fn undocumented_func(&self) {}
+
+    /// Not part of the class reference.
+    #[func]
+    #[hide_in_editor]
+    fn hidden_func(&self) {}
+
+    /// Emitted when something happened.
+    #[signal]
+    fn happened(what: GString);
+}
+
+fn docs_xml() -> String {
+    godot::private::class_docs_xml(DocumentedClass::class_name())
+        .expect("DocumentedClass has doc comments")
+}
+
+#[itest]
+fn docs_struct_description() {
+    let xml = docs_xml();
+
+    assert!(xml.contains(r#"<class name="DocumentedClass" inherits="Node">"#));
+    assert!(xml.contains(
+        "<brief_description>Brief description of the documented class.</brief_description>"
+    ));
+    assert!(xml.contains("Further details, which appear only on the class page.</description>"));
+}
+
+#[itest]
+fn docs_members_and_tooltip() {
+    let xml = docs_xml();
+
+    assert!(xml.contains(
+        r#"<member name="documented" type="int">Documented with `&lt;` and `&amp;`, which need escaping.</member>"#
+    ));
+    assert!(xml.contains(r#"<member name="speed" type="float">Controls movement speed</member>"#));
+    assert!(!xml.contains("replaced by the tooltip"));
+    assert!(!xml.contains(r#"<member name="undocumented""#));
+}
+
+#[itest]
+fn docs_methods_and_signals() {
+    let xml = docs_xml();
+
+    assert!(xml.contains(
+        r#"<method name="double"><return type="int"/><param index="0" name="amount" type="int"/><description>Doubles the given amount.</description></method>"#
+    ));
+    assert!(!xml.contains(r#"<method name="undocumented_func""#));
+    assert!(!xml.contains(r#"<method name="hidden_func""#));
+    assert!(xml.contains(
+        r#"<signal name="happened"><param index="0" name="what" type="String"/><description>Emitted when something happened.</description></signal>"#
+    ));
+}
+
+#[itest]
+fn docs_absent_for_undocumented_class() {
+    assert_eq!(
+        godot::private::class_docs_xml(Undocumented::class_name()),
+        None
+    );
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct Undocumented {
+    #[var]
+    value: i64,
+}
@@ -8,7 +8,8 @@
 // Needed for Clippy to accept #[cfg(all())]
 #![allow(clippy::non_minimal_cfg)]
 
-use crate::framework::itest;
+use crate::framework::{capture_func_errors, expect_panic, itest, suppress_godot_print};
+use godot::engine::global::MethodFlags;
 use godot::engine::ClassDb;
 use godot::prelude::*;
 
@@ -33,6 +34,91 @@ impl FuncObj {
         GString::from("static")
     }
 
+    #[func(instantiate = [i64, f64])]
+    fn clamp_value<T: GodotNumeric>(&self, value: T, lo: T, hi: T) -> T {
+        if value < lo {
+            lo
+        } else if value > hi {
+            hi
+        } else {
+            value
+        }
+    }
+
+    #[func]
+    fn reverse_bytes(&self, bytes: Vec<u8>) -> Vec<u8> {
+        bytes.into_iter().rev().collect()
+    }
+
+    #[func]
+    #[hide_in_editor]
+    fn internal_helper(&self) -> i32 {
+        77
+    }
+
+    #[func]
+    fn rename_node(&self, node: &mut Gd<Node>, name: GString) {
+        node.set_name(name);
+    }
+
+    #[func]
+    fn string_buffer_address(&self, text: &GString) -> i64 {
+        text.chars_checked().as_ptr() as i64
+    }
+
+    #[func]
+    fn is_same_name(&self, name: &StringName, other: &godot::builtin::StringName) -> bool {
+        name == other
+    }
+
+    #[func]
+    fn checked_divide(&self, dividend: i64, divisor: i64) -> Result<i64, GString> {
+        dividend
+            .checked_div(divisor)
+            .ok_or_else(|| GString::from(format!("cannot divide {dividend} by {divisor}")))
+    }
+
+    #[func]
+    fn non_empty_name(name: GString) -> std::result::Result<GString, String> {
+        if name.is_empty() {
+            Err("name must not be empty".to_string())
+        } else {
+            Ok(name)
+        }
+    }
+
+    #[func]
+    #[default_param(scale = 2, offset = 10)]
+    fn scale_value(&self, value: i64, scale: i64, offset: i64) -> i64 {
+        value * scale + offset
+    }
+
+    #[func(rename = describe_speed)]
+    #[default_param(speed = 1.5)]
+    fn describe_speed_inner(&self, name: GString, speed: f64) -> GString {
+        format!("{name} at {speed}").into()
+    }
+
+    #[func]
+    fn echo_string_name(&self, name: StringName) -> StringName {
+        name
+    }
+
+    #[func]
+    fn echo_gstring(&self, string: GString) -> GString {
+        string
+    }
+
+    #[func]
+    fn return_typed_ints(&self) -> Array<i64> {
+        array![1, 2, 3]
+    }
+
+    #[func]
+    fn return_typed_nodes(&self) -> Array<Gd<Node>> {
+        Array::new()
+    }
+
     #[cfg(all())]
     fn returns_hello_world(&self) -> GString {
         GString::from("Hello world!")
@@ -207,6 +293,24 @@ impl GdSelfObj {
     fn takes_gd_as_self_no_return_type(this: Gd<GdSelfObj>) {
         this.bind();
     }
+
+    // Builder-style methods, chained from GDScript in ManualFfiTests.gd.
+    #[func(gd_self)]
+    fn with_internal_value(mut this: Gd<Self>, value: i32) -> Gd<Self> {
+        this.bind_mut().internal_value = value;
+        this
+    }
+
+    #[func(gd_self)]
+    fn add_to_internal_value(mut this: Gd<Self>, value: i32) -> Gd<Self> {
+        this.bind_mut().internal_value += value;
+        this
+    }
+
+    #[func]
+    fn get_internal_value(&self) -> i32 {
+        self.internal_value
+    }
 }
 
 #[godot_api]
@@ -249,6 +353,82 @@ impl IRefCounted for GdSelfObj {
     }
 }
 
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ProfiledObj;
+
+#[cfg(since_api = "4.2")]
+#[godot_api]
+impl ProfiledObj {
+    #[func]
+    #[profile]
+    fn profiled_default_name(&self) -> i32 {
+        if self.early_return() {
+            return 1;
+        }
+        2
+    }
+
+    #[profile(name = "ProfiledObj/custom_name")]
+    fn profiled_custom_name(&self) {}
+
+    fn early_return(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(since_api = "4.2")]
+#[godot_api]
+impl IRefCounted for ProfiledObj {
+    #[profile]
+    fn to_string(&self) -> GString {
+        GString::from("profiled")
+    }
+}
+
+/// Zero-argument scalar getters, which use a dedicated trampoline.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ScalarGetterObj {
+    #[init(default = -7)]
+    count: i64,
+}
+
+#[godot_api]
+impl ScalarGetterObj {
+    #[func]
+    fn get_count(&self) -> i64 {
+        self.count
+    }
+
+    #[func]
+    fn get_ratio(&self) -> f32 {
+        self.count as f32 / 2.0
+    }
+
+    #[func]
+    fn is_negative(&self) -> bool {
+        self.count < 0
+    }
+
+    #[func]
+    fn get_panicking(&self) -> i64 {
+        panic!("getter panicked with count {}", self.count)
+    }
+
+    #[func(rename = small_count)]
+    fn count_as_u8(&self) -> u8 {
+        self.count.unsigned_abs() as u8
+    }
+
+    #[func]
+    fn increment(&mut self) -> i64 {
+        self.count += 1;
+        self.count
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Tests
 
@@ -294,6 +474,263 @@ fn cfg_removes_or_keeps_signals() {
     assert!(!class_has_signal::<GdSelfObj>("cfg_removes_signal"));
 }
 
+#[itest]
+fn func_generic_instances() {
+    assert!(class_has_method::<FuncObj>("clamp_value_i64"));
+    assert!(class_has_method::<FuncObj>("clamp_value_f64"));
+    assert!(!class_has_method::<FuncObj>("clamp_value"));
+
+    let mut obj = FuncObj::new_gd().upcast::<Object>();
+
+    let result = obj.call(
+        "clamp_value_i64".into(),
+        &[15.to_variant(), 0.to_variant(), 10.to_variant()],
+    );
+    assert_eq!(result, 10.to_variant());
+
+    let result = obj.call(
+        "clamp_value_f64".into(),
+        &[(-0.5).to_variant(), 0.0.to_variant(), 1.0.to_variant()],
+    );
+    assert_eq!(result, 0.0.to_variant());
+}
+
+#[itest]
+fn func_vec_as_packed_array() {
+    let mut obj = FuncObj::new_gd().upcast::<Object>();
+
+    let bytes = PackedByteArray::from(&[1, 2, 3]);
+    let result = obj.call("reverse_bytes".into(), &[bytes.to_variant()]);
+
+    assert_eq!(result.get_type(), VariantType::PackedByteArray);
+    assert_eq!(result.to::<PackedByteArray>().to_vec(), vec![3, 2, 1]);
+    assert_eq!(result.to::<Vec<u8>>(), vec![3, 2, 1]);
+}
+
+#[itest]
+fn func_string_name_params() {
+    let mut obj = FuncObj::new_gd().upcast::<Object>();
+
+    // StringName arguments are decoded as StringName, not via GString.
+    let name = StringName::from("interned_key");
+    let result = obj.call("echo_string_name".into(), &[name.to_variant()]);
+    assert_eq!(result.get_type(), VariantType::StringName);
+    assert_eq!(result.to::<StringName>(), name);
+
+    let string = GString::from("plain_string");
+    let result = obj.call("echo_gstring".into(), &[string.to_variant()]);
+    assert_eq!(result.get_type(), VariantType::String);
+    assert_eq!(result.to::<GString>(), string);
+
+    // The registered parameter types are kept apart as well.
+    let arg_type = |method_name: &str| -> i64 {
+        find_method::<FuncObj>(method_name)
+            .get_or_nil("args")
+            .to::<Array<Dictionary>>()
+            .get(0)
+            .get_or_nil("type")
+            .to()
+    };
+    assert_eq!(arg_type("echo_string_name"), VariantType::StringName as i64);
+    assert_eq!(arg_type("echo_gstring"), VariantType::String as i64);
+}
+
+#[itest]
+fn func_typed_array_return() {
+    let mut obj = FuncObj::new_gd().upcast::<Object>();
+
+    // The returned arrays keep their element type, so they don't convert to untyped arrays.
+    let ints = obj.call("return_typed_ints".into(), &[]);
+    assert_eq!(ints.to::<Array<i64>>(), array![1, 2, 3]);
+    assert!(ints.try_to::<VariantArray>().is_err());
+
+    let nodes = obj.call("return_typed_nodes".into(), &[]);
+    assert!(nodes.try_to::<Array<Gd<Node>>>().is_ok());
+    assert!(nodes.try_to::<VariantArray>().is_err());
+}
+
+// Before Godot 4.2, typed arrays are registered without element type.
+#[cfg(since_api = "4.2")]
+#[itest]
+fn func_typed_array_return_hint() {
+    use godot::engine::global::PropertyHint;
+
+    let return_hint = |method_name: &str| -> (i64, GString) {
+        let info = find_method::<FuncObj>(method_name)
+            .get_or_nil("return")
+            .to::<Dictionary>();
+
+        (
+            info.get_or_nil("hint").to(),
+            info.get_or_nil("hint_string").to(),
+        )
+    };
+
+    let array_type = PropertyHint::ARRAY_TYPE.ord() as i64;
+    assert_eq!(return_hint("return_typed_ints"), (array_type, "int".into()));
+    assert_eq!(
+        return_hint("return_typed_nodes"),
+        (array_type, "Node".into())
+    );
+}
+
+#[itest]
+fn func_hide_in_editor() {
+    let method_flags =
+        |name: &str| -> i64 { find_method::<FuncObj>(name).get_or_nil("flags").to() };
+
+    let normal = MethodFlags::NORMAL.ord() as i64;
+    assert_eq!(method_flags("internal_helper") & normal, 0);
+    assert_eq!(method_flags("is_true") & normal, normal);
+
+    // Still callable dynamically.
+    let result = FuncObj::new_gd()
+        .upcast::<Object>()
+        .call("internal_helper".into(), &[]);
+    assert_eq!(result, 77.to_variant());
+}
+
+#[itest]
+fn func_mut_ref_gd_param() {
+    let obj = FuncObj::new_gd();
+    let node = Node::new_alloc();
+
+    obj.clone().upcast::<Object>().call(
+        "rename_node".into(),
+        &[node.to_variant(), "Renamed".to_variant()],
+    );
+    assert_eq!(node.get_name(), StringName::from("Renamed"));
+
+    node.free();
+}
+
+#[itest]
+fn func_borrowed_string_params() {
+    let mut object = FuncObj::new_gd().upcast::<Object>();
+
+    // The method sees the caller's string buffer, not a copy of it.
+    let text = GString::from("borrowed");
+    let address = object.call("string_buffer_address".into(), &[text.to_variant()]);
+    assert_eq!(address, (text.chars_checked().as_ptr() as i64).to_variant());
+
+    let same = object.call(
+        "is_same_name".into(),
+        &[
+            StringName::from("a").to_variant(),
+            StringName::from("a").to_variant(),
+        ],
+    );
+    assert_eq!(same, true.to_variant());
+}
+
+#[itest]
+fn func_error_result() {
+    let mut object = FuncObj::new_gd().upcast::<Object>();
+
+    let quotient = object.call("checked_divide".into(), &[7.to_variant(), 2.to_variant()]);
+    assert_eq!(quotient, 3.to_variant());
+
+    let name = object.call("non_empty_name".into(), &["Godette".to_variant()]);
+    assert_eq!(name, GString::from("Godette").to_variant());
+
+    // Errors are pushed to Godot's error output; Godot receives the default value instead.
+    let errors = capture_func_errors(|| {
+        suppress_godot_print(|| {
+            let quotient = object.call("checked_divide".into(), &[7.to_variant(), 0.to_variant()]);
+            assert_eq!(quotient, 0.to_variant());
+
+            let name = object.call("non_empty_name".into(), &["".to_variant()]);
+            assert_eq!(name, GString::new().to_variant());
+        })
+    });
+    assert_eq!(errors, ["cannot divide 7 by 0", "name must not be empty"]);
+
+    // Godot sees the `Ok` type as return type.
+    let return_type: i64 = find_method::<FuncObj>("checked_divide")
+        .get_or_nil("return")
+        .to::<Dictionary>()
+        .get_or_nil("type")
+        .to();
+    assert_eq!(return_type, VariantType::Int as i64);
+}
+
+#[itest]
+fn func_default_params() {
+    assert!(class_has_method::<FuncObj>("scale_value"));
+    assert!(class_has_method::<FuncObj>("scale_value_default"));
+    assert!(class_has_method::<FuncObj>("describe_speed_default"));
+    assert!(!class_has_method::<FuncObj>("describe_speed_inner_default"));
+
+    let obj = FuncObj::new_gd();
+    let mut object = obj.clone().upcast::<Object>();
+
+    let result = object.call("scale_value_default".into(), &[3.to_variant()]);
+    assert_eq!(result, 16.to_variant());
+
+    let args = [3.to_variant(), 3.to_variant(), 1.to_variant()];
+    let result = object.call("scale_value".into(), &args);
+    assert_eq!(result, 10.to_variant());
+
+    let result = object.call("describe_speed_default".into(), &["Car".to_variant()]);
+    assert_eq!(result, "Car at 1.5".to_variant());
+
+    // The wrapper is a regular Rust method as well.
+    assert_eq!(obj.bind().scale_value_default(5), 20);
+}
+
+#[itest]
+fn func_scalar_getters() {
+    let mut object = ScalarGetterObj::new_gd().upcast::<Object>();
+
+    assert_eq!(object.call("get_count".into(), &[]), (-7).to_variant());
+    assert_eq!(object.call("get_ratio".into(), &[]), (-3.5).to_variant());
+    assert_eq!(object.call("is_negative".into(), &[]), true.to_variant());
+    assert_eq!(object.call("small_count".into(), &[]), 7.to_variant());
+
+    // Getters observe changes made through other methods.
+    object.call("increment".into(), &[]);
+    assert_eq!(object.call("get_count".into(), &[]), (-6).to_variant());
+}
+
+#[itest]
+fn func_scalar_getter_panic() {
+    let mut object = ScalarGetterObj::new_gd().upcast::<Object>();
+
+    // The panic is caught inside the getter and reported to Godot; the failed call then surfaces as a panic on the calling side.
+    suppress_godot_print(|| {
+        expect_panic("panicking scalar getter", || {
+            object.call("get_panicking".into(), &[]);
+        })
+    });
+
+    // The instance is no longer bound after the panic.
+    assert_eq!(object.call("get_count".into(), &[]), (-7).to_variant());
+    object.call("increment".into(), &[]);
+    assert_eq!(object.call("get_count".into(), &[]), (-6).to_variant());
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn profile_registers_custom_monitors() {
+    use godot::engine::Performance;
+
+    let obj = ProfiledObj::new_gd();
+    let performance = Performance::singleton();
+    assert!(!performance.has_custom_monitor("ProfiledObj/profiled_default_name".into()));
+
+    let result = obj
+        .clone()
+        .upcast::<Object>()
+        .call("profiled_default_name".into(), &[]);
+    assert_eq!(result, 1.to_variant());
+    obj.bind().profiled_custom_name();
+    assert_eq!(obj.to_string(), "profiled");
+
+    assert!(performance.has_custom_monitor("ProfiledObj/profiled_default_name".into()));
+    assert!(performance.has_custom_monitor("ProfiledObj/custom_name".into()));
+    assert!(performance.has_custom_monitor("ProfiledObj/to_string".into()));
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers
 
@@ -305,6 +742,17 @@ fn class_has_method<T: GodotClass>(name: &str) -> bool {
         .done()
 }
 
+/// Returns the entry of the method `name` in the class's own method list in [ClassDb].
+fn find_method<T: GodotClass>(name: &str) -> Dictionary {
+    ClassDb::singleton()
+        .class_get_method_list_ex(T::class_name().to_string_name())
+        .no_inheritance(true)
+        .done()
+        .iter_shared()
+        .find(|method| method.get_or_nil("name") == name.to_variant())
+        .unwrap_or_else(|| panic!("method `{name}` not registered"))
+}
+
 /// Checks at runtime if a class has a given signal through [ClassDb].
 fn class_has_signal<T: GodotClass>(name: &str) -> bool {
     ClassDb::singleton().class_has_signal(T::class_name().to_string_name(), name.into())
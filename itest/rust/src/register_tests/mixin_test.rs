@@ -0,0 +1,118 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+// Declared in a separate module, to check that importing the mixin type is enough.
+mod mixins {
+    use godot::prelude::*;
+
+    #[derive(Default)]
+    pub struct MovementMixin {
+        pub speed: f64,
+    }
+
+    #[godot_api(mixin)]
+    impl MovementMixin {
+        #[func]
+        pub fn set_speed(&mut self, speed: f64) {
+            self.speed = speed;
+        }
+
+        #[func]
+        pub fn get_speed(&self) -> f64 {
+            self.speed
+        }
+
+        #[func(rename = max_speed)]
+        pub fn maximum_speed() -> f64 {
+            100.0
+        }
+    }
+
+    #[derive(Default)]
+    pub struct HealthMixin {
+        pub health: i64,
+    }
+
+    #[godot_api(mixin)]
+    impl HealthMixin {
+        #[func]
+        pub fn damage(&mut self, amount: i64) -> i64 {
+            self.health -= amount;
+            self.health
+        }
+    }
+}
+
+use mixins::{HealthMixin, MovementMixin};
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct MixinHost {
+    #[mixin]
+    movement: MovementMixin,
+
+    #[mixin]
+    health: HealthMixin,
+
+    #[var]
+    name: GString,
+}
+
+#[godot_api]
+impl MixinHost {
+    #[func]
+    fn describe(&self) -> GString {
+        format!("{} at {}", self.name, self.movement.speed).into()
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct OtherMixinHost {
+    #[mixin]
+    movement: mixins::MovementMixin,
+}
+
+#[itest]
+fn mixin_methods_registered() {
+    for class_name in ["MixinHost", "OtherMixinHost"] {
+        for method in ["set_speed", "get_speed", "max_speed"] {
+            assert!(
+                ClassDb::singleton().class_has_method(class_name.into(), method.into()),
+                "{class_name}.{method}() should be registered"
+            );
+        }
+    }
+
+    assert!(ClassDb::singleton().class_has_method("MixinHost".into(), "damage".into()));
+    assert!(!ClassDb::singleton().class_has_method("OtherMixinHost".into(), "damage".into()));
+}
+
+#[itest]
+fn mixin_methods_forward_to_field() {
+    let mut host = MixinHost::new_gd();
+    host.bind_mut().health.health = 10;
+
+    host.call("set_speed".into(), &[2.5.to_variant()]);
+    assert_eq!(host.bind().movement.speed, 2.5);
+    assert_eq!(host.call("get_speed".into(), &[]), 2.5.to_variant());
+    assert_eq!(host.call("max_speed".into(), &[]), 100.0.to_variant());
+    assert_eq!(
+        host.call("damage".into(), &[3.to_variant()]),
+        7.to_variant()
+    );
+
+    // Forwarding methods are also available in Rust, next to the class's own methods.
+    host.bind_mut().set_speed(4.0);
+    host.bind_mut().set_name("runner".into());
+    assert_eq!(host.bind().get_speed(), 4.0);
+    assert_eq!(host.bind().describe(), GString::from("runner at 4"));
+}
@@ -9,9 +9,13 @@ mod constant_test;
 mod derive_variant_test;
 mod func_test;
 mod gdscript_ffi_test;
+mod mixin_test;
 mod option_ffi_test;
+mod registration_test;
 mod var_test;
 
+#[cfg(since_api = "4.3")]
+mod docs_test;
 #[cfg(since_api = "4.3")]
 mod func_virtual_test;
 
@@ -5,13 +5,14 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::meta::GodotType;
+use godot::builtin::meta::{GodotType, ToGodot};
+use godot::builtin::{GString, Variant};
 use godot::engine::{Node, Object, RefCounted};
 use godot::obj::{Gd, NewAlloc, NewGd, RawGd};
 use godot::register::{godot_api, GodotClass};
 use godot::sys::GodotFfi;
 
-use crate::framework::itest;
+use crate::framework::{expect_panic, expect_panic_with_message, itest};
 
 #[itest]
 fn option_some_sys_conversion() {
@@ -39,12 +40,122 @@ fn option_none_sys_conversion() {
     assert_eq!(v2, v);
 }
 
+#[itest]
+fn option_static_factory() {
+    let obj = OptionFfiTest::try_create(true);
+    assert!(obj.is_some());
+
+    let obj = OptionFfiTest::try_create(false);
+    assert!(obj.is_none());
+}
+
+#[itest]
+fn option_trailing_args_omitted() {
+    let mut obj = OptionFfiTest::new_gd().upcast::<Object>();
+
+    let result = obj.call("describe_optional_args".into(), &[3.to_variant()]);
+    assert_eq!(result, "3 (none) (none)".to_variant());
+
+    let args = [3.to_variant(), 4.to_variant()];
+    let result = obj.call("describe_optional_args".into(), &args);
+    assert_eq!(result, "3 4 (none)".to_variant());
+
+    let args = [3.to_variant(), Variant::nil(), "label".to_variant()];
+    let result = obj.call("describe_optional_args".into(), &args);
+    assert_eq!(result, "3 (none) label".to_variant());
+}
+
+#[itest]
+fn option_gd_param_accepts_null() {
+    let mut obj = OptionFfiTest::new_gd().upcast::<Object>();
+
+    let result = obj.call("accept_option_node_none".into(), &[Variant::nil()]);
+    assert_eq!(result, true.to_variant());
+
+    let result = obj.call("mirror_option_node".into(), &[Variant::nil()]);
+    assert_eq!(result, Variant::nil());
+}
+
+#[itest]
+fn option_gd_param_accepts_object() {
+    let mut obj = OptionFfiTest::new_gd().upcast::<Object>();
+    let node = Node::new_alloc();
+
+    let result = obj.call("mirror_option_node".into(), &[node.to_variant()]);
+    assert_eq!(result.to::<Option<Gd<Node>>>(), Some(node.clone()));
+
+    node.free();
+}
+
+#[itest]
+fn option_gd_param_rejects_wrong_class() {
+    let mut obj = OptionFfiTest::new_gd().upcast::<Object>();
+
+    // A non-null object of another class is an error, not `None`.
+    expect_panic(
+        "Option<Gd<Node>> parameter with RefCounted argument",
+        || {
+            obj.call(
+                "accept_option_node_none".into(),
+                &[RefCounted::new_gd().to_variant()],
+            );
+        },
+    );
+}
+
+#[itest]
+fn option_param_rejects_wrong_type() {
+    let mut obj = OptionFfiTest::new_gd().upcast::<Object>();
+
+    // Reported as a regular call error, like a mistyped non-optional argument, instead of a panic inside the method.
+    let args = [3.to_variant(), "not a number".to_variant()];
+    expect_panic_with_message(
+        "Option<i64> parameter with String argument",
+        "cannot convert argument #2 from String to Int",
+        || {
+            obj.call("describe_optional_args".into(), &args);
+        },
+    );
+}
+
 #[derive(GodotClass, Debug)]
 #[class(base = RefCounted, init)]
 struct OptionFfiTest;
 
 #[godot_api]
 impl OptionFfiTest {
+    /// Fallible constructor; `None` is passed to GDScript as `null`.
+    #[func]
+    fn try_create(valid: bool) -> Option<Gd<Self>> {
+        if valid {
+            Some(Self::new_gd())
+        } else {
+            None
+        }
+    }
+
+    /// Trailing `Option` parameters may be omitted by the caller.
+    #[func]
+    fn describe_optional_args(
+        &self,
+        required: i64,
+        scale: Option<i64>,
+        label: Option<GString>,
+    ) -> GString {
+        let scale = scale.map_or("(none)".to_string(), |s| s.to_string());
+        let label = label.map_or("(none)".to_string(), |l| l.to_string());
+
+        format!("{required} {scale} {label}").into()
+    }
+
+    /// Non-trailing `Option` parameters are still required, but accept `null`.
+    #[func]
+    fn describe_nullable_arg(&self, value: Option<i64>, suffix: GString) -> GString {
+        let value = value.map_or("(none)".to_string(), |v| v.to_string());
+
+        format!("{value}{suffix}").into()
+    }
+
     #[func]
     fn return_option_refcounted_none(&self) -> Option<Gd<RefCounted>> {
         None
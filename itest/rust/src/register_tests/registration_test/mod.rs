@@ -0,0 +1,59 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Classes split across several files and modules, each registered through its own static constructor.
+//!
+//! Every `#[derive(GodotClass)]` and `#[godot_api]` block adds one plugin to the registry. This checks that none of them is lost
+//! when they end up in different codegen units of the cdylib, including a struct and its `#[godot_api]` impls living in different files.
+
+mod split_impls;
+mod split_structs;
+
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+use crate::framework::itest;
+use split_structs::{SplitNode, SplitResource};
+
+#[itest]
+fn registration_split_across_files() {
+    let db = ClassDb::singleton();
+
+    for (class, parent) in [
+        ("SplitNode", "Node"),
+        ("SplitResource", "Resource"),
+        ("SplitNestedObject", "Object"),
+    ] {
+        assert!(
+            db.class_exists(class.into()),
+            "class {class} not registered"
+        );
+        assert_eq!(
+            db.get_parent_class(class.into()),
+            StringName::from(parent),
+            "parent of class {class}"
+        );
+    }
+}
+
+#[itest]
+fn registration_impls_in_other_file() {
+    let db = ClassDb::singleton();
+
+    // Inherent impl and interface impl are both in split_impls.rs.
+    assert!(db.class_has_method("SplitNode".into(), "split_value".into()));
+    assert!(db.class_has_method("SplitResource".into(), "split_value".into()));
+    assert!(db.class_has_method("SplitNestedObject".into(), "nested_value".into()));
+
+    let mut node = SplitNode::new_alloc();
+    assert_eq!(node.call("split_value".into(), &[]), 11.to_variant());
+    assert_eq!(node.to_string(), "SplitNode(11)");
+    node.free();
+
+    let resource = SplitResource::new_gd();
+    assert_eq!(resource.bind().split_value(), 22);
+}
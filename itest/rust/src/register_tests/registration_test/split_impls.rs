@@ -0,0 +1,37 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+
+use super::split_structs::{SplitNode, SplitResource};
+
+#[godot_api]
+impl INode for SplitNode {
+    fn init(base: Base<Node>) -> Self {
+        Self { value: 11, base }
+    }
+
+    fn to_string(&self) -> GString {
+        format!("SplitNode({})", self.value).into()
+    }
+}
+
+#[godot_api]
+impl SplitNode {
+    #[func]
+    fn split_value(&self) -> i32 {
+        self.value
+    }
+}
+
+#[godot_api]
+impl SplitResource {
+    #[func]
+    pub fn split_value(&self) -> i32 {
+        self.value
+    }
+}
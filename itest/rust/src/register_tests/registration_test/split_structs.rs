@@ -0,0 +1,38 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(base=Node)]
+pub struct SplitNode {
+    pub value: i32,
+    pub(super) base: Base<Node>,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+pub struct SplitResource {
+    #[init(default = 22)]
+    pub value: i32,
+}
+
+mod nested {
+    use godot::prelude::*;
+
+    #[derive(GodotClass)]
+    #[class(init, base=Object)]
+    pub struct SplitNestedObject {}
+
+    #[godot_api]
+    impl SplitNestedObject {
+        #[func]
+        fn nested_value() -> i32 {
+            33
+        }
+    }
+}